@@ -11,4 +11,6 @@ pub struct AltResource {
     pub voice_channels: HashMap<usize, Entity>,
     pub collision_shapes: HashMap<usize, Entity>,
     pub checkpoints: HashMap<usize, Entity>,
+    /// The base object of every entity above, used to pass entities back to the core.
+    pub base_objects: HashMap<Entity, usize>,
 }
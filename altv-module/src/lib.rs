@@ -1,21 +1,243 @@
+use crate::load::{load, Loaded, Watch};
 use altv::app::CoreApplication;
-use altv::app::ResourceMainFn;
+use altv::entry::{CallStatus, EntryPoints};
 use altv::sdk::natives::*;
 use altv::sdk::string_view::StringView;
 use libloading::Library;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
+use std::rc::Rc;
+
+mod load;
 
 const RES_TYPE: &'static str = "rust";
+/// Set to watch the resource libraries and reload them once they change.
+const HOT_RELOAD_ENV: &str = "ALTV_RUST_HOT_RELOAD";
 
 thread_local! {
-    static APPS: RefCell<HashMap<*mut alt_IResource, CoreApplication>> = RefCell::new(HashMap::new());
+    // Every resource is borrowed on its own, so the core can call into the others while one runs.
+    static APPS: RefCell<HashMap<*mut alt_IResource, Rc<RefCell<Resource>>>> =
+        RefCell::new(HashMap::new());
+    static PENDING: RefCell<HashMap<*mut alt_IResource, Vec<Pending>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// A base object callback for a resource that was busy, it runs once the resource returns.
+///
+/// The base object is referenced while queued, so it stays valid if it is destroyed meanwhile.
+#[derive(Clone, Copy)]
+enum Pending {
+    Create(*mut alt_IBaseObject),
+    Remove(*mut alt_IBaseObject),
+}
+
+impl Pending {
+    fn base_obj(self) -> *mut alt_IBaseObject {
+        match self {
+            Pending::Create(base_obj) | Pending::Remove(base_obj) => base_obj,
+        }
+    }
+
+    fn entry_point(self) -> &'static str {
+        match self {
+            Pending::Create(_) => "create_game_object",
+            Pending::Remove(_) => "remove_game_object",
+        }
+    }
+
+    unsafe fn retain(self) {
+        alt_CRefCountable_AddRef(alt_IBaseObject_to_alt_CRefCountable(self.base_obj()));
+    }
+
+    unsafe fn release(self) {
+        alt_CRefCountable_RemoveRef(alt_IBaseObject_to_alt_CRefCountable(self.base_obj()));
+    }
+
+    unsafe fn apply(self, entry_points: &EntryPoints, app: *mut CoreApplication) -> CallStatus {
+        match self {
+            Pending::Create(base_obj) => (entry_points.create_game_object)(app, base_obj),
+            Pending::Remove(base_obj) => (entry_points.remove_game_object)(app, base_obj),
+        }
+    }
+}
+
+struct Resource {
+    // Only the library may drop the application, through its entry points.
+    app: ManuallyDrop<CoreApplication>,
+    entry_points: EntryPoints,
+    lib: Library,
+    res_impl: *mut alt_IResource_Impl,
+    watch: Option<Watch>,
+    // Set once the application panicked, it doesn't get any callbacks afterwards.
+    failed: bool,
+}
+
+impl Resource {
+    fn app(&mut self) -> *mut CoreApplication {
+        &mut *self.app
+    }
+
+    /// Drops the application and unloads its library.
+    unsafe fn unload(mut self) {
+        if (self.entry_points.drop)(self.app()) == CallStatus::Panicked {
+            altv::sdk::log::error("[Rust] Application panicked while it was dropped.");
+        }
+        drop(self.lib);
+
+        if let Some(watch) = self.watch {
+            watch.remove_copy();
+        }
+    }
+}
+
+/// Runs an entry point of the module, a panic is logged by the hook and `default` is returned
+/// instead.
+fn catch<R, F>(entry_point: &str, default: R, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => {
+            altv::sdk::log::error(&format!("[Rust] {} panicked.", entry_point));
+            default
+        }
+    }
+}
+
+fn get_resource(res: *mut alt_IResource) -> Option<Rc<RefCell<Resource>>> {
+    APPS.with(|apps| apps.borrow().get(&res).cloned())
+}
+
+/// Takes the resource out of the module, `None` if it is still running further up the stack.
+unsafe fn take_resource(res: *mut alt_IResource) -> Option<Resource> {
+    let resource = APPS.with(|apps| apps.borrow_mut().remove(&res))?;
+
+    if let Some(pending) = PENDING.with(|pending| pending.borrow_mut().remove(&res)) {
+        pending.into_iter().for_each(|pending| pending.release());
+    }
+
+    match Rc::try_unwrap(resource) {
+        Ok(resource) => Some(resource.into_inner()),
+        Err(resource) => {
+            altv::sdk::log::error("[Rust] Resource is still running, it can't be unloaded.");
+            APPS.with(|apps| apps.borrow_mut().insert(res, resource));
+            None
+        }
+    }
+}
+
+/// Calls an entry point of the application of the resource, returns whether it ran and
+/// returned.
+///
+/// If the application panicked the resource is marked as failed and stopped, the server keeps
+/// running. Base object callbacks the resource caused itself are delivered once `f` returns.
+unsafe fn with_app<F>(res: *mut alt_IResource, entry_point: &str, f: F) -> bool
+where
+    F: FnOnce(&EntryPoints, *mut CoreApplication) -> CallStatus,
+{
+    if res.is_null() {
+        altv::sdk::log::error("[Rust] Resource is null.");
+        return false;
+    }
+
+    let resource = match get_resource(res) {
+        Some(resource) => resource,
+        None => return false,
+    };
+
+    let mut resource = match resource.try_borrow_mut() {
+        Ok(resource) => resource,
+        Err(_) => {
+            altv::sdk::log::error(&format!(
+                "[Rust] Resource {} is busy, {} was skipped.",
+                resource_name(res),
+                entry_point
+            ));
+            return false;
+        }
+    };
+
+    let result = run(&mut resource, res, entry_point, f);
+
+    while let Some(pending) = PENDING.with(|pending| pending.borrow_mut().remove(&res)) {
+        for pending in pending {
+            run(
+                &mut resource,
+                res,
+                pending.entry_point(),
+                |entry_points, app| pending.apply(entry_points, app),
+            );
+            pending.release();
+        }
+    }
+
+    result
+}
+
+// The panics are caught by the entry points inside the resource library, a panic of another
+// Rust runtime can't be caught here.
+unsafe fn run<F>(resource: &mut Resource, res: *mut alt_IResource, entry_point: &str, f: F) -> bool
+where
+    F: FnOnce(&EntryPoints, *mut CoreApplication) -> CallStatus,
+{
+    if resource.failed {
+        return false;
+    }
+
+    let app = resource.app();
+    if f(&resource.entry_points, app) == CallStatus::Ok {
+        return true;
+    }
+
+    resource.failed = true;
+
+    let name = resource_name(res);
+    altv::sdk::log::error(&format!(
+        "[Rust] Resource {} panicked in {} and was stopped.",
+        name, entry_point
+    ));
+
+    if (resource.entry_points.stop)(app) == CallStatus::Panicked {
+        altv::sdk::log::error(&format!(
+            "[Rust] Resource {} panicked again while stopping.",
+            name
+        ));
+    }
+
+    false
+}
+
+/// Delivers a base object callback, or queues it if the resource is the one that caused it.
+unsafe fn on_base_object(res: *mut alt_IResource, callback: Pending) {
+    let busy = match get_resource(res) {
+        Some(resource) => resource.try_borrow_mut().is_err(),
+        None => false,
+    };
+
+    if !busy {
+        with_app(res, callback.entry_point(), |entry_points, app| {
+            callback.apply(entry_points, app)
+        });
+        return;
+    }
+
+    callback.retain();
+    PENDING.with(|pending| pending.borrow_mut().entry(res).or_default().push(callback));
+}
+
+unsafe fn resource_name(res: *mut alt_IResource) -> String {
+    StringView::from(*alt_IResource_GetName_CAPI_Heap(res)).get_data()
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn altMain(core: *mut alt_ICore) -> bool {
-    match core.as_mut() {
+    altv::panic::install_hook();
+
+    catch("altMain", false, || match core.as_mut() {
         Some(core) => {
             alt_ICore_SetInstance(core);
             let script_rt =
@@ -31,7 +253,7 @@ pub unsafe extern "C" fn altMain(core: *mut alt_ICore) -> bool {
             }
         }
         None => false,
-    }
+    })
 }
 
 #[no_mangle]
@@ -43,33 +265,34 @@ unsafe extern "C" fn create_impl(
     _script_rt: *mut alt_IScriptRuntime,
     res: *mut alt_IResource,
 ) -> *mut alt_IResource_Impl {
-    dbg!("Triggered create implementation function.");
-
     if _script_rt.is_null() {
-        panic!("Script runtime is null.");
+        altv::sdk::log::error("[Rust] Script runtime is null.");
+        return std::ptr::null_mut();
     }
 
     if res.is_null() {
-        panic!("Resource is null.");
+        altv::sdk::log::error("[Rust] Resource is null.");
+        return std::ptr::null_mut();
     }
 
+    catch("create_impl", std::ptr::null_mut(), || create(res))
+}
+
+unsafe fn create(res: *mut alt_IResource) -> *mut alt_IResource_Impl {
+    let name = StringView::from(*alt_IResource_GetName_CAPI_Heap(res)).get_data();
     let path = StringView::from(*alt_IResource_GetPath_CAPI_Heap(res)).get_data();
     let main = StringView::from(*alt_IResource_GetMain_CAPI_Heap(res)).get_data();
-    let lib = Library::new(Path::new(&path).join(&main).display().to_string()).unwrap();
-    let main_fn: ResourceMainFn = *lib.get(b"main\0").unwrap();
-    let main_result = main_fn(alt_ICore_Instance() as usize);
-
-    match main_result {
-        Ok(app) => {
-            // TODO: better alternative to "leak"
-            Box::leak(Box::new(lib));
-
-            APPS.with(|apps| {
-                let mut apps = apps.borrow_mut();
-                apps.insert(res, app);
-            });
-
-            alt_CAPIResource_Impl_Create(
+    let path = Path::new(&path).join(&main);
+    let watch = std::env::var_os(HOT_RELOAD_ENV).is_some();
+
+    match load(&path, watch) {
+        Ok(Loaded {
+            app,
+            entry_points,
+            lib,
+            watch,
+        }) => {
+            let res_impl = alt_CAPIResource_Impl_Create(
                 res,
                 Some(res_make_client),
                 Some(res_start),
@@ -78,146 +301,227 @@ unsafe extern "C" fn create_impl(
                 Some(res_on_tick),
                 Some(res_on_create_base_object),
                 Some(res_on_remove_base_object),
-            )
+            );
+
+            APPS.with(|apps| {
+                let mut apps = apps.borrow_mut();
+                apps.insert(
+                    res,
+                    Rc::new(RefCell::new(Resource {
+                        app: ManuallyDrop::new(app),
+                        entry_points,
+                        lib,
+                        res_impl,
+                        watch,
+                        failed: false,
+                    })),
+                );
+            });
+
+            res_impl
+        }
+        Err(e) => {
+            altv::sdk::log::error(&format!(
+                "[Rust] Could not load resource {} from {}: {}",
+                name,
+                path.display(),
+                e
+            ));
+            std::ptr::null_mut()
         }
-        Err(_) => std::ptr::null_mut(),
     }
 }
 
 unsafe extern "C" fn destroy_impl(
     _script_rt: *mut alt_IScriptRuntime,
-    _res_impl: *mut alt_IResource_Impl,
+    res_impl: *mut alt_IResource_Impl,
 ) {
-    dbg!("Triggered destroy implementation function.");
-}
-
-unsafe extern "C" fn tick(_script_rt: *mut alt_IScriptRuntime) {}
+    let res = APPS.with(|apps| {
+        apps.borrow()
+            .iter()
+            .find(|(_, resource)| resource.borrow().res_impl == res_impl)
+            .map(|(res, _)| *res)
+    });
 
-unsafe extern "C" fn res_make_client(
-    _res: *mut alt_IResource,
-    res_info: *mut alt_IResource_CreationInfo,
-    _str_arr: *mut alt_Array_String,
-) -> bool {
-    dbg!("Triggered make resource client function.");
-    (*res_info).type_ = altv::sdk::string::String::new("js").into();
-    true
+    if let Some(resource) = res.and_then(|res| take_resource(res)) {
+        catch("destroy_impl", (), || resource.unload());
+    }
 }
 
-unsafe extern "C" fn res_start(res: *mut alt_IResource) -> bool {
-    dbg!("Triggered start resource function.");
+unsafe extern "C" fn tick(_script_rt: *mut alt_IScriptRuntime) {
+    let changed: Vec<_> = APPS.with(|apps| {
+        apps.borrow()
+            .iter()
+            .filter_map(|(res, resource)| {
+                let mut resource = resource.try_borrow_mut().ok()?;
+                let watch = resource.watch.as_mut()?;
+
+                if watch.is_changed() {
+                    Some(*res)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    });
 
-    if res.is_null() {
-        altv::sdk::log::error("[Rust] Resource is null.");
-        return false;
+    for res in changed {
+        catch("reload", (), || reload(res));
     }
+}
 
-    APPS.with(|apps| {
-        let mut apps = apps.borrow_mut();
-        let app = apps.get_mut(&res).unwrap();
-        app.start();
+/// Swaps the library of a running resource, the new application gets the base objects of the
+/// old one and the data saved by the reload hook.
+///
+/// The old application is stopped before the new library runs its main function, so only one
+/// of them is running at a time.
+unsafe fn reload(res: *mut alt_IResource) {
+    let path = match get_resource(res)
+        .and_then(|resource| Some(resource.borrow().watch.as_ref()?.path.clone()))
+    {
+        Some(path) => path,
+        None => return,
+    };
+
+    let mut data = None;
+    with_app(res, "save", |entry_points, app| {
+        (entry_points.save)(app, &mut data)
+    });
+    with_app(res, "stop", |entry_points, app| (entry_points.stop)(app));
+
+    let old = match take_resource(res) {
+        Some(old) => old,
+        None => return,
+    };
+
+    let loaded = catch("main", None, || match load(&path, true) {
+        Ok(loaded) => Some(loaded),
+        Err(e) => {
+            altv::sdk::log::error(&format!(
+                "[Rust] Could not reload {}: {}",
+                path.display(),
+                e
+            ));
+            None
+        }
     });
 
-    true
-}
+    let Loaded {
+        app,
+        entry_points,
+        lib,
+        watch,
+    } = match loaded {
+        Some(loaded) => loaded,
+        None => {
+            altv::sdk::log::error("[Rust] Restarting the previous version.");
+
+            let mut old = old;
+            if let Some(watch) = old.watch.as_mut() {
+                watch.modified = watch.changed.take();
+            }
 
-unsafe extern "C" fn res_stop(res: *mut alt_IResource) -> bool {
-    dbg!("Triggered stop resource function.");
+            APPS.with(|apps| apps.borrow_mut().insert(res, Rc::new(RefCell::new(old))));
+            with_app(res, "start", |entry_points, app| (entry_points.start)(app));
+            return;
+        }
+    };
 
-    if res.is_null() {
-        altv::sdk::log::error("[Rust] Resource is null.");
-        return false;
+    // Blips, collision shapes and the like can't be listed through the core, so the new
+    // application takes over the objects of the old one. They stay referenced in between.
+    let base_objects = old.app.base_objects();
+    for base_obj in &base_objects {
+        alt_CRefCountable_AddRef(alt_IBaseObject_to_alt_CRefCountable(*base_obj));
     }
 
+    let res_impl = old.res_impl;
+    catch("unload", (), || old.unload());
+
     APPS.with(|apps| {
-        let mut apps = apps.borrow_mut();
-        let app = apps.get_mut(&res).unwrap();
-        app.stop();
+        apps.borrow_mut().insert(
+            res,
+            Rc::new(RefCell::new(Resource {
+                app: ManuallyDrop::new(app),
+                entry_points,
+                lib,
+                res_impl,
+                watch,
+                failed: false,
+            })),
+        )
     });
 
+    for base_obj in base_objects {
+        with_app(res, "create_game_object", |entry_points, app| {
+            (entry_points.create_game_object)(app, base_obj)
+        });
+        alt_CRefCountable_RemoveRef(alt_IBaseObject_to_alt_CRefCountable(base_obj));
+    }
+
+    if let Some(data) = data {
+        with_app(res, "restore", |entry_points, app| {
+            (entry_points.restore)(app, data.as_ptr(), data.len())
+        });
+    }
+
+    with_app(res, "start", |entry_points, app| (entry_points.start)(app));
+
+    altv::sdk::log::info(&format!("[Rust] Reloaded {}.", path.display()));
+}
+
+unsafe extern "C" fn res_make_client(
+    _res: *mut alt_IResource,
+    res_info: *mut alt_IResource_CreationInfo,
+    _str_arr: *mut alt_Array_String,
+) -> bool {
+    (*res_info).type_ = altv::sdk::string::String::new("js").into();
     true
 }
 
-unsafe extern "C" fn res_on_event(res: *mut alt_IResource, e: *mut alt_CEvent) -> bool {
-    dbg!("Triggered event function.");
+unsafe extern "C" fn res_start(res: *mut alt_IResource) -> bool {
+    with_app(res, "start", |entry_points, app| (entry_points.start)(app))
+}
 
-    if res.is_null() {
-        panic!("Resource is null.");
-    }
+unsafe extern "C" fn res_stop(res: *mut alt_IResource) -> bool {
+    with_app(res, "stop", |entry_points, app| (entry_points.stop)(app))
+}
 
+unsafe extern "C" fn res_on_event(res: *mut alt_IResource, e: *mut alt_CEvent) -> bool {
     if e.is_null() {
-        panic!("Event is null.");
+        altv::sdk::log::error("[Rust] Event is null.");
+        return true;
     }
 
-    dbg!(alt_CEvent_GetType(e));
-
-    APPS.with(|apps| {
-        let apps = apps.as_ptr();
-        // let mut apps = apps.borrow_mut();
-        let app = (*apps).get_mut(&res).unwrap();
-        app.handle_event(e);
+    with_app(res, "handle_event", |entry_points, app| {
+        (entry_points.handle_event)(app, e)
     });
     true
 }
 
 unsafe extern "C" fn res_on_tick(res: *mut alt_IResource) {
-    // dbg!("Triggered tick resource function.");
-
-    if res.is_null() {
-        altv::sdk::log::error("[Rust] Resource is null.");
-        return;
-    }
-
-    APPS.with(|apps| {
-        let mut apps = apps.borrow_mut();
-        let app = apps.get_mut(&res).unwrap();
-        app.tick();
-    });
+    with_app(res, "tick", |entry_points, app| (entry_points.tick)(app));
 }
 
 unsafe extern "C" fn res_on_create_base_object(
     res: *mut alt_IResource,
     base_obj: *mut alt_RefBase_RefStore_IBaseObject,
 ) {
-    dbg!("Triggered create base object resource function.");
-
-    if res.is_null() {
-        panic!("Resource is null.");
-    }
-
     if base_obj.is_null() {
-        panic!("Base object is null.");
+        altv::sdk::log::error("[Rust] Base object is null.");
+        return;
     }
 
-    dbg!(alt_IBaseObject_GetType((*base_obj).ptr));
-
-    APPS.with(|apps| {
-        let apps = apps.as_ptr();
-        // let mut apps = apps.borrow_mut();
-        let app = (*apps).get_mut(&res).unwrap();
-        app.create_game_object((*base_obj).ptr);
-    });
+    on_base_object(res, Pending::Create((*base_obj).ptr));
 }
 
 unsafe extern "C" fn res_on_remove_base_object(
     res: *mut alt_IResource,
     base_obj: *mut alt_RefBase_RefStore_IBaseObject,
 ) {
-    dbg!("Triggered remove base object resource function.");
-
-    if res.is_null() {
-        panic!("Resource is null.");
-    }
-
     if base_obj.is_null() {
-        panic!("Base object is null.");
+        altv::sdk::log::error("[Rust] Base object is null.");
+        return;
     }
 
-    dbg!(alt_IBaseObject_GetType((*base_obj).ptr));
-
-    APPS.with(|apps| {
-        let apps = apps.as_ptr();
-        // let mut apps = apps.borrow_mut();
-        let app = (*apps).get_mut(&res).unwrap();
-        app.remove_game_object((*base_obj).ptr);
-    });
+    on_base_object(res, Pending::Remove((*base_obj).ptr));
 }
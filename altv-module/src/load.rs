@@ -0,0 +1,197 @@
+use altv::app::{CoreApplication, ResourceMainFn};
+use altv::entry::{EntryPoints, EntryPointsFn};
+use altv::sdk::natives::alt_ICore_Instance;
+use altv::version::{BuildInfoFn, BUILD_INFO_SYMBOL, ENTRY_POINTS_SYMBOL, MAIN_SYMBOL, VERSION};
+use libloading::Library;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+static LOADED_COPIES: AtomicUsize = AtomicUsize::new(0);
+
+// Everything coming from the library is turned into owned data, it is unloaded on errors.
+pub enum LoadError {
+    Copy(io::Error),
+    Library(libloading::Error),
+    Symbol(&'static str, libloading::Error),
+    Version { version: String, sdk_version: u32 },
+    Main(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Copy(e) => write!(f, "could not copy the library for hot reloading: {}", e),
+            LoadError::Library(e) => write!(f, "could not load the library: {}", e),
+            LoadError::Symbol(symbol, e) => {
+                write!(
+                    f,
+                    "the library doesn't export `{}`: {}, declare its entry point with \
+                     `altv::resource!` and build it with altv {}",
+                    symbol, e, VERSION
+                )
+            }
+            LoadError::Version {
+                version,
+                sdk_version,
+            } => write!(
+                f,
+                "the library was built with altv {} for SDK version {}, the module uses altv {} \
+                 for SDK version {}",
+                version,
+                sdk_version,
+                VERSION,
+                altv::sdk::core::SDK_VERSION
+            ),
+            LoadError::Main(e) => write!(f, "main returned an error: {}", e),
+        }
+    }
+}
+
+pub struct Watch {
+    pub path: PathBuf,
+    // The library is loaded from a copy, so the original can be replaced and the copy is
+    // a new file for the dynamic loader.
+    copy: PathBuf,
+    pub modified: Option<SystemTime>,
+    pub changed: Option<SystemTime>,
+    last_check: Instant,
+}
+
+impl Watch {
+    /// Returns true once the library changed and stayed the same for a whole interval.
+    pub fn is_changed(&mut self) -> bool {
+        if self.last_check.elapsed() < WATCH_INTERVAL {
+            return false;
+        }
+
+        self.last_check = Instant::now();
+        let modified = modified(&self.path);
+
+        if modified == self.modified {
+            self.changed = None;
+            return false;
+        }
+
+        if self.changed == modified {
+            return true;
+        }
+
+        self.changed = modified;
+        false
+    }
+
+    pub fn remove_copy(self) {
+        let _ = std::fs::remove_file(self.copy);
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn copy(path: &Path) -> Result<Watch, LoadError> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let copies = LOADED_COPIES.fetch_add(1, Ordering::Relaxed);
+    let copy = std::env::temp_dir().join(format!(
+        "altv-rust-{}-{}-{}",
+        std::process::id(),
+        copies,
+        file_name
+    ));
+
+    let modified = modified(path);
+    std::fs::copy(path, &copy).map_err(LoadError::Copy)?;
+
+    Ok(Watch {
+        path: path.to_owned(),
+        copy,
+        modified,
+        changed: None,
+        last_check: Instant::now(),
+    })
+}
+
+/// A loaded resource library and the application its main function built.
+pub struct Loaded {
+    pub app: CoreApplication,
+    pub entry_points: EntryPoints,
+    pub lib: Library,
+    pub watch: Option<Watch>,
+}
+
+/// Loads the library, checks its build and runs its main function.
+///
+/// In watch mode the library is loaded from a fresh copy, so it can be replaced while running.
+pub unsafe fn load(path: &Path, watch: bool) -> Result<Loaded, LoadError> {
+    let watch = if watch { Some(copy(path)?) } else { None };
+
+    let result = {
+        let path = watch
+            .as_ref()
+            .map(|watch| watch.copy.as_path())
+            .unwrap_or(path);
+        open(path)
+    };
+
+    match result {
+        Ok((app, entry_points, lib)) => Ok(Loaded {
+            app,
+            entry_points,
+            lib,
+            watch,
+        }),
+        Err(e) => {
+            if let Some(watch) = watch {
+                watch.remove_copy();
+            }
+
+            Err(e)
+        }
+    }
+}
+
+unsafe fn open(path: &Path) -> Result<(CoreApplication, EntryPoints, Library), LoadError> {
+    let lib = Library::new(path.as_os_str()).map_err(LoadError::Library)?;
+
+    let build_info = lib
+        .get::<BuildInfoFn>(BUILD_INFO_SYMBOL)
+        .map_err(|e| LoadError::Symbol("altv_rs_build_info", e))?();
+    if !build_info.is_compatible() {
+        return Err(LoadError::Version {
+            version: build_info.version(),
+            sdk_version: build_info.sdk_version(),
+        });
+    }
+
+    let entry_points = lib
+        .get::<EntryPointsFn>(ENTRY_POINTS_SYMBOL)
+        .map_err(|e| LoadError::Symbol("altv_rs_entry_points", e))?();
+
+    let main_fn: ResourceMainFn = *lib
+        .get(MAIN_SYMBOL)
+        .map_err(|e| LoadError::Symbol("altv_main", e))?;
+
+    let result = *Box::from_raw(main_fn(alt_ICore_Instance()));
+    let app = result.map_err(|e| LoadError::Main(error_chain(&*e)))?;
+    Ok((app, entry_points, lib))
+}
+
+fn error_chain(e: &dyn Error) -> String {
+    let mut chain = e.to_string();
+
+    let mut source = e.source();
+    while let Some(e) = source {
+        chain.push_str(&format!("\n  caused by: {}", e));
+        source = e.source();
+    }
+
+    chain
+}
@@ -2,6 +2,7 @@ use crate::mvalue::MValue;
 use crate::natives::*;
 use crate::string::String;
 use crate::string_view::StringView;
+use altv_core::AltResource;
 use std::slice::Iter;
 
 // struct Array<T> {
@@ -32,32 +33,40 @@ impl From<alt_Array_String> for Vec<String> {
     }
 }
 
-impl From<alt_Array_RefBase_RefStore_constIMValue> for Vec<MValue> {
-    fn from(v: alt_Array_RefBase_RefStore_constIMValue) -> Self {
-        let mut vec = Vec::new();
-
-        for n in 0..v.size {
-            vec.push(MValue::new(unsafe { (*v.data.offset(n as isize)).ptr }));
-        }
+pub fn convert_array_to_vec_mvalue(
+    alt: &AltResource,
+    v: alt_Array_RefBase_RefStore_constIMValue,
+) -> crate::Result<Vec<MValue>> {
+    let mut vec = Vec::new();
 
-        vec
+    for n in 0..v.size {
+        vec.push(MValue::new(alt, unsafe {
+            (*v.data.offset(n as isize)).ptr
+        })?);
     }
+
+    Ok(vec)
 }
 
 pub fn convert_iter_to_array_mvalue(
+    alt: &AltResource,
     v: Iter<MValue>,
-) -> *mut alt_Array_RefBase_RefStore_constIMValue {
+) -> crate::Result<*mut alt_Array_RefBase_RefStore_constIMValue> {
+    for val in v.clone() {
+        val.check_entities(alt)?;
+    }
+
     unsafe {
         let arr = alt_Array_RefBase_RefStore_constIMValue_Create_CAPI_Heap();
 
         for val in v {
-            let ptr: *mut alt_RefBase_RefStore_IMValue = val.clone().into();
+            let ptr = val.clone().into_ref(alt)?;
             alt_Array_RefBase_RefStore_constIMValue_Push(
                 arr,
                 ptr as *mut alt_RefBase_RefStore_constIMValue,
             );
         }
 
-        arr
+        Ok(arr)
     }
 }
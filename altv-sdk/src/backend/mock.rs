@@ -0,0 +1,2069 @@
+use super::CoreBackend;
+use crate::mvalue::MValue;
+use crate::natives::*;
+use crate::rgba::Rgba;
+use crate::vector::{Rotation3, Vector3};
+use altv_core::AltResource;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Error,
+    Warning,
+    Debug,
+    Colored,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientEvent {
+    pub target: Option<*mut alt_IPlayer>,
+    pub name: String,
+    pub args: Vec<MValue>,
+}
+
+#[derive(Copy, Clone)]
+enum Shape {
+    Sphere(Vector3, f32),
+    Cylinder(Vector3, f32, f32),
+    Circle(Vector3, f32),
+    Cuboid(Vector3, Vector3),
+    Rectangle(f32, f32, f32, f32),
+}
+
+impl Shape {
+    fn get_type(&self) -> alt_IColShape_ColShapeType {
+        match self {
+            Shape::Sphere(..) => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_SPHERE,
+            Shape::Cylinder(..) => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CYLINDER,
+            Shape::Circle(..) => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CIRCLE,
+            Shape::Cuboid(..) => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CUBOID,
+            Shape::Rectangle(..) => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_RECT,
+        }
+    }
+
+    fn moved(self, offset: Vector3) -> Shape {
+        match self {
+            Shape::Sphere(c, radius) => Shape::Sphere(c + offset, radius),
+            Shape::Cylinder(c, radius, height) => Shape::Cylinder(c + offset, radius, height),
+            Shape::Circle(c, radius) => Shape::Circle(c + offset, radius),
+            Shape::Cuboid(a, b) => Shape::Cuboid(a + offset, b + offset),
+            Shape::Rectangle(x1, y1, x2, y2) => {
+                Shape::Rectangle(x1 + offset.x, y1 + offset.y, x2 + offset.x, y2 + offset.y)
+            }
+        }
+    }
+
+    fn contains(&self, p: Vector3) -> bool {
+        let distance_2d = |c: Vector3| ((p.x - c.x).powi(2) + (p.y - c.y).powi(2)).sqrt();
+
+        match *self {
+            Shape::Sphere(c, radius) => (p - c).norm() <= radius,
+            Shape::Cylinder(c, radius, height) => {
+                distance_2d(c) <= radius && p.z >= c.z && p.z <= c.z + height
+            }
+            Shape::Circle(c, radius) => distance_2d(c) <= radius,
+            Shape::Cuboid(a, b) => {
+                p.x >= a.x.min(b.x)
+                    && p.x <= a.x.max(b.x)
+                    && p.y >= a.y.min(b.y)
+                    && p.y <= a.y.max(b.y)
+                    && p.z >= a.z.min(b.z)
+                    && p.z <= a.z.max(b.z)
+            }
+            Shape::Rectangle(x1, y1, x2, y2) => {
+                p.x >= x1.min(x2) && p.x <= x1.max(x2) && p.y >= y1.min(y2) && p.y <= y1.max(y2)
+            }
+        }
+    }
+}
+
+struct MockObject {
+    object_type: alt_IBaseObject_Type,
+    ref_count: u64,
+    removed: bool,
+    meta_data: HashMap<String, usize>,
+    synced_meta_data: HashMap<String, usize>,
+    stream_synced_meta_data: HashMap<String, usize>,
+    properties: HashMap<String, MValue>,
+    shape: Option<Shape>,
+}
+
+impl MockObject {
+    fn new(object_type: alt_IBaseObject_Type) -> Self {
+        MockObject {
+            object_type,
+            ref_count: 1,
+            removed: false,
+            meta_data: HashMap::new(),
+            synced_meta_data: HashMap::new(),
+            stream_synced_meta_data: HashMap::new(),
+            properties: HashMap::new(),
+            shape: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum MockMValue {
+    Value(MValue),
+    List(Vec<usize>),
+    Dict(BTreeMap<String, usize>),
+    BaseObject(usize),
+}
+
+#[derive(Default)]
+struct MockState {
+    elapsed: Duration,
+    last_id: usize,
+    objects: HashMap<usize, MockObject>,
+    mvalues: HashMap<usize, MockMValue>,
+    client_events: Vec<ClientEvent>,
+    logs: Vec<(LogLevel, String)>,
+}
+
+/// In-memory alt:V core for running resources without a server.
+///
+/// Objects are plain property bags addressed by fake pointers, so every element
+/// wrapper works unchanged. Client events and log lines are recorded for assertions.
+pub struct MockCore {
+    started_at: Instant,
+    state: RefCell<MockState>,
+}
+
+fn to_ptr<T>(id: usize) -> *mut T {
+    (id << 4) as *mut T
+}
+
+fn to_id<T>(ptr: *mut T) -> usize {
+    ptr as usize >> 4
+}
+
+impl Default for MockCore {
+    fn default() -> Self {
+        MockCore::new()
+    }
+}
+
+impl MockCore {
+    pub fn new() -> Self {
+        MockCore {
+            started_at: Instant::now(),
+            state: RefCell::new(MockState::default()),
+        }
+    }
+
+    /// Moves the clock returned by `now` forward, the wall clock is never consulted.
+    pub fn advance_time(&self, duration: Duration) {
+        self.state.borrow_mut().elapsed += duration;
+    }
+
+    /// Creates a connected player, the caller still has to announce it to the application.
+    pub fn create_player(&self, name: &str) -> *mut alt_IBaseObject {
+        let player = self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER);
+        self.set(player, "name", name.to_owned());
+        self.set(player, "connected", true);
+        player
+    }
+
+    /// Drops a player after it disconnected, unlike `destroy_base_object`.
+    pub fn remove_player(&self, base_obj: *mut alt_IBaseObject) {
+        self.set(base_obj, "connected", false);
+        self.remove(base_obj);
+    }
+
+    /// Returns whether the object is still in the game, removed objects can outlive this while refs are held.
+    pub fn exists(&self, base_obj: *mut alt_IBaseObject) -> bool {
+        match self.state.borrow().objects.get(&to_id(base_obj)) {
+            Some(object) => !object.removed,
+            None => false,
+        }
+    }
+
+    /// Returns whether the memory of the object is still around, i.e. some ref on it is left.
+    pub fn is_allocated<T>(&self, ptr: *mut T) -> bool {
+        self.state.borrow().objects.contains_key(&to_id(ptr))
+    }
+
+    /// Reads a property the element API can only write, such as `weather` or `kick_reason`.
+    pub fn get_property<T>(&self, ptr: *mut T, key: &str) -> Option<MValue> {
+        self.get(ptr, key)
+    }
+
+    pub fn get_client_events(&self) -> Vec<ClientEvent> {
+        self.state.borrow().client_events.clone()
+    }
+
+    pub fn take_client_events(&self) -> Vec<ClientEvent> {
+        std::mem::take(&mut self.state.borrow_mut().client_events)
+    }
+
+    pub fn get_logs(&self) -> Vec<(LogLevel, String)> {
+        self.state.borrow().logs.clone()
+    }
+
+    fn create_object<T>(&self, object_type: alt_IBaseObject_Type) -> *mut T {
+        let mut state = self.state.borrow_mut();
+        state.last_id += 1;
+        let id = state.last_id;
+        state.objects.insert(id, MockObject::new(object_type));
+        to_ptr(id)
+    }
+
+    fn with_object<T, R>(&self, ptr: *mut T, f: impl FnOnce(&mut MockObject) -> R) -> R {
+        let mut state = self.state.borrow_mut();
+
+        match state.objects.get_mut(&to_id(ptr)) {
+            Some(object) => f(object),
+            None => panic!("[Mock] Object {:?} does not exist.", ptr),
+        }
+    }
+
+    /// Takes the object out of the game and drops the ref the core held on it.
+    fn remove<T>(&self, ptr: *mut T) {
+        self.with_object(ptr, |object| object.removed = true);
+        self.release(ptr);
+    }
+
+    fn release<T>(&self, ptr: *mut T) {
+        let ref_count = self.with_object(ptr, |object| {
+            object.ref_count -= 1;
+            object.ref_count
+        });
+
+        if ref_count == 0 {
+            self.state.borrow_mut().objects.remove(&to_id(ptr));
+        }
+    }
+
+    fn get_type<T>(&self, ptr: *mut T) -> alt_IBaseObject_Type {
+        self.with_object(ptr, |object| object.object_type)
+    }
+
+    fn cast<T, R>(&self, ptr: *mut T, is_type: impl Fn(alt_IBaseObject_Type) -> bool) -> *mut R {
+        if ptr.is_null() || !is_type(self.get_type(ptr)) {
+            return std::ptr::null_mut();
+        }
+
+        ptr as *mut R
+    }
+
+    fn log(&self, level: LogLevel, msg: &str) {
+        println!("[{:?}] {}", level, msg);
+        self.state.borrow_mut().logs.push((level, msg.to_owned()));
+    }
+
+    fn get<T>(&self, ptr: *mut T, key: &str) -> Option<MValue> {
+        self.with_object(ptr, |object| object.properties.get(key).cloned())
+    }
+
+    fn set<T, V: Into<MValue>>(&self, ptr: *mut T, key: &str, value: V) {
+        let value = value.into();
+        self.with_object(ptr, |object| {
+            object.properties.insert(key.to_owned(), value)
+        });
+    }
+
+    fn get_bool<T>(&self, ptr: *mut T, key: &str) -> bool {
+        match self.get(ptr, key) {
+            Some(MValue::Bool(v)) => v,
+            _ => false,
+        }
+    }
+
+    fn get_uint<T>(&self, ptr: *mut T, key: &str, default: u64) -> u64 {
+        match self.get(ptr, key) {
+            Some(MValue::Uint(v)) => v,
+            _ => default,
+        }
+    }
+
+    fn get_int<T>(&self, ptr: *mut T, key: &str, default: i64) -> i64 {
+        match self.get(ptr, key) {
+            Some(MValue::Int(v)) => v,
+            _ => default,
+        }
+    }
+
+    fn get_double<T>(&self, ptr: *mut T, key: &str, default: f64) -> f64 {
+        match self.get(ptr, key) {
+            Some(MValue::Double(v)) => v,
+            _ => default,
+        }
+    }
+
+    fn get_string<T>(&self, ptr: *mut T, key: &str) -> String {
+        match self.get(ptr, key) {
+            Some(MValue::String(v)) => v,
+            _ => String::new(),
+        }
+    }
+
+    fn get_vector3<T>(&self, ptr: *mut T, key: &str) -> Vector3 {
+        match self.get(ptr, key) {
+            Some(MValue::Vector3(v)) => v,
+            _ => Vector3::zeros(),
+        }
+    }
+
+    fn get_rgba<T>(&self, ptr: *mut T, key: &str) -> Rgba {
+        match self.get(ptr, key) {
+            Some(MValue::Rgba(v)) => v,
+            _ => Rgba::default(),
+        }
+    }
+
+    fn get_object<T, R>(&self, ptr: *mut T, key: &str) -> *mut R {
+        to_ptr(self.get_uint(ptr, key, 0) as usize)
+    }
+
+    /// Moves an object, collision shapes are shifted along with it.
+    fn set_position<T>(&self, ptr: *mut T, position: Vector3) {
+        let offset = position - self.get_vector3(ptr, "position");
+        self.with_object(ptr, |object| {
+            object.shape = object.shape.map(|shape| shape.moved(offset))
+        });
+        self.set(ptr, "position", position)
+    }
+
+    fn create_col_shape(&self, shape: Shape, position: Vector3) -> *mut alt_IColShape {
+        let col_shape = self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE);
+        self.set(col_shape, "position", position);
+        self.with_object(col_shape, |object| object.shape = Some(shape));
+        col_shape
+    }
+
+    fn create_mvalue(&self, mvalue: MockMValue) -> *mut alt_RefBase_RefStore_IMValue {
+        let mut state = self.state.borrow_mut();
+        state.last_id += 1;
+        let id = state.last_id;
+        state.mvalues.insert(id, mvalue);
+        to_ptr(id)
+    }
+
+    fn with_mvalue<T, R>(&self, ptr: *mut T, f: impl FnOnce(&mut MockMValue) -> R) -> R {
+        let mut state = self.state.borrow_mut();
+
+        match state.mvalues.get_mut(&to_id(ptr)) {
+            Some(mvalue) => f(mvalue),
+            None => panic!("[Mock] MValue {:?} does not exist.", ptr),
+        }
+    }
+
+    fn get_mvalue(&self, ptr: *mut alt_IMValue) -> MValue {
+        match self.with_mvalue(ptr, |mvalue| mvalue.clone()) {
+            MockMValue::Value(v) => v,
+            _ => panic!("[Mock] MValue {:?} is a container.", ptr),
+        }
+    }
+
+    /// Reads a stored meta value like the core would, through its `IMValue`.
+    fn read_meta(&self, alt: &AltResource, id: Option<usize>) -> crate::Result<MValue> {
+        match id {
+            Some(id) => MValue::new(alt, to_ptr(id)),
+            None => Ok(MValue::None),
+        }
+    }
+
+    fn opaque(color: Rgba) -> Rgba {
+        Rgba::new(color.r, color.g, color.b, 255)
+    }
+}
+
+impl CoreBackend for MockCore {
+    fn log_info(&self, msg: &str) {
+        self.log(LogLevel::Info, msg)
+    }
+
+    fn log_error(&self, msg: &str) {
+        self.log(LogLevel::Error, msg)
+    }
+
+    fn log_warning(&self, msg: &str) {
+        self.log(LogLevel::Warning, msg)
+    }
+
+    fn log_debug(&self, msg: &str) {
+        self.log(LogLevel::Debug, msg)
+    }
+
+    fn log_colored(&self, msg: &str) {
+        self.log(LogLevel::Colored, msg)
+    }
+
+    fn now(&self) -> Instant {
+        self.started_at + self.state.borrow().elapsed
+    }
+
+    fn create_vehicle(
+        &self,
+        model: u32,
+        position: Vector3,
+        rotation: Rotation3,
+    ) -> *mut alt_IVehicle {
+        if model == 0 {
+            return std::ptr::null_mut();
+        }
+
+        let vehicle = self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE);
+        self.set(vehicle, "model", u64::from(model));
+        self.set(vehicle, "position", position);
+        self.entity_set_rotation(vehicle as *mut alt_IEntity, rotation);
+        vehicle
+    }
+
+    fn create_col_shape_sphere(&self, position: Vector3, radius: f32) -> *mut alt_IColShape {
+        self.create_col_shape(Shape::Sphere(position, radius), position)
+    }
+
+    fn create_col_shape_cube(
+        &self,
+        start_position: Vector3,
+        end_position: Vector3,
+    ) -> *mut alt_IColShape {
+        self.create_col_shape(Shape::Cuboid(start_position, end_position), start_position)
+    }
+
+    fn create_col_shape_rectangle(
+        &self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        z: f32,
+    ) -> *mut alt_IColShape {
+        self.create_col_shape(Shape::Rectangle(x1, y1, x2, y2), Vector3::new(x1, y1, z))
+    }
+
+    fn create_col_shape_circle(&self, position: Vector3, radius: f32) -> *mut alt_IColShape {
+        self.create_col_shape(Shape::Circle(position, radius), position)
+    }
+
+    fn create_col_shape_cylinder(
+        &self,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+    ) -> *mut alt_IColShape {
+        self.create_col_shape(Shape::Cylinder(position, radius, height), position)
+    }
+
+    fn create_checkpoint(
+        &self,
+        checkpoint_type: u8,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+        color: Rgba,
+    ) -> *mut alt_ICheckpoint {
+        let checkpoint = self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT);
+        self.set(checkpoint, "checkpoint_type", u64::from(checkpoint_type));
+        self.set(checkpoint, "position", position);
+        self.set(checkpoint, "radius", f64::from(radius));
+        self.set(checkpoint, "height", f64::from(height));
+        self.set(checkpoint, "color", color);
+        checkpoint
+    }
+
+    fn create_blip(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        position: Vector3,
+    ) -> *mut alt_IBlip {
+        let blip = self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP);
+        self.set(blip, "blip_type", blip_type as u64);
+        self.set(blip, "position", position);
+
+        if let Some(target) = target {
+            self.set(blip, "target", to_id(target) as u64);
+        }
+
+        blip
+    }
+
+    fn create_blip_attached(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        attach_to: *mut alt_IEntity,
+    ) -> *mut alt_IBlip {
+        let position = self.get_vector3(attach_to, "position");
+        let blip = self.create_blip(target, blip_type, position);
+        self.set(blip, "attached_to", to_id(attach_to) as u64);
+        blip
+    }
+
+    fn create_voice_channel(&self, spatial: bool, max_distance: f32) -> *mut alt_IVoiceChannel {
+        let voice_channel =
+            self.create_object(alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL);
+        self.set(voice_channel, "spatial", spatial);
+        self.set(voice_channel, "max_distance", f64::from(max_distance));
+        voice_channel
+    }
+
+    fn destroy_base_object(&self, base_obj: *mut alt_IBaseObject) {
+        // Players can only leave by disconnecting.
+        if self.get_type(base_obj) == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER {
+            return;
+        }
+
+        // Destroying an object the resource holds no ref to is a use after free in the core.
+        assert!(
+            self.with_object(base_obj, |object| object.ref_count) > 1,
+            "destroyed an object without holding a ref to it"
+        );
+        self.remove(base_obj);
+    }
+
+    fn trigger_client_event(
+        &self,
+        alt: &AltResource,
+        target: Option<*mut alt_IPlayer>,
+        name: &str,
+        args: &[MValue],
+    ) -> crate::Result<()> {
+        for arg in args {
+            arg.check_entities(alt)?;
+        }
+
+        self.state.borrow_mut().client_events.push(ClientEvent {
+            target,
+            name: name.to_owned(),
+            args: args.to_vec(),
+        });
+        Ok(())
+    }
+
+    fn create_mvalue_none(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::None))
+    }
+
+    fn create_mvalue_nil(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Nil))
+    }
+
+    fn create_mvalue_bool(&self, value: bool) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Bool(value)))
+    }
+
+    fn create_mvalue_int(&self, value: i64) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Int(value)))
+    }
+
+    fn create_mvalue_uint(&self, value: u64) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Uint(value)))
+    }
+
+    fn create_mvalue_double(&self, value: f64) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Double(value)))
+    }
+
+    fn create_mvalue_string(&self, value: &str) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::String(value.to_owned())))
+    }
+
+    fn create_mvalue_list(&self, size: u64) -> *mut alt_RefBase_RefStore_IMValue {
+        let list = (0..size)
+            .map(|_| to_id(self.create_mvalue_none()))
+            .collect();
+        self.create_mvalue(MockMValue::List(list))
+    }
+
+    fn create_mvalue_dict(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Dict(BTreeMap::new()))
+    }
+
+    fn create_mvalue_base_object(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_RefBase_RefStore_IMValue {
+        // The value keeps its object allocated, like a ref held by the core.
+        self.with_object(base_obj, |object| object.ref_count += 1);
+        self.create_mvalue(MockMValue::BaseObject(to_id(base_obj)))
+    }
+
+    fn create_mvalue_vector3(&self, value: Vector3) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Vector3(value)))
+    }
+
+    fn create_mvalue_rgba(&self, value: Rgba) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::Rgba(value)))
+    }
+
+    fn create_mvalue_byte_array(&self, value: Vec<u8>) -> *mut alt_RefBase_RefStore_IMValue {
+        self.create_mvalue(MockMValue::Value(MValue::ByteArray(value)))
+    }
+
+    fn mvalue_ref_get(&self, mvalue: *mut alt_RefBase_RefStore_IMValue) -> *mut alt_IMValue {
+        mvalue as *mut alt_IMValue
+    }
+
+    fn mvalue_get_type(&self, mvalue: *mut alt_IMValue) -> alt_IMValue_Type {
+        match self.with_mvalue(mvalue, |mvalue| mvalue.clone()) {
+            MockMValue::Value(MValue::None) => alt_IMValue_Type::ALT_IMVALUE_TYPE_NONE,
+            MockMValue::Value(MValue::Nil) => alt_IMValue_Type::ALT_IMVALUE_TYPE_NIL,
+            MockMValue::Value(MValue::Bool(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_BOOL,
+            MockMValue::Value(MValue::Int(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_INT,
+            MockMValue::Value(MValue::Uint(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_UINT,
+            MockMValue::Value(MValue::Double(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_DOUBLE,
+            MockMValue::Value(MValue::String(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_STRING,
+            MockMValue::Value(MValue::Vector3(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_VECTOR3,
+            MockMValue::Value(MValue::Rgba(_)) => alt_IMValue_Type::ALT_IMVALUE_TYPE_RGBA,
+            MockMValue::Value(MValue::ByteArray(_)) => {
+                alt_IMValue_Type::ALT_IMVALUE_TYPE_BYTE_ARRAY
+            }
+            MockMValue::Value(MValue::List(_)) | MockMValue::List(_) => {
+                alt_IMValue_Type::ALT_IMVALUE_TYPE_LIST
+            }
+            MockMValue::Value(MValue::Dict(_)) | MockMValue::Dict(_) => {
+                alt_IMValue_Type::ALT_IMVALUE_TYPE_DICT
+            }
+            MockMValue::Value(MValue::BaseObject(_)) | MockMValue::BaseObject(_) => {
+                alt_IMValue_Type::ALT_IMVALUE_TYPE_BASE_OBJECT
+            }
+        }
+    }
+
+    fn mvalue_get_bool(&self, mvalue: *mut alt_IMValue) -> bool {
+        match self.get_mvalue(mvalue) {
+            MValue::Bool(v) => v,
+            v => panic!("[Mock] Expected a bool, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_int(&self, mvalue: *mut alt_IMValue) -> i64 {
+        match self.get_mvalue(mvalue) {
+            MValue::Int(v) => v,
+            v => panic!("[Mock] Expected an int, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_uint(&self, mvalue: *mut alt_IMValue) -> u64 {
+        match self.get_mvalue(mvalue) {
+            MValue::Uint(v) => v,
+            v => panic!("[Mock] Expected an uint, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_double(&self, mvalue: *mut alt_IMValue) -> f64 {
+        match self.get_mvalue(mvalue) {
+            MValue::Double(v) => v,
+            v => panic!("[Mock] Expected a double, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_string(&self, mvalue: *mut alt_IMValue) -> String {
+        match self.get_mvalue(mvalue) {
+            MValue::String(v) => v,
+            v => panic!("[Mock] Expected a string, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_base_object(&self, mvalue: *mut alt_IMValue) -> *mut alt_IBaseObject {
+        self.with_mvalue(mvalue, |v| match v {
+            MockMValue::BaseObject(id) => to_ptr(*id),
+            _ => panic!("[Mock] MValue {:?} is not a base object.", mvalue),
+        })
+    }
+
+    fn mvalue_get_vector3(&self, mvalue: *mut alt_IMValue) -> Vector3 {
+        match self.get_mvalue(mvalue) {
+            MValue::Vector3(v) => v,
+            v => panic!("[Mock] Expected a vector3, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_rgba(&self, mvalue: *mut alt_IMValue) -> Rgba {
+        match self.get_mvalue(mvalue) {
+            MValue::Rgba(v) => v,
+            v => panic!("[Mock] Expected a rgba, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_get_byte_array(&self, mvalue: *mut alt_IMValue) -> Vec<u8> {
+        match self.get_mvalue(mvalue) {
+            MValue::ByteArray(v) => v,
+            v => panic!("[Mock] Expected a byte array, got {:?}.", v),
+        }
+    }
+
+    fn mvalue_list_get_size(&self, list: *mut alt_IMValue) -> u64 {
+        self.with_mvalue(list, |mvalue| match mvalue {
+            MockMValue::List(v) => v.len() as u64,
+            _ => panic!("[Mock] MValue {:?} is not a list.", list),
+        })
+    }
+
+    fn mvalue_list_get(&self, list: *mut alt_IMValue, index: u64) -> *mut alt_IMValue {
+        self.with_mvalue(list, |mvalue| match mvalue {
+            MockMValue::List(v) => to_ptr(v[index as usize]),
+            _ => panic!("[Mock] MValue {:?} is not a list.", list),
+        })
+    }
+
+    fn mvalue_list_set(
+        &self,
+        list: *mut alt_RefBase_RefStore_IMValue,
+        index: u64,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    ) {
+        self.with_mvalue(list, |mvalue| match mvalue {
+            MockMValue::List(v) => v[index as usize] = to_id(value),
+            _ => panic!("[Mock] MValue {:?} is not a list.", list),
+        })
+    }
+
+    fn mvalue_dict_get_keys(&self, dict: *mut alt_IMValue) -> Vec<String> {
+        self.with_mvalue(dict, |mvalue| match mvalue {
+            MockMValue::Dict(v) => v.keys().cloned().collect(),
+            _ => panic!("[Mock] MValue {:?} is not a dict.", dict),
+        })
+    }
+
+    fn mvalue_dict_get(&self, dict: *mut alt_IMValue, key: &str) -> *mut alt_IMValue {
+        let id = self.with_mvalue(dict, |mvalue| match mvalue {
+            MockMValue::Dict(v) => v.get(key).copied(),
+            _ => panic!("[Mock] MValue {:?} is not a dict.", dict),
+        });
+
+        match id {
+            Some(id) => to_ptr(id),
+            None => self.create_mvalue_none() as *mut alt_IMValue,
+        }
+    }
+
+    fn mvalue_dict_set(
+        &self,
+        dict: *mut alt_RefBase_RefStore_IMValue,
+        key: &str,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    ) {
+        self.with_mvalue(dict, |mvalue| match mvalue {
+            MockMValue::Dict(v) => {
+                v.insert(key.to_owned(), to_id(value));
+            }
+            _ => panic!("[Mock] MValue {:?} is not a dict.", dict),
+        })
+    }
+
+    fn base_object_to_ref_countable(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_CRefCountable {
+        self.cast(base_obj, |_| true)
+    }
+
+    fn base_object_to_world_object(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IWorldObject {
+        self.cast(base_obj, |t| {
+            t != alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL
+        })
+    }
+
+    fn base_object_to_entity(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IEntity {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER
+                || t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE
+        })
+    }
+
+    fn base_object_to_player(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IPlayer {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER
+        })
+    }
+
+    fn base_object_to_vehicle(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IVehicle {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE
+        })
+    }
+
+    fn base_object_to_blip(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IBlip {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP
+        })
+    }
+
+    fn base_object_to_voice_channel(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_IVoiceChannel {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL
+        })
+    }
+
+    fn base_object_to_col_shape(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IColShape {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE
+                || t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT
+        })
+    }
+
+    fn base_object_to_checkpoint(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_ICheckpoint {
+        self.cast(base_obj, |t| {
+            t == alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT
+        })
+    }
+
+    fn entity_to_base_object(&self, entity: *mut alt_IEntity) -> *mut alt_IBaseObject {
+        entity as *mut alt_IBaseObject
+    }
+
+    fn vehicle_to_base_object(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IBaseObject {
+        vehicle as *mut alt_IBaseObject
+    }
+
+    fn col_shape_to_base_object(&self, col_shape: *mut alt_IColShape) -> *mut alt_IBaseObject {
+        col_shape as *mut alt_IBaseObject
+    }
+
+    fn checkpoint_to_base_object(&self, checkpoint: *mut alt_ICheckpoint) -> *mut alt_IBaseObject {
+        checkpoint as *mut alt_IBaseObject
+    }
+
+    fn blip_to_base_object(&self, blip: *mut alt_IBlip) -> *mut alt_IBaseObject {
+        blip as *mut alt_IBaseObject
+    }
+
+    fn voice_channel_to_base_object(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+    ) -> *mut alt_IBaseObject {
+        voice_channel as *mut alt_IBaseObject
+    }
+
+    fn ref_countable_get_ref_count(&self, ref_countable: *mut alt_CRefCountable) -> u64 {
+        self.with_object(ref_countable, |object| object.ref_count)
+    }
+
+    fn ref_countable_add_ref(&self, ref_countable: *mut alt_CRefCountable) {
+        self.with_object(ref_countable, |object| object.ref_count += 1)
+    }
+
+    fn ref_countable_remove_ref(&self, ref_countable: *mut alt_CRefCountable) {
+        self.release(ref_countable);
+    }
+
+    fn base_object_get_type(&self, base_obj: *mut alt_IBaseObject) -> alt_IBaseObject_Type {
+        self.get_type(base_obj)
+    }
+
+    fn base_object_has_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str) -> bool {
+        self.with_object(base_obj, |object| object.meta_data.contains_key(key))
+    }
+
+    fn base_object_get_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        let id = self.with_object(base_obj, |object| object.meta_data.get(key).copied());
+        self.read_meta(alt, id)
+    }
+
+    fn base_object_set_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let id = to_id(value.into_ref(alt)?);
+        self.with_object(base_obj, |object| {
+            object.meta_data.insert(key.to_owned(), id)
+        });
+        Ok(())
+    }
+
+    fn base_object_delete_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str) {
+        self.with_object(base_obj, |object| object.meta_data.remove(key));
+    }
+
+    fn world_object_get_dimension(&self, world_obj: *mut alt_IWorldObject) -> i32 {
+        self.get_int(world_obj, "dimension", 0) as i32
+    }
+
+    fn world_object_set_dimension(&self, world_obj: *mut alt_IWorldObject, dimension: i32) {
+        self.set(world_obj, "dimension", i64::from(dimension))
+    }
+
+    fn world_object_get_position(&self, world_obj: *mut alt_IWorldObject) -> Vector3 {
+        self.get_vector3(world_obj, "position")
+    }
+
+    fn world_object_set_position(&self, world_obj: *mut alt_IWorldObject, position: Vector3) {
+        self.set_position(world_obj, position)
+    }
+
+    fn entity_get_id(&self, entity: *mut alt_IEntity) -> u16 {
+        to_id(entity) as u16
+    }
+
+    fn entity_get_network_owner(&self, entity: *mut alt_IEntity) -> *mut alt_IPlayer {
+        self.get_object(entity, "network_owner")
+    }
+
+    fn entity_get_model(&self, entity: *mut alt_IEntity) -> u32 {
+        self.get_uint(entity, "model", 0) as u32
+    }
+
+    fn entity_get_rotation(&self, entity: *mut alt_IEntity) -> Rotation3 {
+        let euler = self.get_vector3(entity, "rotation");
+        Rotation3::from_euler_angles(euler.x, euler.y, euler.z)
+    }
+
+    fn entity_set_rotation(&self, entity: *mut alt_IEntity, rotation: Rotation3) {
+        let (roll, pitch, yaw) = rotation.euler_angles();
+        self.set(entity, "rotation", Vector3::new(roll, pitch, yaw))
+    }
+
+    fn entity_has_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool {
+        self.with_object(entity, |object| object.synced_meta_data.contains_key(key))
+    }
+
+    fn entity_get_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        let id = self.with_object(entity, |object| object.synced_meta_data.get(key).copied());
+        self.read_meta(alt, id)
+    }
+
+    fn entity_set_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let id = to_id(value.into_ref(alt)?);
+        self.with_object(entity, |object| {
+            object.synced_meta_data.insert(key.to_owned(), id)
+        });
+        Ok(())
+    }
+
+    fn entity_delete_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) {
+        self.with_object(entity, |object| object.synced_meta_data.remove(key));
+    }
+
+    fn entity_has_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool {
+        self.with_object(entity, |object| {
+            object.stream_synced_meta_data.contains_key(key)
+        })
+    }
+
+    fn entity_get_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        let id = self.with_object(entity, |object| {
+            object.stream_synced_meta_data.get(key).copied()
+        });
+        self.read_meta(alt, id)
+    }
+
+    fn entity_set_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let id = to_id(value.into_ref(alt)?);
+        self.with_object(entity, |object| {
+            object.stream_synced_meta_data.insert(key.to_owned(), id)
+        });
+        Ok(())
+    }
+
+    fn entity_delete_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) {
+        self.with_object(entity, |object| object.stream_synced_meta_data.remove(key));
+    }
+
+    fn player_is_connected(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "connected")
+    }
+
+    fn player_get_ping(&self, player: *mut alt_IPlayer) -> u32 {
+        self.get_uint(player, "ping", 0) as u32
+    }
+
+    fn player_get_ip(&self, player: *mut alt_IPlayer) -> String {
+        match self.get(player, "ip") {
+            Some(MValue::String(ip)) => ip,
+            _ => "127.0.0.1".to_owned(),
+        }
+    }
+
+    fn player_spawn(&self, player: *mut alt_IPlayer, position: Vector3, _delay: Duration) {
+        self.set(player, "position", position);
+        self.set(
+            player,
+            "health",
+            u64::from(self.player_get_max_health(player)),
+        );
+    }
+
+    fn player_despawn(&self, player: *mut alt_IPlayer) {
+        self.set(player, "health", 0u64)
+    }
+
+    fn player_get_name(&self, player: *mut alt_IPlayer) -> String {
+        self.get_string(player, "name")
+    }
+
+    fn player_get_social_id(&self, player: *mut alt_IPlayer) -> u64 {
+        self.get_uint(player, "social_id", 0)
+    }
+
+    fn player_get_hwid_hash(&self, player: *mut alt_IPlayer) -> u64 {
+        self.get_uint(player, "hwid_hash", 0)
+    }
+
+    fn player_get_hwid_ex_hash(&self, player: *mut alt_IPlayer) -> u64 {
+        self.get_uint(player, "hwid_ex_hash", 0)
+    }
+
+    fn player_get_auth_token(&self, player: *mut alt_IPlayer) -> String {
+        self.get_string(player, "auth_token")
+    }
+
+    fn player_get_health(&self, player: *mut alt_IPlayer) -> u16 {
+        self.get_uint(player, "health", 200) as u16
+    }
+
+    fn player_set_health(&self, player: *mut alt_IPlayer, health: u16) {
+        self.set(player, "health", u64::from(health))
+    }
+
+    fn player_get_max_health(&self, player: *mut alt_IPlayer) -> u16 {
+        self.get_uint(player, "max_health", 200) as u16
+    }
+
+    fn player_set_max_health(&self, player: *mut alt_IPlayer, max_health: u16) {
+        self.set(player, "max_health", u64::from(max_health))
+    }
+
+    fn player_set_date_time(
+        &self,
+        player: *mut alt_IPlayer,
+        day: i32,
+        month: i32,
+        year: i32,
+        hour: i32,
+        minute: i32,
+        second: i32,
+    ) {
+        let date_time = [day, month, year, hour, minute, second]
+            .iter()
+            .map(|v| MValue::Int(i64::from(*v)))
+            .collect::<Vec<_>>();
+        self.set(player, "date_time", MValue::List(date_time))
+    }
+
+    fn player_set_weather(&self, player: *mut alt_IPlayer, weather: u32) {
+        self.set(player, "weather", u64::from(weather))
+    }
+
+    fn player_give_weapon(&self, player: *mut alt_IPlayer, weapon: u32, ammo: i32, select: bool) {
+        self.set(player, &format!("weapon:{}", weapon), i64::from(ammo));
+
+        if select {
+            self.player_set_current_weapon(player, weapon);
+        }
+    }
+
+    fn player_remove_weapon(&self, player: *mut alt_IPlayer, weapon: u32) {
+        self.with_object(player, |object| {
+            object.properties.remove(&format!("weapon:{}", weapon))
+        });
+    }
+
+    fn player_remove_all_weapons(&self, player: *mut alt_IPlayer) {
+        self.with_object(player, |object| {
+            object
+                .properties
+                .retain(|key, _| !key.starts_with("weapon:"))
+        });
+        self.player_set_current_weapon(player, 0);
+    }
+
+    fn player_add_weapon_component(&self, player: *mut alt_IPlayer, weapon: u32, component: u32) {
+        self.set(player, &format!("component:{}:{}", weapon, component), true)
+    }
+
+    fn player_remove_weapon_component(
+        &self,
+        player: *mut alt_IPlayer,
+        weapon: u32,
+        component: u32,
+    ) {
+        self.set(
+            player,
+            &format!("component:{}:{}", weapon, component),
+            false,
+        )
+    }
+
+    fn player_set_weapon_tint_index(&self, player: *mut alt_IPlayer, weapon: u32, tint_index: u8) {
+        self.set(
+            player,
+            &format!("tint_index:{}", weapon),
+            u64::from(tint_index),
+        )
+    }
+
+    fn player_get_current_weapon_tint_index(&self, player: *mut alt_IPlayer) -> u8 {
+        let weapon = self.player_get_current_weapon(player);
+        self.get_uint(player, &format!("tint_index:{}", weapon), 0) as u8
+    }
+
+    fn player_get_current_weapon(&self, player: *mut alt_IPlayer) -> u32 {
+        self.get_uint(player, "current_weapon", 0) as u32
+    }
+
+    fn player_set_current_weapon(&self, player: *mut alt_IPlayer, weapon: u32) {
+        self.set(player, "current_weapon", u64::from(weapon))
+    }
+
+    fn player_is_dead(&self, player: *mut alt_IPlayer) -> bool {
+        self.player_get_health(player) <= 100
+    }
+
+    fn player_is_jumping(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "jumping")
+    }
+
+    fn player_is_in_ragdoll(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "in_ragdoll")
+    }
+
+    fn player_is_aiming(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "aiming")
+    }
+
+    fn player_is_shooting(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "shooting")
+    }
+
+    fn player_is_reloading(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "reloading")
+    }
+
+    fn player_get_armor(&self, player: *mut alt_IPlayer) -> u16 {
+        self.get_uint(player, "armor", 0) as u16
+    }
+
+    fn player_set_armor(&self, player: *mut alt_IPlayer, armor: u16) {
+        self.set(player, "armor", u64::from(armor))
+    }
+
+    fn player_get_max_armor(&self, player: *mut alt_IPlayer) -> u16 {
+        self.get_uint(player, "max_armor", 100) as u16
+    }
+
+    fn player_set_max_armor(&self, player: *mut alt_IPlayer, max_armor: u16) {
+        self.set(player, "max_armor", u64::from(max_armor))
+    }
+
+    fn player_get_move_speed(&self, player: *mut alt_IPlayer) -> f32 {
+        self.get_double(player, "move_speed", 0.0) as f32
+    }
+
+    fn player_get_weapon(&self, player: *mut alt_IPlayer) -> u32 {
+        self.player_get_current_weapon(player)
+    }
+
+    fn player_get_ammo(&self, player: *mut alt_IPlayer) -> u16 {
+        let weapon = self.player_get_current_weapon(player);
+        self.get_int(player, &format!("weapon:{}", weapon), 0) as u16
+    }
+
+    fn player_get_aim_position(&self, player: *mut alt_IPlayer) -> Vector3 {
+        self.get_vector3(player, "aim_position")
+    }
+
+    fn player_get_head_rotation(&self, player: *mut alt_IPlayer) -> Rotation3 {
+        let euler = self.get_vector3(player, "head_rotation");
+        Rotation3::from_euler_angles(euler.x, euler.y, euler.z)
+    }
+
+    fn player_is_in_vehicle(&self, player: *mut alt_IPlayer) -> bool {
+        !self.player_get_vehicle(player).is_null()
+    }
+
+    fn player_get_vehicle(&self, player: *mut alt_IPlayer) -> *mut alt_IVehicle {
+        self.get_object(player, "vehicle")
+    }
+
+    fn player_get_seat(&self, player: *mut alt_IPlayer) -> u8 {
+        self.get_uint(player, "seat", 0) as u8
+    }
+
+    fn player_get_entity_aiming_at(&self, player: *mut alt_IPlayer) -> *mut alt_IEntity {
+        self.get_object(player, "entity_aiming_at")
+    }
+
+    fn player_get_entity_aim_offset(&self, player: *mut alt_IPlayer) -> Vector3 {
+        self.get_vector3(player, "entity_aim_offset")
+    }
+
+    fn player_is_flashlight_active(&self, player: *mut alt_IPlayer) -> bool {
+        self.get_bool(player, "flashlight_active")
+    }
+
+    fn player_kick(&self, player: *mut alt_IPlayer, reason: &str) {
+        self.set(player, "connected", false);
+        self.set(player, "kick_reason", reason.to_owned());
+    }
+
+    fn player_set_model(&self, player: *mut alt_IPlayer, model: u32) {
+        self.set(player, "model", u64::from(model))
+    }
+
+    fn vehicle_get_driver(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IPlayer {
+        self.get_object(vehicle, "driver")
+    }
+
+    fn vehicle_get_mod(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8 {
+        self.get_uint(vehicle, &format!("mod:{}", category), 0) as u8
+    }
+
+    fn vehicle_get_mods_count(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8 {
+        self.get_uint(vehicle, &format!("mods_count:{}", category), 0) as u8
+    }
+
+    fn vehicle_set_mod(&self, vehicle: *mut alt_IVehicle, category: u8, id: u8) -> bool {
+        if self.vehicle_get_mod_kit(vehicle) == 0 {
+            return false;
+        }
+
+        self.set(vehicle, &format!("mod:{}", category), u64::from(id));
+        true
+    }
+
+    fn vehicle_get_mod_kit(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "mod_kit", 0) as u8
+    }
+
+    fn vehicle_get_mod_kits_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "mod_kits_count", 1) as u8
+    }
+
+    fn vehicle_set_mod_kit(&self, vehicle: *mut alt_IVehicle, id: u8) -> bool {
+        if id > self.vehicle_get_mod_kits_count(vehicle) {
+            return false;
+        }
+
+        self.set(vehicle, "mod_kit", u64::from(id));
+        true
+    }
+
+    fn vehicle_is_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "primary_color_is_rgb")
+    }
+
+    fn vehicle_get_primary_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "primary_color", 0) as u8
+    }
+
+    fn vehicle_get_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        self.get_rgba(vehicle, "primary_color_rgb")
+    }
+
+    fn vehicle_set_primary_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "primary_color", u64::from(color));
+        self.set(vehicle, "primary_color_is_rgb", false);
+    }
+
+    fn vehicle_set_primary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        self.set(vehicle, "primary_color_rgb", MockCore::opaque(color));
+        self.set(vehicle, "primary_color_is_rgb", true);
+    }
+
+    fn vehicle_is_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "secondary_color_is_rgb")
+    }
+
+    fn vehicle_get_secondary_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "secondary_color", 0) as u8
+    }
+
+    fn vehicle_get_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        self.get_rgba(vehicle, "secondary_color_rgb")
+    }
+
+    fn vehicle_set_secondary_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "secondary_color", u64::from(color));
+        self.set(vehicle, "secondary_color_is_rgb", false);
+    }
+
+    fn vehicle_set_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        self.set(vehicle, "secondary_color_rgb", MockCore::opaque(color));
+        self.set(vehicle, "secondary_color_is_rgb", true);
+    }
+
+    fn vehicle_get_pearl_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "pearl_color", 0) as u8
+    }
+
+    fn vehicle_set_pearl_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "pearl_color", u64::from(color))
+    }
+
+    fn vehicle_get_wheel_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "wheel_color", 0) as u8
+    }
+
+    fn vehicle_set_wheel_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "wheel_color", u64::from(color))
+    }
+
+    fn vehicle_get_interior_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "interior_color", 0) as u8
+    }
+
+    fn vehicle_set_interior_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "interior_color", u64::from(color))
+    }
+
+    fn vehicle_get_dashboard_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "dashboard_color", 0) as u8
+    }
+
+    fn vehicle_set_dashboard_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "dashboard_color", u64::from(color))
+    }
+
+    fn vehicle_is_tire_smoke_color_custom(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get(vehicle, "tire_smoke_color").is_some()
+    }
+
+    fn vehicle_get_tire_smoke_color(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        self.get_rgba(vehicle, "tire_smoke_color")
+    }
+
+    fn vehicle_set_tire_smoke_color(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        self.set(vehicle, "tire_smoke_color", MockCore::opaque(color))
+    }
+
+    fn vehicle_get_wheel_type(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "wheel_type", 0) as u8
+    }
+
+    fn vehicle_get_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "wheel_variation", 0) as u8
+    }
+
+    fn vehicle_get_rear_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "rear_wheel_variation", 0) as u8
+    }
+
+    fn vehicle_set_wheels(&self, vehicle: *mut alt_IVehicle, wheel_type: u8, variation: u8) {
+        self.set(vehicle, "wheel_type", u64::from(wheel_type));
+        self.set(vehicle, "wheel_variation", u64::from(variation));
+    }
+
+    fn vehicle_set_rear_wheels(&self, vehicle: *mut alt_IVehicle, variation: u8) {
+        self.set(vehicle, "rear_wheel_variation", u64::from(variation))
+    }
+
+    fn vehicle_get_custom_tires(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "custom_tires")
+    }
+
+    fn vehicle_set_custom_tires(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        self.set(vehicle, "custom_tires", state)
+    }
+
+    fn vehicle_get_special_darkness(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "special_darkness", 0) as u8
+    }
+
+    fn vehicle_set_special_darkness(&self, vehicle: *mut alt_IVehicle, special_darkness: u8) {
+        self.set(vehicle, "special_darkness", u64::from(special_darkness))
+    }
+
+    fn vehicle_get_numberplate_index(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        self.get_uint(vehicle, "numberplate_index", 0) as u32
+    }
+
+    fn vehicle_set_numberplate_index(&self, vehicle: *mut alt_IVehicle, index: u32) {
+        self.set(vehicle, "numberplate_index", u64::from(index))
+    }
+
+    fn vehicle_get_numberplate_text(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "numberplate_text")
+    }
+
+    fn vehicle_set_numberplate_text(&self, vehicle: *mut alt_IVehicle, text: &str) {
+        self.set(vehicle, "numberplate_text", text.to_owned())
+    }
+
+    fn vehicle_get_window_tint(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "window_tint", 0) as u8
+    }
+
+    fn vehicle_set_window_tint(&self, vehicle: *mut alt_IVehicle, window_tint: u8) {
+        self.set(vehicle, "window_tint", u64::from(window_tint))
+    }
+
+    fn vehicle_get_dirt_level(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "dirt_level", 0) as u8
+    }
+
+    fn vehicle_set_dirt_level(&self, vehicle: *mut alt_IVehicle, dirt_level: u8) {
+        self.set(vehicle, "dirt_level", u64::from(dirt_level))
+    }
+
+    fn vehicle_is_extra_on(&self, vehicle: *mut alt_IVehicle, extra_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("extra:{}", extra_id))
+    }
+
+    fn vehicle_toggle_extra(&self, vehicle: *mut alt_IVehicle, extra_id: u8, state: bool) {
+        self.set(vehicle, &format!("extra:{}", extra_id), state)
+    }
+
+    fn vehicle_is_neon_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        let (left, right, front, back) = self.vehicle_get_neon_active(vehicle);
+        left || right || front || back
+    }
+
+    fn vehicle_get_neon_active(&self, vehicle: *mut alt_IVehicle) -> (bool, bool, bool, bool) {
+        (
+            self.get_bool(vehicle, "neon_left"),
+            self.get_bool(vehicle, "neon_right"),
+            self.get_bool(vehicle, "neon_front"),
+            self.get_bool(vehicle, "neon_back"),
+        )
+    }
+
+    fn vehicle_set_neon_active(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        left: bool,
+        right: bool,
+        front: bool,
+        back: bool,
+    ) {
+        self.set(vehicle, "neon_left", left);
+        self.set(vehicle, "neon_right", right);
+        self.set(vehicle, "neon_front", front);
+        self.set(vehicle, "neon_back", back);
+    }
+
+    fn vehicle_get_neon_color(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        // The server only reports a neon color while at least one neon is on.
+        if !self.vehicle_is_neon_active(vehicle) {
+            return Rgba::default();
+        }
+
+        self.get_rgba(vehicle, "neon_color")
+    }
+
+    fn vehicle_set_neon_color(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        self.set(vehicle, "neon_color", MockCore::opaque(color))
+    }
+
+    fn vehicle_get_livery(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "livery", 0) as u8
+    }
+
+    fn vehicle_set_livery(&self, vehicle: *mut alt_IVehicle, livery: u8) {
+        self.set(vehicle, "livery", u64::from(livery))
+    }
+
+    fn vehicle_get_roof_livery(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "roof_livery", 0) as u8
+    }
+
+    fn vehicle_set_roof_livery(&self, vehicle: *mut alt_IVehicle, roof_livery: u8) {
+        self.set(vehicle, "roof_livery", u64::from(roof_livery))
+    }
+
+    fn vehicle_get_appearance_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "appearance_data")
+    }
+
+    fn vehicle_load_appearance_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        self.set(vehicle, "appearance_data", base64.to_owned())
+    }
+
+    fn vehicle_is_engine_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "engine_on")
+    }
+
+    fn vehicle_set_engine_on(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        self.set(vehicle, "engine_on", state)
+    }
+
+    fn vehicle_is_handbrake_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "handbrake_active")
+    }
+
+    fn vehicle_get_headlight_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "headlight_color", 0) as u8
+    }
+
+    fn vehicle_set_headlight_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        self.set(vehicle, "headlight_color", u64::from(color))
+    }
+
+    fn vehicle_get_radio_station_index(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        self.get_uint(vehicle, "radio_station_index", 0) as u32
+    }
+
+    fn vehicle_set_radio_station_index(&self, vehicle: *mut alt_IVehicle, index: u32) {
+        self.set(vehicle, "radio_station_index", u64::from(index))
+    }
+
+    fn vehicle_is_siren_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "siren_active")
+    }
+
+    fn vehicle_set_siren_active(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        self.set(vehicle, "siren_active", state)
+    }
+
+    fn vehicle_get_lock_state(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "lock_state", 1) as u8
+    }
+
+    fn vehicle_set_lock_state(&self, vehicle: *mut alt_IVehicle, lock_state: u8) {
+        self.set(vehicle, "lock_state", u64::from(lock_state))
+    }
+
+    fn vehicle_get_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8) -> u8 {
+        self.get_uint(vehicle, &format!("door_state:{}", door_id), 0) as u8
+    }
+
+    fn vehicle_set_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8, state: u8) {
+        self.set(
+            vehicle,
+            &format!("door_state:{}", door_id),
+            u64::from(state),
+        )
+    }
+
+    fn vehicle_is_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("window_opened:{}", window_id))
+    }
+
+    fn vehicle_set_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool) {
+        self.set(vehicle, &format!("window_opened:{}", window_id), state)
+    }
+
+    fn vehicle_is_daylight_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "daylight_on")
+    }
+
+    fn vehicle_is_nightlight_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "nightlight_on")
+    }
+
+    fn vehicle_is_roof_opened(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "roof_opened")
+    }
+
+    fn vehicle_set_roof_opened(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        self.set(vehicle, "roof_opened", state)
+    }
+
+    fn vehicle_is_flamethrower_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "flamethrower_active")
+    }
+
+    fn vehicle_get_lights_multiplier(&self, vehicle: *mut alt_IVehicle) -> f32 {
+        self.get_double(vehicle, "lights_multiplier", 1.0) as f32
+    }
+
+    fn vehicle_set_lights_multiplier(&self, vehicle: *mut alt_IVehicle, multiplier: f32) {
+        self.set(vehicle, "lights_multiplier", f64::from(multiplier))
+    }
+
+    fn vehicle_get_engine_health(&self, vehicle: *mut alt_IVehicle) -> i32 {
+        self.get_int(vehicle, "engine_health", 1000) as i32
+    }
+
+    fn vehicle_set_engine_health(&self, vehicle: *mut alt_IVehicle, health: i32) {
+        self.set(vehicle, "engine_health", i64::from(health))
+    }
+
+    fn vehicle_get_petrol_tank_health(&self, vehicle: *mut alt_IVehicle) -> i32 {
+        self.get_int(vehicle, "petrol_tank_health", 1000) as i32
+    }
+
+    fn vehicle_set_petrol_tank_health(&self, vehicle: *mut alt_IVehicle, health: i32) {
+        self.set(vehicle, "petrol_tank_health", i64::from(health))
+    }
+
+    fn vehicle_get_wheels_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "wheels_count", 4) as u8
+    }
+
+    fn vehicle_is_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("wheel_burst:{}", wheel_id))
+    }
+
+    fn vehicle_set_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        self.set(vehicle, &format!("wheel_burst:{}", wheel_id), state)
+    }
+
+    fn vehicle_does_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        !self.get_bool(vehicle, &format!("wheel_tire_missing:{}", wheel_id))
+    }
+
+    fn vehicle_set_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        self.set(vehicle, &format!("wheel_tire_missing:{}", wheel_id), !state)
+    }
+
+    fn vehicle_is_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("wheel_detached:{}", wheel_id))
+    }
+
+    fn vehicle_set_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        self.set(vehicle, &format!("wheel_detached:{}", wheel_id), state)
+    }
+
+    fn vehicle_is_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("wheel_on_fire:{}", wheel_id))
+    }
+
+    fn vehicle_set_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        self.set(vehicle, &format!("wheel_on_fire:{}", wheel_id), state)
+    }
+
+    fn vehicle_get_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> f32 {
+        self.get_double(vehicle, &format!("wheel_health:{}", wheel_id), 1000.0) as f32
+    }
+
+    fn vehicle_set_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, health: f32) {
+        self.set(
+            vehicle,
+            &format!("wheel_health:{}", wheel_id),
+            f64::from(health),
+        )
+    }
+
+    fn vehicle_get_repairs_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        self.get_uint(vehicle, "repairs_count", 0) as u8
+    }
+
+    fn vehicle_get_body_health(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        self.get_uint(vehicle, "body_health", 1000) as u32
+    }
+
+    fn vehicle_set_body_health(&self, vehicle: *mut alt_IVehicle, health: u32) {
+        self.set(vehicle, "body_health", u64::from(health))
+    }
+
+    fn vehicle_get_body_additional_health(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        self.get_uint(vehicle, "body_additional_health", 1000) as u32
+    }
+
+    fn vehicle_set_body_additional_health(&self, vehicle: *mut alt_IVehicle, health: u32) {
+        self.set(vehicle, "body_additional_health", u64::from(health))
+    }
+
+    fn vehicle_get_health_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "health_data")
+    }
+
+    fn vehicle_load_health_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        self.set(vehicle, "health_data", base64.to_owned())
+    }
+
+    fn vehicle_get_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8 {
+        self.get_uint(vehicle, &format!("part_damage_level:{}", part_id), 0) as u8
+    }
+
+    fn vehicle_set_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8, level: u8) {
+        self.set(
+            vehicle,
+            &format!("part_damage_level:{}", part_id),
+            u64::from(level),
+        )
+    }
+
+    fn vehicle_get_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8 {
+        self.get_uint(vehicle, &format!("part_bullet_holes:{}", part_id), 0) as u8
+    }
+
+    fn vehicle_set_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8, count: u8) {
+        self.set(
+            vehicle,
+            &format!("part_bullet_holes:{}", part_id),
+            u64::from(count),
+        )
+    }
+
+    fn vehicle_is_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("light_damaged:{}", light_id))
+    }
+
+    fn vehicle_set_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8, state: bool) {
+        self.set(vehicle, &format!("light_damaged:{}", light_id), state)
+    }
+
+    fn vehicle_is_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("window_damaged:{}", window_id))
+    }
+
+    fn vehicle_set_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool) {
+        self.set(vehicle, &format!("window_damaged:{}", window_id), state)
+    }
+
+    fn vehicle_is_special_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool {
+        self.get_bool(vehicle, &format!("special_light_damaged:{}", light_id))
+    }
+
+    fn vehicle_set_special_light_damaged(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        light_id: u8,
+        state: bool,
+    ) {
+        self.set(
+            vehicle,
+            &format!("special_light_damaged:{}", light_id),
+            state,
+        )
+    }
+
+    fn vehicle_has_armored_windows(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "armored_windows")
+    }
+
+    fn vehicle_get_armored_window_health(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> f32 {
+        self.get_double(
+            vehicle,
+            &format!("armored_window_health:{}", window_id),
+            0.0,
+        ) as f32
+    }
+
+    fn vehicle_set_armored_window_health(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        health: f32,
+    ) {
+        self.set(
+            vehicle,
+            &format!("armored_window_health:{}", window_id),
+            f64::from(health),
+        )
+    }
+
+    fn vehicle_get_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+    ) -> u8 {
+        self.get_uint(
+            vehicle,
+            &format!("armored_window_shoot_count:{}", window_id),
+            0,
+        ) as u8
+    }
+
+    fn vehicle_set_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        count: u8,
+    ) {
+        self.set(
+            vehicle,
+            &format!("armored_window_shoot_count:{}", window_id),
+            u64::from(count),
+        )
+    }
+
+    fn vehicle_get_bumper_damage_level(&self, vehicle: *mut alt_IVehicle, bumper_id: u8) -> u8 {
+        self.get_uint(vehicle, &format!("bumper_damage_level:{}", bumper_id), 0) as u8
+    }
+
+    fn vehicle_set_bumper_damage_level(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        bumper_id: u8,
+        level: u8,
+    ) {
+        self.set(
+            vehicle,
+            &format!("bumper_damage_level:{}", bumper_id),
+            u64::from(level),
+        )
+    }
+
+    fn vehicle_get_damage_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "damage_data")
+    }
+
+    fn vehicle_load_damage_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        self.set(vehicle, "damage_data", base64.to_owned())
+    }
+
+    fn vehicle_set_manual_engine_control(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        self.set(vehicle, "manual_engine_control", state)
+    }
+
+    fn vehicle_is_manual_engine_control(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "manual_engine_control")
+    }
+
+    fn vehicle_get_script_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "script_data")
+    }
+
+    fn vehicle_load_script_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        self.set(vehicle, "script_data", base64.to_owned())
+    }
+
+    fn vehicle_get_game_state_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        self.get_string(vehicle, "game_state")
+    }
+
+    fn vehicle_load_game_state_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        self.set(vehicle, "game_state", base64.to_owned())
+    }
+
+    fn vehicle_is_destroyed(&self, vehicle: *mut alt_IVehicle) -> bool {
+        self.get_bool(vehicle, "destroyed")
+    }
+
+    fn col_shape_get_colshape_type(
+        &self,
+        col_shape: *mut alt_IColShape,
+    ) -> alt_IColShape_ColShapeType {
+        match self.with_object(col_shape, |object| object.shape) {
+            Some(shape) => shape.get_type(),
+            None => alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CHECKPOINT_CYLINDER,
+        }
+    }
+
+    fn col_shape_is_entity_in(
+        &self,
+        col_shape: *mut alt_IColShape,
+        entity: *mut alt_IEntity,
+    ) -> bool {
+        let position = self.get_vector3(entity, "position");
+        self.col_shape_is_point_in(col_shape, position)
+    }
+
+    fn col_shape_get_dimension(&self, col_shape: *mut alt_IColShape) -> i32 {
+        self.get_int(col_shape, "dimension", 0) as i32
+    }
+
+    fn col_shape_set_dimension(&self, col_shape: *mut alt_IColShape, dimension: i32) {
+        self.set(col_shape, "dimension", i64::from(dimension))
+    }
+
+    fn col_shape_get_position(&self, col_shape: *mut alt_IColShape) -> Vector3 {
+        self.get_vector3(col_shape, "position")
+    }
+
+    fn col_shape_set_position(&self, col_shape: *mut alt_IColShape, position: Vector3) {
+        self.set_position(col_shape, position)
+    }
+
+    fn col_shape_is_point_in(&self, col_shape: *mut alt_IColShape, position: Vector3) -> bool {
+        match self.with_object(col_shape, |object| object.shape) {
+            Some(shape) => shape.contains(position),
+            None => {
+                let checkpoint = col_shape as *mut alt_ICheckpoint;
+                let center = self.get_vector3(col_shape, "position");
+                Shape::Cylinder(
+                    center,
+                    self.checkpoint_get_radius(checkpoint),
+                    self.checkpoint_get_height(checkpoint),
+                )
+                .contains(position)
+            }
+        }
+    }
+
+    fn checkpoint_get_checkpoint_type(&self, checkpoint: *mut alt_ICheckpoint) -> u8 {
+        self.get_uint(checkpoint, "checkpoint_type", 0) as u8
+    }
+
+    fn checkpoint_get_height(&self, checkpoint: *mut alt_ICheckpoint) -> f32 {
+        self.get_double(checkpoint, "height", 0.0) as f32
+    }
+
+    fn checkpoint_get_radius(&self, checkpoint: *mut alt_ICheckpoint) -> f32 {
+        self.get_double(checkpoint, "radius", 0.0) as f32
+    }
+
+    fn checkpoint_get_color(&self, checkpoint: *mut alt_ICheckpoint) -> Rgba {
+        self.get_rgba(checkpoint, "color")
+    }
+
+    fn checkpoint_get_colshape_type(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+    ) -> alt_IColShape_ColShapeType {
+        self.col_shape_get_colshape_type(checkpoint as *mut alt_IColShape)
+    }
+
+    fn checkpoint_is_entity_in(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+        entity: *mut alt_IEntity,
+    ) -> bool {
+        self.col_shape_is_entity_in(checkpoint as *mut alt_IColShape, entity)
+    }
+
+    fn checkpoint_is_point_in(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3) -> bool {
+        self.col_shape_is_point_in(checkpoint as *mut alt_IColShape, position)
+    }
+
+    fn checkpoint_get_dimension(&self, checkpoint: *mut alt_ICheckpoint) -> i32 {
+        self.get_int(checkpoint, "dimension", 0) as i32
+    }
+
+    fn checkpoint_set_dimension(&self, checkpoint: *mut alt_ICheckpoint, dimension: i32) {
+        self.set(checkpoint, "dimension", i64::from(dimension))
+    }
+
+    fn checkpoint_get_position(&self, checkpoint: *mut alt_ICheckpoint) -> Vector3 {
+        self.get_vector3(checkpoint, "position")
+    }
+
+    fn checkpoint_set_position(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3) {
+        self.set_position(checkpoint, position)
+    }
+
+    fn blip_is_global(&self, blip: *mut alt_IBlip) -> bool {
+        self.blip_get_target(blip).is_null()
+    }
+
+    fn blip_get_target(&self, blip: *mut alt_IBlip) -> *mut alt_IPlayer {
+        self.get_object(blip, "target")
+    }
+
+    fn blip_is_attached(&self, blip: *mut alt_IBlip) -> bool {
+        !self.blip_attached_to(blip).is_null()
+    }
+
+    fn blip_attached_to(&self, blip: *mut alt_IBlip) -> *mut alt_IEntity {
+        self.get_object(blip, "attached_to")
+    }
+
+    fn blip_get_blip_type(&self, blip: *mut alt_IBlip) -> alt_IBlip_BlipType {
+        match self.get_uint(blip, "blip_type", 3) {
+            1 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_VEHICLE,
+            2 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PED,
+            4 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_DESTINATION,
+            5 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_CONT,
+            6 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_UNK,
+            7 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_RADIUS,
+            8 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP,
+            9 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_COP,
+            11 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_AREA,
+            12 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_GALLERY,
+            13 => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_OBJECT,
+            _ => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_OBJECT,
+        }
+    }
+
+    fn blip_set_sprite(&self, blip: *mut alt_IBlip, sprite: u16) {
+        self.set(blip, "sprite", u64::from(sprite))
+    }
+
+    fn blip_set_color(&self, blip: *mut alt_IBlip, color: u8) {
+        self.set(blip, "color", u64::from(color))
+    }
+
+    fn blip_set_route(&self, blip: *mut alt_IBlip, state: bool) {
+        self.set(blip, "route", state)
+    }
+
+    fn blip_set_route_color(&self, blip: *mut alt_IBlip, color: u8) {
+        self.set(blip, "route_color", u64::from(color))
+    }
+
+    fn blip_get_dimension(&self, blip: *mut alt_IBlip) -> i32 {
+        self.get_int(blip, "dimension", 0) as i32
+    }
+
+    fn blip_set_dimension(&self, blip: *mut alt_IBlip, dimension: i32) {
+        self.set(blip, "dimension", i64::from(dimension))
+    }
+
+    fn blip_get_position(&self, blip: *mut alt_IBlip) -> Vector3 {
+        self.get_vector3(blip, "position")
+    }
+
+    fn blip_set_position(&self, blip: *mut alt_IBlip, position: Vector3) {
+        self.set_position(blip, position)
+    }
+
+    fn voice_channel_is_spatial(&self, voice_channel: *mut alt_IVoiceChannel) -> bool {
+        self.get_bool(voice_channel, "spatial")
+    }
+
+    fn voice_channel_get_max_distance(&self, voice_channel: *mut alt_IVoiceChannel) -> f32 {
+        self.get_double(voice_channel, "max_distance", 0.0) as f32
+    }
+
+    fn voice_channel_has_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool {
+        self.get_bool(voice_channel, &format!("player:{}", to_id(player)))
+    }
+
+    fn voice_channel_add_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        self.set(voice_channel, &format!("player:{}", to_id(player)), true)
+    }
+
+    fn voice_channel_remove_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        self.set(voice_channel, &format!("player:{}", to_id(player)), false);
+        self.set(voice_channel, &format!("muted:{}", to_id(player)), false);
+    }
+
+    fn voice_channel_is_player_muted(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool {
+        self.get_bool(voice_channel, &format!("muted:{}", to_id(player)))
+    }
+
+    fn voice_channel_mute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        self.set(voice_channel, &format!("muted:{}", to_id(player)), true)
+    }
+
+    fn voice_channel_unmute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        self.set(voice_channel, &format!("muted:{}", to_id(player)), false)
+    }
+}
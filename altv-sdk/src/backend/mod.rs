@@ -0,0 +1,558 @@
+use crate::mvalue::MValue;
+use crate::natives::*;
+use crate::rgba::Rgba;
+use crate::vector::{Rotation3, Vector3};
+use altv_core::AltResource;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+pub mod mock;
+pub mod native;
+
+pub use mock::MockCore;
+pub use native::NativeCore;
+
+thread_local! {
+    static INSTANCE: RefCell<Option<Rc<dyn CoreBackend>>> = RefCell::new(None);
+}
+
+/// Returns the backend every element wrapper calls into.
+///
+/// Falls back to the native alt:V core if no backend was set on this thread.
+pub fn instance() -> Rc<dyn CoreBackend> {
+    INSTANCE.with(|instance| {
+        instance
+            .borrow_mut()
+            .get_or_insert_with(|| Rc::new(NativeCore))
+            .clone()
+    })
+}
+
+pub fn set_instance(backend: Rc<dyn CoreBackend>) {
+    INSTANCE.with(|instance| {
+        instance.borrow_mut().replace(backend);
+    });
+}
+
+/// Everything the SDK needs from an alt:V core.
+///
+/// `NativeCore` forwards to the C API, `MockCore` simulates the server in memory.
+pub trait CoreBackend {
+    // ICore
+
+    fn log_info(&self, msg: &str);
+    fn log_error(&self, msg: &str);
+    fn log_warning(&self, msg: &str);
+    fn log_debug(&self, msg: &str);
+    fn log_colored(&self, msg: &str);
+
+    fn now(&self) -> Instant;
+
+    fn create_vehicle(
+        &self,
+        model: u32,
+        position: Vector3,
+        rotation: Rotation3,
+    ) -> *mut alt_IVehicle;
+    fn create_col_shape_sphere(&self, position: Vector3, radius: f32) -> *mut alt_IColShape;
+    fn create_col_shape_cube(
+        &self,
+        start_position: Vector3,
+        end_position: Vector3,
+    ) -> *mut alt_IColShape;
+    fn create_col_shape_rectangle(
+        &self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        z: f32,
+    ) -> *mut alt_IColShape;
+    fn create_col_shape_circle(&self, position: Vector3, radius: f32) -> *mut alt_IColShape;
+    fn create_col_shape_cylinder(
+        &self,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+    ) -> *mut alt_IColShape;
+    fn create_checkpoint(
+        &self,
+        checkpoint_type: u8,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+        color: Rgba,
+    ) -> *mut alt_ICheckpoint;
+    fn create_blip(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        position: Vector3,
+    ) -> *mut alt_IBlip;
+    fn create_blip_attached(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        attach_to: *mut alt_IEntity,
+    ) -> *mut alt_IBlip;
+    fn create_voice_channel(&self, spatial: bool, max_distance: f32) -> *mut alt_IVoiceChannel;
+    fn destroy_base_object(&self, base_obj: *mut alt_IBaseObject);
+    fn trigger_client_event(
+        &self,
+        alt: &AltResource,
+        target: Option<*mut alt_IPlayer>,
+        name: &str,
+        args: &[MValue],
+    ) -> crate::Result<()>;
+
+    // IMValue
+
+    fn create_mvalue_none(&self) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_nil(&self) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_bool(&self, value: bool) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_int(&self, value: i64) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_uint(&self, value: u64) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_double(&self, value: f64) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_string(&self, value: &str) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_list(&self, size: u64) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_dict(&self) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_base_object(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_vector3(&self, value: Vector3) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_rgba(&self, value: Rgba) -> *mut alt_RefBase_RefStore_IMValue;
+    fn create_mvalue_byte_array(&self, value: Vec<u8>) -> *mut alt_RefBase_RefStore_IMValue;
+    fn mvalue_ref_get(&self, mvalue: *mut alt_RefBase_RefStore_IMValue) -> *mut alt_IMValue;
+    fn mvalue_get_type(&self, mvalue: *mut alt_IMValue) -> alt_IMValue_Type;
+    fn mvalue_get_bool(&self, mvalue: *mut alt_IMValue) -> bool;
+    fn mvalue_get_int(&self, mvalue: *mut alt_IMValue) -> i64;
+    fn mvalue_get_uint(&self, mvalue: *mut alt_IMValue) -> u64;
+    fn mvalue_get_double(&self, mvalue: *mut alt_IMValue) -> f64;
+    fn mvalue_get_string(&self, mvalue: *mut alt_IMValue) -> String;
+    fn mvalue_get_base_object(&self, mvalue: *mut alt_IMValue) -> *mut alt_IBaseObject;
+    fn mvalue_get_vector3(&self, mvalue: *mut alt_IMValue) -> Vector3;
+    fn mvalue_get_rgba(&self, mvalue: *mut alt_IMValue) -> Rgba;
+    fn mvalue_get_byte_array(&self, mvalue: *mut alt_IMValue) -> Vec<u8>;
+    fn mvalue_list_get_size(&self, list: *mut alt_IMValue) -> u64;
+    fn mvalue_list_get(&self, list: *mut alt_IMValue, index: u64) -> *mut alt_IMValue;
+    fn mvalue_list_set(
+        &self,
+        list: *mut alt_RefBase_RefStore_IMValue,
+        index: u64,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    );
+    fn mvalue_dict_get_keys(&self, dict: *mut alt_IMValue) -> Vec<String>;
+    fn mvalue_dict_get(&self, dict: *mut alt_IMValue, key: &str) -> *mut alt_IMValue;
+    fn mvalue_dict_set(
+        &self,
+        dict: *mut alt_RefBase_RefStore_IMValue,
+        key: &str,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    );
+
+    // Casts
+
+    fn base_object_to_ref_countable(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_CRefCountable;
+    fn base_object_to_world_object(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IWorldObject;
+    fn base_object_to_entity(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IEntity;
+    fn base_object_to_player(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IPlayer;
+    fn base_object_to_vehicle(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IVehicle;
+    fn base_object_to_blip(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IBlip;
+    fn base_object_to_voice_channel(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_IVoiceChannel;
+    fn base_object_to_col_shape(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IColShape;
+    fn base_object_to_checkpoint(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_ICheckpoint;
+    fn entity_to_base_object(&self, entity: *mut alt_IEntity) -> *mut alt_IBaseObject;
+    fn vehicle_to_base_object(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IBaseObject;
+    fn col_shape_to_base_object(&self, col_shape: *mut alt_IColShape) -> *mut alt_IBaseObject;
+    fn checkpoint_to_base_object(&self, checkpoint: *mut alt_ICheckpoint) -> *mut alt_IBaseObject;
+    fn blip_to_base_object(&self, blip: *mut alt_IBlip) -> *mut alt_IBaseObject;
+    fn voice_channel_to_base_object(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+    ) -> *mut alt_IBaseObject;
+
+    // CRefCountable
+
+    fn ref_countable_get_ref_count(&self, ref_countable: *mut alt_CRefCountable) -> u64;
+    fn ref_countable_add_ref(&self, ref_countable: *mut alt_CRefCountable);
+    fn ref_countable_remove_ref(&self, ref_countable: *mut alt_CRefCountable);
+
+    // IBaseObject
+
+    fn base_object_get_type(&self, base_obj: *mut alt_IBaseObject) -> alt_IBaseObject_Type;
+    fn base_object_has_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str) -> bool;
+    fn base_object_get_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+    ) -> crate::Result<MValue>;
+    fn base_object_set_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()>;
+    fn base_object_delete_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str);
+
+    // IWorldObject
+
+    fn world_object_get_dimension(&self, world_obj: *mut alt_IWorldObject) -> i32;
+    fn world_object_set_dimension(&self, world_obj: *mut alt_IWorldObject, dimension: i32);
+    fn world_object_get_position(&self, world_obj: *mut alt_IWorldObject) -> Vector3;
+    fn world_object_set_position(&self, world_obj: *mut alt_IWorldObject, position: Vector3);
+
+    // IEntity
+
+    fn entity_get_id(&self, entity: *mut alt_IEntity) -> u16;
+    fn entity_get_network_owner(&self, entity: *mut alt_IEntity) -> *mut alt_IPlayer;
+    fn entity_get_model(&self, entity: *mut alt_IEntity) -> u32;
+    fn entity_get_rotation(&self, entity: *mut alt_IEntity) -> Rotation3;
+    fn entity_set_rotation(&self, entity: *mut alt_IEntity, rotation: Rotation3);
+    fn entity_has_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool;
+    fn entity_get_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue>;
+    fn entity_set_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()>;
+    fn entity_delete_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str);
+    fn entity_has_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool;
+    fn entity_get_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue>;
+    fn entity_set_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()>;
+    fn entity_delete_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str);
+
+    // IPlayer
+
+    fn player_is_connected(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_get_ping(&self, player: *mut alt_IPlayer) -> u32;
+    fn player_get_ip(&self, player: *mut alt_IPlayer) -> String;
+    fn player_spawn(&self, player: *mut alt_IPlayer, position: Vector3, delay: Duration);
+    fn player_despawn(&self, player: *mut alt_IPlayer);
+    fn player_get_name(&self, player: *mut alt_IPlayer) -> String;
+    fn player_get_social_id(&self, player: *mut alt_IPlayer) -> u64;
+    fn player_get_hwid_hash(&self, player: *mut alt_IPlayer) -> u64;
+    fn player_get_hwid_ex_hash(&self, player: *mut alt_IPlayer) -> u64;
+    fn player_get_auth_token(&self, player: *mut alt_IPlayer) -> String;
+    fn player_get_health(&self, player: *mut alt_IPlayer) -> u16;
+    fn player_set_health(&self, player: *mut alt_IPlayer, health: u16);
+    fn player_get_max_health(&self, player: *mut alt_IPlayer) -> u16;
+    fn player_set_max_health(&self, player: *mut alt_IPlayer, max_health: u16);
+    #[allow(clippy::too_many_arguments)]
+    fn player_set_date_time(
+        &self,
+        player: *mut alt_IPlayer,
+        day: i32,
+        month: i32,
+        year: i32,
+        hour: i32,
+        minute: i32,
+        second: i32,
+    );
+    fn player_set_weather(&self, player: *mut alt_IPlayer, weather: u32);
+    fn player_give_weapon(&self, player: *mut alt_IPlayer, weapon: u32, ammo: i32, select: bool);
+    fn player_remove_weapon(&self, player: *mut alt_IPlayer, weapon: u32);
+    fn player_remove_all_weapons(&self, player: *mut alt_IPlayer);
+    fn player_add_weapon_component(&self, player: *mut alt_IPlayer, weapon: u32, component: u32);
+    fn player_remove_weapon_component(&self, player: *mut alt_IPlayer, weapon: u32, component: u32);
+    fn player_set_weapon_tint_index(&self, player: *mut alt_IPlayer, weapon: u32, tint_index: u8);
+    fn player_get_current_weapon_tint_index(&self, player: *mut alt_IPlayer) -> u8;
+    fn player_get_current_weapon(&self, player: *mut alt_IPlayer) -> u32;
+    fn player_set_current_weapon(&self, player: *mut alt_IPlayer, weapon: u32);
+    fn player_is_dead(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_is_jumping(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_is_in_ragdoll(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_is_aiming(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_is_shooting(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_is_reloading(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_get_armor(&self, player: *mut alt_IPlayer) -> u16;
+    fn player_set_armor(&self, player: *mut alt_IPlayer, armor: u16);
+    fn player_get_max_armor(&self, player: *mut alt_IPlayer) -> u16;
+    fn player_set_max_armor(&self, player: *mut alt_IPlayer, max_armor: u16);
+    fn player_get_move_speed(&self, player: *mut alt_IPlayer) -> f32;
+    fn player_get_weapon(&self, player: *mut alt_IPlayer) -> u32;
+    fn player_get_ammo(&self, player: *mut alt_IPlayer) -> u16;
+    fn player_get_aim_position(&self, player: *mut alt_IPlayer) -> Vector3;
+    fn player_get_head_rotation(&self, player: *mut alt_IPlayer) -> Rotation3;
+    fn player_is_in_vehicle(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_get_vehicle(&self, player: *mut alt_IPlayer) -> *mut alt_IVehicle;
+    fn player_get_seat(&self, player: *mut alt_IPlayer) -> u8;
+    fn player_get_entity_aiming_at(&self, player: *mut alt_IPlayer) -> *mut alt_IEntity;
+    fn player_get_entity_aim_offset(&self, player: *mut alt_IPlayer) -> Vector3;
+    fn player_is_flashlight_active(&self, player: *mut alt_IPlayer) -> bool;
+    fn player_kick(&self, player: *mut alt_IPlayer, reason: &str);
+    fn player_set_model(&self, player: *mut alt_IPlayer, model: u32);
+
+    // IVehicle
+
+    fn vehicle_get_driver(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IPlayer;
+    fn vehicle_get_mod(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8;
+    fn vehicle_get_mods_count(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8;
+    fn vehicle_set_mod(&self, vehicle: *mut alt_IVehicle, category: u8, id: u8) -> bool;
+    fn vehicle_get_mod_kit(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_mod_kits_count(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_mod_kit(&self, vehicle: *mut alt_IVehicle, id: u8) -> bool;
+    fn vehicle_is_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_primary_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba;
+    fn vehicle_set_primary_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_set_primary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba);
+    fn vehicle_is_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_secondary_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba;
+    fn vehicle_set_secondary_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_set_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba);
+    fn vehicle_get_pearl_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_pearl_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_get_wheel_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_wheel_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_get_interior_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_interior_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_get_dashboard_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_dashboard_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_is_tire_smoke_color_custom(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_tire_smoke_color(&self, vehicle: *mut alt_IVehicle) -> Rgba;
+    fn vehicle_set_tire_smoke_color(&self, vehicle: *mut alt_IVehicle, color: Rgba);
+    fn vehicle_get_wheel_type(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_rear_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_wheels(&self, vehicle: *mut alt_IVehicle, wheel_type: u8, variation: u8);
+    fn vehicle_set_rear_wheels(&self, vehicle: *mut alt_IVehicle, variation: u8);
+    fn vehicle_get_custom_tires(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_set_custom_tires(&self, vehicle: *mut alt_IVehicle, state: bool);
+    fn vehicle_get_special_darkness(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_special_darkness(&self, vehicle: *mut alt_IVehicle, special_darkness: u8);
+    fn vehicle_get_numberplate_index(&self, vehicle: *mut alt_IVehicle) -> u32;
+    fn vehicle_set_numberplate_index(&self, vehicle: *mut alt_IVehicle, index: u32);
+    fn vehicle_get_numberplate_text(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_set_numberplate_text(&self, vehicle: *mut alt_IVehicle, text: &str);
+    fn vehicle_get_window_tint(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_window_tint(&self, vehicle: *mut alt_IVehicle, window_tint: u8);
+    fn vehicle_get_dirt_level(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_dirt_level(&self, vehicle: *mut alt_IVehicle, dirt_level: u8);
+    fn vehicle_is_extra_on(&self, vehicle: *mut alt_IVehicle, extra_id: u8) -> bool;
+    fn vehicle_toggle_extra(&self, vehicle: *mut alt_IVehicle, extra_id: u8, state: bool);
+    fn vehicle_is_neon_active(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_neon_active(&self, vehicle: *mut alt_IVehicle) -> (bool, bool, bool, bool);
+    fn vehicle_set_neon_active(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        left: bool,
+        right: bool,
+        front: bool,
+        back: bool,
+    );
+    fn vehicle_get_neon_color(&self, vehicle: *mut alt_IVehicle) -> Rgba;
+    fn vehicle_set_neon_color(&self, vehicle: *mut alt_IVehicle, color: Rgba);
+    fn vehicle_get_livery(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_livery(&self, vehicle: *mut alt_IVehicle, livery: u8);
+    fn vehicle_get_roof_livery(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_roof_livery(&self, vehicle: *mut alt_IVehicle, roof_livery: u8);
+    fn vehicle_get_appearance_data_base64(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_load_appearance_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str);
+    fn vehicle_is_engine_on(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_set_engine_on(&self, vehicle: *mut alt_IVehicle, state: bool);
+    fn vehicle_is_handbrake_active(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_headlight_color(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_headlight_color(&self, vehicle: *mut alt_IVehicle, color: u8);
+    fn vehicle_get_radio_station_index(&self, vehicle: *mut alt_IVehicle) -> u32;
+    fn vehicle_set_radio_station_index(&self, vehicle: *mut alt_IVehicle, index: u32);
+    fn vehicle_is_siren_active(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_set_siren_active(&self, vehicle: *mut alt_IVehicle, state: bool);
+    fn vehicle_get_lock_state(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_set_lock_state(&self, vehicle: *mut alt_IVehicle, lock_state: u8);
+    fn vehicle_get_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8) -> u8;
+    fn vehicle_set_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8, state: u8);
+    fn vehicle_is_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool;
+    fn vehicle_set_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool);
+    fn vehicle_is_daylight_on(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_is_nightlight_on(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_is_roof_opened(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_set_roof_opened(&self, vehicle: *mut alt_IVehicle, state: bool);
+    fn vehicle_is_flamethrower_active(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_lights_multiplier(&self, vehicle: *mut alt_IVehicle) -> f32;
+    fn vehicle_set_lights_multiplier(&self, vehicle: *mut alt_IVehicle, multiplier: f32);
+    fn vehicle_get_engine_health(&self, vehicle: *mut alt_IVehicle) -> i32;
+    fn vehicle_set_engine_health(&self, vehicle: *mut alt_IVehicle, health: i32);
+    fn vehicle_get_petrol_tank_health(&self, vehicle: *mut alt_IVehicle) -> i32;
+    fn vehicle_set_petrol_tank_health(&self, vehicle: *mut alt_IVehicle, health: i32);
+    fn vehicle_get_wheels_count(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_is_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool;
+    fn vehicle_set_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool);
+    fn vehicle_does_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool;
+    fn vehicle_set_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool);
+    fn vehicle_is_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool;
+    fn vehicle_set_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool);
+    fn vehicle_is_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool;
+    fn vehicle_set_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool);
+    fn vehicle_get_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> f32;
+    fn vehicle_set_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, health: f32);
+    fn vehicle_get_repairs_count(&self, vehicle: *mut alt_IVehicle) -> u8;
+    fn vehicle_get_body_health(&self, vehicle: *mut alt_IVehicle) -> u32;
+    fn vehicle_set_body_health(&self, vehicle: *mut alt_IVehicle, health: u32);
+    fn vehicle_get_body_additional_health(&self, vehicle: *mut alt_IVehicle) -> u32;
+    fn vehicle_set_body_additional_health(&self, vehicle: *mut alt_IVehicle, health: u32);
+    fn vehicle_get_health_data_base64(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_load_health_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str);
+    fn vehicle_get_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8;
+    fn vehicle_set_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8, level: u8);
+    fn vehicle_get_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8;
+    fn vehicle_set_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8, count: u8);
+    fn vehicle_is_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool;
+    fn vehicle_set_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8, state: bool);
+    fn vehicle_is_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool;
+    fn vehicle_set_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool);
+    fn vehicle_is_special_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool;
+    fn vehicle_set_special_light_damaged(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        light_id: u8,
+        state: bool,
+    );
+    fn vehicle_has_armored_windows(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_armored_window_health(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> f32;
+    fn vehicle_set_armored_window_health(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        health: f32,
+    );
+    fn vehicle_get_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+    ) -> u8;
+    fn vehicle_set_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        count: u8,
+    );
+    fn vehicle_get_bumper_damage_level(&self, vehicle: *mut alt_IVehicle, bumper_id: u8) -> u8;
+    fn vehicle_set_bumper_damage_level(&self, vehicle: *mut alt_IVehicle, bumper_id: u8, level: u8);
+    fn vehicle_get_damage_data_base64(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_load_damage_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str);
+    fn vehicle_set_manual_engine_control(&self, vehicle: *mut alt_IVehicle, state: bool);
+    fn vehicle_is_manual_engine_control(&self, vehicle: *mut alt_IVehicle) -> bool;
+    fn vehicle_get_script_data_base64(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_load_script_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str);
+    fn vehicle_get_game_state_base64(&self, vehicle: *mut alt_IVehicle) -> String;
+    fn vehicle_load_game_state_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str);
+    fn vehicle_is_destroyed(&self, vehicle: *mut alt_IVehicle) -> bool;
+
+    // IColShape
+
+    fn col_shape_get_colshape_type(
+        &self,
+        col_shape: *mut alt_IColShape,
+    ) -> alt_IColShape_ColShapeType;
+    fn col_shape_is_entity_in(
+        &self,
+        col_shape: *mut alt_IColShape,
+        entity: *mut alt_IEntity,
+    ) -> bool;
+    fn col_shape_is_point_in(&self, col_shape: *mut alt_IColShape, position: Vector3) -> bool;
+    fn col_shape_get_dimension(&self, col_shape: *mut alt_IColShape) -> i32;
+    fn col_shape_set_dimension(&self, col_shape: *mut alt_IColShape, dimension: i32);
+    fn col_shape_get_position(&self, col_shape: *mut alt_IColShape) -> Vector3;
+    fn col_shape_set_position(&self, col_shape: *mut alt_IColShape, position: Vector3);
+
+    // ICheckpoint
+
+    fn checkpoint_get_checkpoint_type(&self, checkpoint: *mut alt_ICheckpoint) -> u8;
+    fn checkpoint_get_height(&self, checkpoint: *mut alt_ICheckpoint) -> f32;
+    fn checkpoint_get_radius(&self, checkpoint: *mut alt_ICheckpoint) -> f32;
+    fn checkpoint_get_color(&self, checkpoint: *mut alt_ICheckpoint) -> Rgba;
+    fn checkpoint_get_colshape_type(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+    ) -> alt_IColShape_ColShapeType;
+    fn checkpoint_is_entity_in(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+        entity: *mut alt_IEntity,
+    ) -> bool;
+    fn checkpoint_is_point_in(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3) -> bool;
+    fn checkpoint_get_dimension(&self, checkpoint: *mut alt_ICheckpoint) -> i32;
+    fn checkpoint_set_dimension(&self, checkpoint: *mut alt_ICheckpoint, dimension: i32);
+    fn checkpoint_get_position(&self, checkpoint: *mut alt_ICheckpoint) -> Vector3;
+    fn checkpoint_set_position(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3);
+
+    // IBlip
+
+    fn blip_is_global(&self, blip: *mut alt_IBlip) -> bool;
+    fn blip_get_target(&self, blip: *mut alt_IBlip) -> *mut alt_IPlayer;
+    fn blip_is_attached(&self, blip: *mut alt_IBlip) -> bool;
+    fn blip_attached_to(&self, blip: *mut alt_IBlip) -> *mut alt_IEntity;
+    fn blip_get_blip_type(&self, blip: *mut alt_IBlip) -> alt_IBlip_BlipType;
+    fn blip_set_sprite(&self, blip: *mut alt_IBlip, sprite: u16);
+    fn blip_set_color(&self, blip: *mut alt_IBlip, color: u8);
+    fn blip_set_route(&self, blip: *mut alt_IBlip, state: bool);
+    fn blip_set_route_color(&self, blip: *mut alt_IBlip, color: u8);
+    fn blip_get_dimension(&self, blip: *mut alt_IBlip) -> i32;
+    fn blip_set_dimension(&self, blip: *mut alt_IBlip, dimension: i32);
+    fn blip_get_position(&self, blip: *mut alt_IBlip) -> Vector3;
+    fn blip_set_position(&self, blip: *mut alt_IBlip, position: Vector3);
+
+    // IVoiceChannel
+
+    fn voice_channel_is_spatial(&self, voice_channel: *mut alt_IVoiceChannel) -> bool;
+    fn voice_channel_get_max_distance(&self, voice_channel: *mut alt_IVoiceChannel) -> f32;
+    fn voice_channel_has_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool;
+    fn voice_channel_add_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    );
+    fn voice_channel_remove_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    );
+    fn voice_channel_is_player_muted(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool;
+    fn voice_channel_mute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    );
+    fn voice_channel_unmute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    );
+}
@@ -0,0 +1,1763 @@
+use super::CoreBackend;
+use crate::mvalue::MValue;
+use crate::natives::*;
+use crate::rgba::Rgba;
+use crate::string;
+use crate::string_view::StringView;
+use crate::vector::{Rotation3, Vector3};
+use altv_core::AltResource;
+use std::time::{Duration, Instant};
+
+/// Backend forwarding every call to the alt:V C API of the current core instance.
+pub struct NativeCore;
+
+impl NativeCore {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn new(core: *mut alt_ICore) -> Self {
+        unsafe {
+            alt_ICore_SetInstance(core);
+        }
+
+        NativeCore
+    }
+}
+
+fn string_view(str: &str) -> *mut alt_StringView {
+    Box::into_raw(Box::new(StringView::new(str).into()))
+}
+
+fn string(str: &str) -> *mut alt_String {
+    Box::into_raw(Box::new(string::String::new(str).into()))
+}
+
+/// Reads a ref store the generated C API left opaque, it only holds the pointer.
+unsafe fn opaque_ref_get<R, T>(ref_store: *mut R) -> *mut T {
+    *(ref_store as *mut *mut T)
+}
+
+/// An empty reference targets every player.
+unsafe fn player_ref(player: Option<*mut alt_IPlayer>) -> *mut alt_RefBase_RefStore_IPlayer {
+    match player {
+        Some(player) => alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+        None => alt_RefBase_RefStore_IPlayer_Create_3_CAPI_Heap(),
+    }
+}
+
+unsafe fn mvalue_ref_get(mvalue: *mut alt_RefBase_RefStore_IMValue) -> *mut alt_IMValue {
+    alt_RefBase_RefStore_constIMValue_Get(mvalue as *mut alt_RefBase_RefStore_constIMValue)
+}
+
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+impl CoreBackend for NativeCore {
+    fn log_info(&self, msg: &str) {
+        unsafe { alt_ICore_LogInfo(alt_ICore_Instance(), string_view(msg)) }
+    }
+
+    fn log_error(&self, msg: &str) {
+        unsafe { alt_ICore_LogError(alt_ICore_Instance(), string_view(msg)) }
+    }
+
+    fn log_warning(&self, msg: &str) {
+        unsafe { alt_ICore_LogWarning(alt_ICore_Instance(), string_view(msg)) }
+    }
+
+    fn log_debug(&self, msg: &str) {
+        unsafe { alt_ICore_LogDebug(alt_ICore_Instance(), string_view(msg)) }
+    }
+
+    fn log_colored(&self, msg: &str) {
+        unsafe { alt_ICore_LogColored(alt_ICore_Instance(), string_view(msg)) }
+    }
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn create_vehicle(
+        &self,
+        model: u32,
+        position: Vector3,
+        rotation: Rotation3,
+    ) -> *mut alt_IVehicle {
+        let rotation: alt_RotationLayout = rotation.into();
+
+        unsafe {
+            let veh = alt_ICore_CreateVehicle_CAPI_Heap(
+                alt_ICore_Instance(),
+                model,
+                Box::into_raw(Box::new(position.into())),
+                Box::into_raw(Box::new(rotation)) as *mut alt_Vector_float_3_RotationLayout,
+            );
+            (*veh).ptr
+        }
+    }
+
+    fn create_col_shape_sphere(&self, position: Vector3, radius: f32) -> *mut alt_IColShape {
+        unsafe {
+            let cs = alt_ICore_CreateColShapeSphere_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(position.into())),
+                radius,
+            );
+            (*cs).ptr
+        }
+    }
+
+    fn create_col_shape_cube(
+        &self,
+        start_position: Vector3,
+        end_position: Vector3,
+    ) -> *mut alt_IColShape {
+        unsafe {
+            let cs = alt_ICore_CreateColShapeCube_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(start_position.into())),
+                Box::into_raw(Box::new(end_position.into())),
+            );
+            (*cs).ptr
+        }
+    }
+
+    fn create_col_shape_rectangle(
+        &self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        z: f32,
+    ) -> *mut alt_IColShape {
+        unsafe {
+            let cs = alt_ICore_CreateColShapeRectangle_CAPI_Heap(
+                alt_ICore_Instance(),
+                x1,
+                y1,
+                x2,
+                y2,
+                z,
+            );
+            (*cs).ptr
+        }
+    }
+
+    fn create_col_shape_circle(&self, position: Vector3, radius: f32) -> *mut alt_IColShape {
+        unsafe {
+            let cs = alt_ICore_CreateColShapeCircle_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(position.into())),
+                radius,
+            );
+            (*cs).ptr
+        }
+    }
+
+    fn create_col_shape_cylinder(
+        &self,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+    ) -> *mut alt_IColShape {
+        unsafe {
+            let cs = alt_ICore_CreateColShapeCylinder_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(position.into())),
+                radius,
+                height,
+            );
+            (*cs).ptr
+        }
+    }
+
+    fn create_checkpoint(
+        &self,
+        checkpoint_type: u8,
+        position: Vector3,
+        radius: f32,
+        height: f32,
+        color: Rgba,
+    ) -> *mut alt_ICheckpoint {
+        let color: alt_RGBA = color.into();
+
+        unsafe {
+            let cp = alt_ICore_CreateCheckpoint_CAPI_Heap(
+                alt_ICore_Instance(),
+                checkpoint_type,
+                Box::into_raw(Box::new(position.into())),
+                radius,
+                height,
+                Box::into_raw(Box::new(color)),
+            );
+            opaque_ref_get(cp)
+        }
+    }
+
+    fn create_blip(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        position: Vector3,
+    ) -> *mut alt_IBlip {
+        unsafe {
+            let blip = alt_ICore_CreateBlip_CAPI_Heap(
+                alt_ICore_Instance(),
+                player_ref(target),
+                blip_type,
+                Box::into_raw(Box::new(position.into())),
+            );
+            opaque_ref_get(blip)
+        }
+    }
+
+    fn create_blip_attached(
+        &self,
+        target: Option<*mut alt_IPlayer>,
+        blip_type: alt_IBlip_BlipType,
+        attach_to: *mut alt_IEntity,
+    ) -> *mut alt_IBlip {
+        unsafe {
+            let blip = alt_ICore_CreateBlip_1_CAPI_Heap(
+                alt_ICore_Instance(),
+                player_ref(target),
+                blip_type,
+                alt_RefBase_RefStore_IEntity_Create_4_CAPI_Heap(attach_to),
+            );
+            opaque_ref_get(blip)
+        }
+    }
+
+    fn create_voice_channel(&self, spatial: bool, max_distance: f32) -> *mut alt_IVoiceChannel {
+        unsafe {
+            let voice_channel =
+                alt_ICore_CreateVoiceChannel_CAPI_Heap(alt_ICore_Instance(), spatial, max_distance);
+            opaque_ref_get(voice_channel)
+        }
+    }
+
+    fn destroy_base_object(&self, base_obj: *mut alt_IBaseObject) {
+        unsafe {
+            alt_ICore_DestroyBaseObject(
+                alt_ICore_Instance(),
+                alt_RefBase_RefStore_IBaseObject_Create_2_CAPI_Heap(base_obj),
+            )
+        }
+    }
+
+    fn trigger_client_event(
+        &self,
+        alt: &AltResource,
+        target: Option<*mut alt_IPlayer>,
+        name: &str,
+        args: &[MValue],
+    ) -> crate::Result<()> {
+        let args = crate::array::convert_iter_to_array_mvalue(alt, args.iter())?;
+
+        unsafe {
+            alt_ICore_TriggerClientEvent(
+                alt_ICore_Instance(),
+                player_ref(target),
+                string_view(name),
+                args,
+            )
+        }
+        Ok(())
+    }
+
+    fn create_mvalue_none(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueNone_CAPI_Heap(alt_ICore_Instance())
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_nil(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueNil_CAPI_Heap(alt_ICore_Instance())
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_bool(&self, value: bool) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueBool_CAPI_Heap(alt_ICore_Instance(), value)
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_int(&self, value: i64) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueInt_CAPI_Heap(alt_ICore_Instance(), value)
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_uint(&self, value: u64) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueUInt_CAPI_Heap(alt_ICore_Instance(), value)
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_double(&self, value: f64) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueDouble_CAPI_Heap(alt_ICore_Instance(), value)
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_string(&self, value: &str) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueString_CAPI_Heap(alt_ICore_Instance(), string(value))
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_list(&self, size: u64) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueList_CAPI_Heap(alt_ICore_Instance(), size)
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_dict(&self) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueDict_CAPI_Heap(alt_ICore_Instance())
+                as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_base_object(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueBaseObject_CAPI_Heap(
+                alt_ICore_Instance(),
+                alt_RefBase_RefStore_IBaseObject_Create_2_CAPI_Heap(base_obj),
+            ) as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_vector3(&self, value: Vector3) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueVector3_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(value.into())),
+            ) as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_rgba(&self, value: Rgba) -> *mut alt_RefBase_RefStore_IMValue {
+        unsafe {
+            alt_ICore_CreateMValueRGBA_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(Box::new(value.into())),
+            ) as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn create_mvalue_byte_array(&self, value: Vec<u8>) -> *mut alt_RefBase_RefStore_IMValue {
+        let size = value.len() as u64;
+
+        unsafe {
+            alt_ICore_CreateMValueByteArray_CAPI_Heap(
+                alt_ICore_Instance(),
+                Box::into_raw(value.into_boxed_slice()) as *mut u8,
+                size,
+            ) as *mut alt_RefBase_RefStore_IMValue
+        }
+    }
+
+    fn mvalue_ref_get(&self, mvalue: *mut alt_RefBase_RefStore_IMValue) -> *mut alt_IMValue {
+        unsafe { mvalue_ref_get(mvalue) }
+    }
+
+    fn mvalue_get_type(&self, mvalue: *mut alt_IMValue) -> alt_IMValue_Type {
+        unsafe { alt_IMValue_GetType(mvalue) }
+    }
+
+    fn mvalue_get_bool(&self, mvalue: *mut alt_IMValue) -> bool {
+        unsafe { alt_IMValueBool_Value(alt_IMValue_to_alt_IMValueBool(mvalue)) }
+    }
+
+    fn mvalue_get_int(&self, mvalue: *mut alt_IMValue) -> i64 {
+        unsafe { alt_IMValueInt_Value(alt_IMValue_to_alt_IMValueInt(mvalue)) }
+    }
+
+    fn mvalue_get_uint(&self, mvalue: *mut alt_IMValue) -> u64 {
+        unsafe { alt_IMValueUInt_Value(alt_IMValue_to_alt_IMValueUInt(mvalue)) }
+    }
+
+    fn mvalue_get_double(&self, mvalue: *mut alt_IMValue) -> f64 {
+        unsafe { alt_IMValueDouble_Value(alt_IMValue_to_alt_IMValueDouble(mvalue)) }
+    }
+
+    fn mvalue_get_string(&self, mvalue: *mut alt_IMValue) -> String {
+        unsafe {
+            let val = alt_IMValueString_Value_CAPI_Heap(alt_IMValue_to_alt_IMValueString(mvalue));
+            StringView::from(*val).get_data()
+        }
+    }
+
+    fn mvalue_get_base_object(&self, mvalue: *mut alt_IMValue) -> *mut alt_IBaseObject {
+        unsafe {
+            let val =
+                alt_IMValueBaseObject_Value_CAPI_Heap(alt_IMValue_to_alt_IMValueBaseObject(mvalue));
+            (*val).ptr
+        }
+    }
+
+    fn mvalue_get_vector3(&self, mvalue: *mut alt_IMValue) -> Vector3 {
+        unsafe {
+            let val = alt_IMValueVector3_Value_CAPI_Heap(alt_IMValue_to_alt_IMValueVector3(mvalue));
+            Vector3::from(*val)
+        }
+    }
+
+    fn mvalue_get_rgba(&self, mvalue: *mut alt_IMValue) -> Rgba {
+        unsafe {
+            let val = alt_IMValueRGBA_Value_CAPI_Heap(alt_IMValue_to_alt_IMValueRGBA(mvalue));
+            Rgba::from(*val)
+        }
+    }
+
+    fn mvalue_get_byte_array(&self, mvalue: *mut alt_IMValue) -> Vec<u8> {
+        unsafe {
+            let ptr = alt_IMValue_to_alt_IMValueByteArray(mvalue);
+            let size = alt_IMValueByteArray_GetSize(ptr);
+            let val = alt_IMValueByteArray_GetData(ptr);
+
+            std::slice::from_raw_parts(val, size as usize).to_vec()
+        }
+    }
+
+    fn mvalue_list_get_size(&self, list: *mut alt_IMValue) -> u64 {
+        unsafe { alt_IMValueList_GetSize(alt_IMValue_to_alt_IMValueList(list)) }
+    }
+
+    fn mvalue_list_get(&self, list: *mut alt_IMValue, index: u64) -> *mut alt_IMValue {
+        unsafe {
+            let val = alt_IMValueList_Get_1_CAPI_Heap(alt_IMValue_to_alt_IMValueList(list), index);
+            alt_RefBase_RefStore_constIMValue_Get(val)
+        }
+    }
+
+    fn mvalue_list_set(
+        &self,
+        list: *mut alt_RefBase_RefStore_IMValue,
+        index: u64,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    ) {
+        unsafe {
+            alt_IMValueList_Set(
+                alt_IMValue_to_alt_IMValueList(mvalue_ref_get(list)),
+                index,
+                value,
+            )
+        }
+    }
+
+    fn mvalue_dict_get_keys(&self, dict: *mut alt_IMValue) -> Vec<String> {
+        unsafe {
+            let ptr = alt_IMValue_to_alt_IMValueDict(dict);
+            let size = alt_IMValueDict_GetSize(ptr);
+
+            let mut keys = Vec::with_capacity(size as usize);
+            let mut iter = alt_IMValueDict_Begin(ptr);
+
+            for _ in 0..size {
+                let key = alt_IMValueDict_Iterator_GetKey_CAPI_Heap(iter);
+                keys.push(string::String::from(*key).get_data());
+
+                iter = alt_IMValueDict_Next(ptr);
+            }
+
+            keys
+        }
+    }
+
+    fn mvalue_dict_get(&self, dict: *mut alt_IMValue, key: &str) -> *mut alt_IMValue {
+        unsafe {
+            let val =
+                alt_IMValueDict_Get_1_CAPI_Heap(alt_IMValue_to_alt_IMValueDict(dict), string(key));
+            alt_RefBase_RefStore_constIMValue_Get(val)
+        }
+    }
+
+    fn mvalue_dict_set(
+        &self,
+        dict: *mut alt_RefBase_RefStore_IMValue,
+        key: &str,
+        value: *mut alt_RefBase_RefStore_IMValue,
+    ) {
+        unsafe {
+            alt_IMValueDict_Set(
+                alt_IMValue_to_alt_IMValueDict(mvalue_ref_get(dict)),
+                string(key),
+                value,
+            )
+        }
+    }
+
+    fn base_object_to_ref_countable(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_CRefCountable {
+        unsafe { alt_IBaseObject_to_alt_CRefCountable(base_obj) }
+    }
+
+    fn base_object_to_world_object(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IWorldObject {
+        unsafe { alt_IBaseObject_to_alt_IWorldObject(base_obj) }
+    }
+
+    fn base_object_to_entity(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IEntity {
+        unsafe { alt_IBaseObject_to_alt_IEntity(base_obj) }
+    }
+
+    fn base_object_to_player(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IPlayer {
+        unsafe { alt_IBaseObject_to_alt_IPlayer(base_obj) }
+    }
+
+    fn base_object_to_vehicle(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IVehicle {
+        unsafe { alt_IBaseObject_to_alt_IVehicle(base_obj) }
+    }
+
+    fn base_object_to_blip(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IBlip {
+        unsafe { alt_IBaseObject_to_alt_IBlip(base_obj) }
+    }
+
+    fn base_object_to_voice_channel(
+        &self,
+        base_obj: *mut alt_IBaseObject,
+    ) -> *mut alt_IVoiceChannel {
+        unsafe { alt_IBaseObject_to_alt_IVoiceChannel(base_obj) }
+    }
+
+    fn base_object_to_col_shape(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_IColShape {
+        unsafe { alt_IBaseObject_to_alt_IColShape(base_obj) }
+    }
+
+    fn base_object_to_checkpoint(&self, base_obj: *mut alt_IBaseObject) -> *mut alt_ICheckpoint {
+        unsafe { alt_IBaseObject_to_alt_ICheckpoint(base_obj) }
+    }
+
+    fn entity_to_base_object(&self, entity: *mut alt_IEntity) -> *mut alt_IBaseObject {
+        unsafe { alt_IEntity_to_alt_IBaseObject(entity) }
+    }
+
+    fn vehicle_to_base_object(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IBaseObject {
+        unsafe { alt_IVehicle_to_alt_IBaseObject(vehicle) }
+    }
+
+    fn col_shape_to_base_object(&self, col_shape: *mut alt_IColShape) -> *mut alt_IBaseObject {
+        unsafe { alt_IColShape_to_alt_IBaseObject(col_shape) }
+    }
+
+    fn checkpoint_to_base_object(&self, checkpoint: *mut alt_ICheckpoint) -> *mut alt_IBaseObject {
+        unsafe { alt_ICheckpoint_to_alt_IBaseObject(checkpoint) }
+    }
+
+    fn blip_to_base_object(&self, blip: *mut alt_IBlip) -> *mut alt_IBaseObject {
+        unsafe { alt_IBlip_to_alt_IBaseObject(blip) }
+    }
+
+    fn voice_channel_to_base_object(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+    ) -> *mut alt_IBaseObject {
+        unsafe { alt_IVoiceChannel_to_alt_IBaseObject(voice_channel) }
+    }
+
+    fn ref_countable_get_ref_count(&self, ref_countable: *mut alt_CRefCountable) -> u64 {
+        unsafe { alt_CRefCountable_GetRefCount(ref_countable) }
+    }
+
+    fn ref_countable_add_ref(&self, ref_countable: *mut alt_CRefCountable) {
+        unsafe { alt_CRefCountable_AddRef(ref_countable) }
+    }
+
+    fn ref_countable_remove_ref(&self, ref_countable: *mut alt_CRefCountable) {
+        unsafe { alt_CRefCountable_RemoveRef(ref_countable) }
+    }
+
+    fn base_object_get_type(&self, base_obj: *mut alt_IBaseObject) -> alt_IBaseObject_Type {
+        unsafe { alt_IBaseObject_GetType(base_obj) }
+    }
+
+    fn base_object_has_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str) -> bool {
+        unsafe { alt_IBaseObject_HasMetaData(base_obj, string_view(key)) }
+    }
+
+    fn base_object_get_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        unsafe {
+            let val = alt_IBaseObject_GetMetaData_CAPI_Heap(base_obj, string_view(key));
+            MValue::new(alt, (*val).ptr)
+        }
+    }
+
+    fn base_object_set_meta_data(
+        &self,
+        alt: &AltResource,
+        base_obj: *mut alt_IBaseObject,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let value = value.into_ref(alt)?;
+        unsafe { alt_IBaseObject_SetMetaData(base_obj, string_view(key), value) }
+        Ok(())
+    }
+
+    fn base_object_delete_meta_data(&self, base_obj: *mut alt_IBaseObject, key: &str) {
+        unsafe { alt_IBaseObject_DeleteMetaData(base_obj, string_view(key)) }
+    }
+
+    fn world_object_get_dimension(&self, world_obj: *mut alt_IWorldObject) -> i32 {
+        unsafe { alt_IWorldObject_GetDimension(world_obj) }
+    }
+
+    fn world_object_set_dimension(&self, world_obj: *mut alt_IWorldObject, dimension: i32) {
+        unsafe { alt_IWorldObject_SetDimension(world_obj, dimension) }
+    }
+
+    fn world_object_get_position(&self, world_obj: *mut alt_IWorldObject) -> Vector3 {
+        unsafe {
+            let pos = alt_IWorldObject_GetPosition_CAPI_Heap(world_obj);
+            Vector3::from(*pos)
+        }
+    }
+
+    fn world_object_set_position(&self, world_obj: *mut alt_IWorldObject, position: Vector3) {
+        unsafe { alt_IWorldObject_SetPosition(world_obj, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn entity_get_id(&self, entity: *mut alt_IEntity) -> u16 {
+        unsafe { alt_IEntity_GetID(entity) }
+    }
+
+    fn entity_get_network_owner(&self, entity: *mut alt_IEntity) -> *mut alt_IPlayer {
+        unsafe { (*alt_IEntity_GetNetworkOwner_CAPI_Heap(entity)).ptr }
+    }
+
+    fn entity_get_model(&self, entity: *mut alt_IEntity) -> u32 {
+        unsafe { alt_IEntity_GetModel(entity) }
+    }
+
+    fn entity_get_rotation(&self, entity: *mut alt_IEntity) -> Rotation3 {
+        unsafe {
+            let rot = alt_IEntity_GetRotation_CAPI_Heap(entity);
+            Rotation3::from(*(rot as *mut alt_RotationLayout))
+        }
+    }
+
+    fn entity_set_rotation(&self, entity: *mut alt_IEntity, rotation: Rotation3) {
+        let rotation: alt_RotationLayout = rotation.into();
+
+        unsafe {
+            alt_IEntity_SetRotation(
+                entity,
+                Box::into_raw(Box::new(rotation)) as *mut alt_Vector_float_3_RotationLayout,
+            )
+        }
+    }
+
+    fn entity_has_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool {
+        unsafe { alt_IEntity_HasSyncedMetaData(entity, string_view(key)) }
+    }
+
+    fn entity_get_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        unsafe {
+            let val = alt_IEntity_GetSyncedMetaData_CAPI_Heap(entity, string_view(key));
+            MValue::new(alt, (*val).ptr)
+        }
+    }
+
+    fn entity_set_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let value = value.into_ref(alt)?;
+        unsafe { alt_IEntity_SetSyncedMetaData(entity, string_view(key), value) }
+        Ok(())
+    }
+
+    fn entity_delete_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) {
+        unsafe { alt_IEntity_DeleteSyncedMetaData(entity, string_view(key)) }
+    }
+
+    fn entity_has_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) -> bool {
+        unsafe { alt_IEntity_HasStreamSyncedMetaData(entity, string_view(key)) }
+    }
+
+    fn entity_get_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+    ) -> crate::Result<MValue> {
+        unsafe {
+            let val = alt_IEntity_GetStreamSyncedMetaData_CAPI_Heap(entity, string_view(key));
+            MValue::new(alt, (*val).ptr)
+        }
+    }
+
+    fn entity_set_stream_synced_meta_data(
+        &self,
+        alt: &AltResource,
+        entity: *mut alt_IEntity,
+        key: &str,
+        value: MValue,
+    ) -> crate::Result<()> {
+        let value = value.into_ref(alt)?;
+        unsafe { alt_IEntity_SetStreamSyncedMetaData(entity, string_view(key), value) }
+        Ok(())
+    }
+
+    fn entity_delete_stream_synced_meta_data(&self, entity: *mut alt_IEntity, key: &str) {
+        unsafe { alt_IEntity_DeleteStreamSyncedMetaData(entity, string_view(key)) }
+    }
+
+    fn player_is_connected(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsConnected(player) }
+    }
+
+    fn player_get_ping(&self, player: *mut alt_IPlayer) -> u32 {
+        unsafe { alt_IPlayer_GetPing(player) }
+    }
+
+    fn player_get_ip(&self, player: *mut alt_IPlayer) -> String {
+        unsafe {
+            let ip = alt_IPlayer_GetIP_CAPI_Heap(player);
+            StringView::from(*ip).get_data()
+        }
+    }
+
+    fn player_spawn(&self, player: *mut alt_IPlayer, position: Vector3, delay: Duration) {
+        unsafe {
+            alt_IPlayer_Spawn(
+                player,
+                Box::into_raw(Box::new(position.into())),
+                delay.as_millis() as u32,
+            )
+        }
+    }
+
+    fn player_despawn(&self, player: *mut alt_IPlayer) {
+        unsafe { alt_IPlayer_Despawn(player) }
+    }
+
+    fn player_get_name(&self, player: *mut alt_IPlayer) -> String {
+        unsafe {
+            let name = alt_IPlayer_GetName_CAPI_Heap(player);
+            StringView::from(*name).get_data()
+        }
+    }
+
+    fn player_get_social_id(&self, player: *mut alt_IPlayer) -> u64 {
+        unsafe { alt_IPlayer_GetSocialID(player) }
+    }
+
+    fn player_get_hwid_hash(&self, player: *mut alt_IPlayer) -> u64 {
+        unsafe { alt_IPlayer_GetHwidHash(player) }
+    }
+
+    fn player_get_hwid_ex_hash(&self, player: *mut alt_IPlayer) -> u64 {
+        unsafe { alt_IPlayer_GetHwidExHash(player) }
+    }
+
+    fn player_get_auth_token(&self, player: *mut alt_IPlayer) -> String {
+        unsafe {
+            let at = alt_IPlayer_GetAuthToken_CAPI_Heap(player);
+            StringView::from(*at).get_data()
+        }
+    }
+
+    fn player_get_health(&self, player: *mut alt_IPlayer) -> u16 {
+        unsafe { alt_IPlayer_GetHealth(player) }
+    }
+
+    fn player_set_health(&self, player: *mut alt_IPlayer, health: u16) {
+        unsafe { alt_IPlayer_SetHealth(player, health) }
+    }
+
+    fn player_get_max_health(&self, player: *mut alt_IPlayer) -> u16 {
+        unsafe { alt_IPlayer_GetMaxHealth(player) }
+    }
+
+    fn player_set_max_health(&self, player: *mut alt_IPlayer, max_health: u16) {
+        unsafe { alt_IPlayer_SetMaxHealth(player, max_health) }
+    }
+
+    fn player_set_date_time(
+        &self,
+        player: *mut alt_IPlayer,
+        day: i32,
+        month: i32,
+        year: i32,
+        hour: i32,
+        minute: i32,
+        second: i32,
+    ) {
+        unsafe { alt_IPlayer_SetDateTime(player, day, month, year, hour, minute, second) }
+    }
+
+    fn player_set_weather(&self, player: *mut alt_IPlayer, weather: u32) {
+        unsafe { alt_IPlayer_SetWeather(player, weather) }
+    }
+
+    fn player_give_weapon(&self, player: *mut alt_IPlayer, weapon: u32, ammo: i32, select: bool) {
+        unsafe { alt_IPlayer_GiveWeapon(player, weapon, ammo, select) }
+    }
+
+    fn player_remove_weapon(&self, player: *mut alt_IPlayer, weapon: u32) {
+        unsafe { alt_IPlayer_RemoveWeapon(player, weapon) }
+    }
+
+    fn player_remove_all_weapons(&self, player: *mut alt_IPlayer) {
+        unsafe { alt_IPlayer_RemoveAllWeapons(player) }
+    }
+
+    fn player_add_weapon_component(&self, player: *mut alt_IPlayer, weapon: u32, component: u32) {
+        unsafe { alt_IPlayer_AddWeaponComponent(player, weapon, component) }
+    }
+
+    fn player_remove_weapon_component(
+        &self,
+        player: *mut alt_IPlayer,
+        weapon: u32,
+        component: u32,
+    ) {
+        unsafe { alt_IPlayer_RemoveWeaponComponent(player, weapon, component) }
+    }
+
+    fn player_set_weapon_tint_index(&self, player: *mut alt_IPlayer, weapon: u32, tint_index: u8) {
+        unsafe { alt_IPlayer_SetWeaponTintIndex(player, weapon, tint_index) }
+    }
+
+    fn player_get_current_weapon_tint_index(&self, player: *mut alt_IPlayer) -> u8 {
+        unsafe { alt_IPlayer_GetCurrentWeaponTintIndex(player) }
+    }
+
+    fn player_get_current_weapon(&self, player: *mut alt_IPlayer) -> u32 {
+        unsafe { alt_IPlayer_GetCurrentWeapon(player) }
+    }
+
+    fn player_set_current_weapon(&self, player: *mut alt_IPlayer, weapon: u32) {
+        unsafe { alt_IPlayer_SetCurrentWeapon(player, weapon) }
+    }
+
+    fn player_is_dead(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsDead(player) }
+    }
+
+    fn player_is_jumping(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsJumping(player) }
+    }
+
+    fn player_is_in_ragdoll(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsInRagdoll(player) }
+    }
+
+    fn player_is_aiming(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsAiming(player) }
+    }
+
+    fn player_is_shooting(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsShooting(player) }
+    }
+
+    fn player_is_reloading(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsReloading(player) }
+    }
+
+    fn player_get_armor(&self, player: *mut alt_IPlayer) -> u16 {
+        unsafe { alt_IPlayer_GetArmour(player) }
+    }
+
+    fn player_set_armor(&self, player: *mut alt_IPlayer, armor: u16) {
+        unsafe { alt_IPlayer_SetArmour(player, armor) }
+    }
+
+    fn player_get_max_armor(&self, player: *mut alt_IPlayer) -> u16 {
+        unsafe { alt_IPlayer_GetMaxArmour(player) }
+    }
+
+    fn player_set_max_armor(&self, player: *mut alt_IPlayer, max_armor: u16) {
+        unsafe { alt_IPlayer_SetMaxArmour(player, max_armor) }
+    }
+
+    fn player_get_move_speed(&self, player: *mut alt_IPlayer) -> f32 {
+        unsafe { alt_IPlayer_GetMoveSpeed(player) }
+    }
+
+    fn player_get_weapon(&self, player: *mut alt_IPlayer) -> u32 {
+        unsafe { alt_IPlayer_GetWeapon(player) }
+    }
+
+    fn player_get_ammo(&self, player: *mut alt_IPlayer) -> u16 {
+        unsafe { alt_IPlayer_GetAmmo(player) }
+    }
+
+    fn player_get_aim_position(&self, player: *mut alt_IPlayer) -> Vector3 {
+        unsafe {
+            let pos = alt_IPlayer_GetAimPos_CAPI_Heap(player);
+            Vector3::from(*pos)
+        }
+    }
+
+    fn player_get_head_rotation(&self, player: *mut alt_IPlayer) -> Rotation3 {
+        unsafe {
+            let rot = alt_IPlayer_GetHeadRotation_CAPI_Heap(player);
+            Rotation3::from(*(rot as *mut alt_RotationLayout))
+        }
+    }
+
+    fn player_is_in_vehicle(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsInVehicle(player) }
+    }
+
+    fn player_get_vehicle(&self, player: *mut alt_IPlayer) -> *mut alt_IVehicle {
+        unsafe { (*alt_IPlayer_GetVehicle_CAPI_Heap(player)).ptr }
+    }
+
+    fn player_get_seat(&self, player: *mut alt_IPlayer) -> u8 {
+        unsafe { alt_IPlayer_GetSeat(player) }
+    }
+
+    fn player_get_entity_aiming_at(&self, player: *mut alt_IPlayer) -> *mut alt_IEntity {
+        unsafe { (*alt_IPlayer_GetEntityAimingAt_CAPI_Heap(player)).ptr }
+    }
+
+    fn player_get_entity_aim_offset(&self, player: *mut alt_IPlayer) -> Vector3 {
+        unsafe {
+            let offset = alt_IPlayer_GetEntityAimOffset_CAPI_Heap(player);
+            Vector3::from(*offset)
+        }
+    }
+
+    fn player_is_flashlight_active(&self, player: *mut alt_IPlayer) -> bool {
+        unsafe { alt_IPlayer_IsFlashlightActive(player) }
+    }
+
+    fn player_kick(&self, player: *mut alt_IPlayer, reason: &str) {
+        unsafe { alt_IPlayer_Kick(player, string_view(reason)) }
+    }
+
+    fn player_set_model(&self, player: *mut alt_IPlayer, model: u32) {
+        unsafe { alt_IPlayer_SetModel(player, model) }
+    }
+
+    fn vehicle_get_driver(&self, vehicle: *mut alt_IVehicle) -> *mut alt_IPlayer {
+        unsafe { (*alt_IVehicle_GetDriver_CAPI_Heap(vehicle)).ptr }
+    }
+
+    fn vehicle_get_mod(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8 {
+        unsafe { alt_IVehicle_GetMod(vehicle, category) }
+    }
+
+    fn vehicle_get_mods_count(&self, vehicle: *mut alt_IVehicle, category: u8) -> u8 {
+        unsafe { alt_IVehicle_GetModsCount(vehicle, category) }
+    }
+
+    fn vehicle_set_mod(&self, vehicle: *mut alt_IVehicle, category: u8, id: u8) -> bool {
+        unsafe { alt_IVehicle_SetMod(vehicle, category, id) }
+    }
+
+    fn vehicle_get_mod_kit(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetModKit(vehicle) }
+    }
+
+    fn vehicle_get_mod_kits_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetModKitsCount(vehicle) }
+    }
+
+    fn vehicle_set_mod_kit(&self, vehicle: *mut alt_IVehicle, id: u8) -> bool {
+        unsafe { alt_IVehicle_SetModKit(vehicle, id) }
+    }
+
+    fn vehicle_is_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsPrimaryColorRGB(vehicle) }
+    }
+
+    fn vehicle_get_primary_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetPrimaryColor(vehicle) }
+    }
+
+    fn vehicle_get_primary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        unsafe { Rgba::from(*alt_IVehicle_GetPrimaryColorRGB_CAPI_Heap(vehicle)) }
+    }
+
+    fn vehicle_set_primary_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetPrimaryColor(vehicle, color) }
+    }
+
+    fn vehicle_set_primary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        unsafe { alt_IVehicle_SetPrimaryColorRGB(vehicle, Box::into_raw(Box::new(color.into()))) }
+    }
+
+    fn vehicle_is_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsSecondaryColorRGB(vehicle) }
+    }
+
+    fn vehicle_get_secondary_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetSecondaryColor(vehicle) }
+    }
+
+    fn vehicle_get_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        unsafe { Rgba::from(*alt_IVehicle_GetSecondaryColorRGB_CAPI_Heap(vehicle)) }
+    }
+
+    fn vehicle_set_secondary_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetSecondaryColor(vehicle, color) }
+    }
+
+    fn vehicle_set_secondary_color_rgb(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        unsafe { alt_IVehicle_SetSecondaryColorRGB(vehicle, Box::into_raw(Box::new(color.into()))) }
+    }
+
+    fn vehicle_get_pearl_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetPearlColor(vehicle) }
+    }
+
+    fn vehicle_set_pearl_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetPearlColor(vehicle, color) }
+    }
+
+    fn vehicle_get_wheel_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetWheelColor(vehicle) }
+    }
+
+    fn vehicle_set_wheel_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetWheelColor(vehicle, color) }
+    }
+
+    fn vehicle_get_interior_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetInteriorColor(vehicle) }
+    }
+
+    fn vehicle_set_interior_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetInteriorColor(vehicle, color) }
+    }
+
+    fn vehicle_get_dashboard_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetDashboardColor(vehicle) }
+    }
+
+    fn vehicle_set_dashboard_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetDashboardColor(vehicle, color) }
+    }
+
+    fn vehicle_is_tire_smoke_color_custom(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsTireSmokeColorCustom(vehicle) }
+    }
+
+    fn vehicle_get_tire_smoke_color(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        unsafe { Rgba::from(*alt_IVehicle_GetTireSmokeColor_CAPI_Heap(vehicle)) }
+    }
+
+    fn vehicle_set_tire_smoke_color(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        unsafe { alt_IVehicle_SetTireSmokeColor(vehicle, Box::into_raw(Box::new(color.into()))) }
+    }
+
+    fn vehicle_get_wheel_type(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetWheelType(vehicle) }
+    }
+
+    fn vehicle_get_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetWheelVariation(vehicle) }
+    }
+
+    fn vehicle_get_rear_wheel_variation(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetRearWheelVariation(vehicle) }
+    }
+
+    fn vehicle_set_wheels(&self, vehicle: *mut alt_IVehicle, wheel_type: u8, variation: u8) {
+        unsafe { alt_IVehicle_SetWheels(vehicle, wheel_type, variation) }
+    }
+
+    fn vehicle_set_rear_wheels(&self, vehicle: *mut alt_IVehicle, variation: u8) {
+        unsafe { alt_IVehicle_SetRearWheels(vehicle, variation) }
+    }
+
+    fn vehicle_get_custom_tires(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_GetCustomTires(vehicle) }
+    }
+
+    fn vehicle_set_custom_tires(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        unsafe { alt_IVehicle_SetCustomTires(vehicle, state) }
+    }
+
+    fn vehicle_get_special_darkness(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetSpecialDarkness(vehicle) }
+    }
+
+    fn vehicle_set_special_darkness(&self, vehicle: *mut alt_IVehicle, special_darkness: u8) {
+        unsafe { alt_IVehicle_SetSpecialDarkness(vehicle, special_darkness) }
+    }
+
+    fn vehicle_get_numberplate_index(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        unsafe { alt_IVehicle_GetNumberplateIndex(vehicle) }
+    }
+
+    fn vehicle_set_numberplate_index(&self, vehicle: *mut alt_IVehicle, index: u32) {
+        unsafe { alt_IVehicle_SetNumberplateIndex(vehicle, index) }
+    }
+
+    fn vehicle_get_numberplate_text(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let text = alt_IVehicle_GetNumberplateText_CAPI_Heap(vehicle);
+            StringView::from(*text).get_data()
+        }
+    }
+
+    fn vehicle_set_numberplate_text(&self, vehicle: *mut alt_IVehicle, text: &str) {
+        unsafe { alt_IVehicle_SetNumberplateText(vehicle, string_view(text)) }
+    }
+
+    fn vehicle_get_window_tint(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetWindowTint(vehicle) }
+    }
+
+    fn vehicle_set_window_tint(&self, vehicle: *mut alt_IVehicle, window_tint: u8) {
+        unsafe { alt_IVehicle_SetWindowTint(vehicle, window_tint) }
+    }
+
+    fn vehicle_get_dirt_level(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetDirtLevel(vehicle) }
+    }
+
+    fn vehicle_set_dirt_level(&self, vehicle: *mut alt_IVehicle, dirt_level: u8) {
+        unsafe { alt_IVehicle_SetDirtLevel(vehicle, dirt_level) }
+    }
+
+    fn vehicle_is_extra_on(&self, vehicle: *mut alt_IVehicle, extra_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsExtraOn(vehicle, extra_id) }
+    }
+
+    fn vehicle_toggle_extra(&self, vehicle: *mut alt_IVehicle, extra_id: u8, state: bool) {
+        unsafe { alt_IVehicle_ToggleExtra(vehicle, extra_id, state) }
+    }
+
+    fn vehicle_is_neon_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsNeonActive(vehicle) }
+    }
+
+    fn vehicle_get_neon_active(&self, vehicle: *mut alt_IVehicle) -> (bool, bool, bool, bool) {
+        let mut left = false;
+        let mut right = false;
+        let mut front = false;
+        let mut back = false;
+
+        unsafe {
+            alt_IVehicle_GetNeonActive(vehicle, &mut left, &mut right, &mut front, &mut back);
+        }
+
+        (left, right, front, back)
+    }
+
+    fn vehicle_set_neon_active(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        left: bool,
+        right: bool,
+        front: bool,
+        back: bool,
+    ) {
+        unsafe { alt_IVehicle_SetNeonActive(vehicle, left, right, front, back) }
+    }
+
+    fn vehicle_get_neon_color(&self, vehicle: *mut alt_IVehicle) -> Rgba {
+        unsafe { Rgba::from(*alt_IVehicle_GetNeonColor_CAPI_Heap(vehicle)) }
+    }
+
+    fn vehicle_set_neon_color(&self, vehicle: *mut alt_IVehicle, color: Rgba) {
+        unsafe { alt_IVehicle_SetNeonColor(vehicle, Box::into_raw(Box::new(color.into()))) }
+    }
+
+    fn vehicle_get_livery(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetLivery(vehicle) }
+    }
+
+    fn vehicle_set_livery(&self, vehicle: *mut alt_IVehicle, livery: u8) {
+        unsafe { alt_IVehicle_SetLivery(vehicle, livery) }
+    }
+
+    fn vehicle_get_roof_livery(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetRoofLivery(vehicle) }
+    }
+
+    fn vehicle_set_roof_livery(&self, vehicle: *mut alt_IVehicle, roof_livery: u8) {
+        unsafe { alt_IVehicle_SetRoofLivery(vehicle, roof_livery) }
+    }
+
+    fn vehicle_get_appearance_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let b = alt_IVehicle_GetAppearanceDataBase64_CAPI_Heap(vehicle);
+            crate::string::String::from(*b).get_data()
+        }
+    }
+
+    fn vehicle_load_appearance_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        unsafe { alt_IVehicle_LoadAppearanceDataFromBase64(vehicle, string_view(base64)) }
+    }
+
+    fn vehicle_is_engine_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsEngineOn(vehicle) }
+    }
+
+    fn vehicle_set_engine_on(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        unsafe { alt_IVehicle_SetEngineOn(vehicle, state) }
+    }
+
+    fn vehicle_is_handbrake_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsHandbrakeActive(vehicle) }
+    }
+
+    fn vehicle_get_headlight_color(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetHeadlightColor(vehicle) }
+    }
+
+    fn vehicle_set_headlight_color(&self, vehicle: *mut alt_IVehicle, color: u8) {
+        unsafe { alt_IVehicle_SetHeadlightColor(vehicle, color) }
+    }
+
+    fn vehicle_get_radio_station_index(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        unsafe { alt_IVehicle_GetRadioStationIndex(vehicle) }
+    }
+
+    fn vehicle_set_radio_station_index(&self, vehicle: *mut alt_IVehicle, index: u32) {
+        unsafe { alt_IVehicle_SetRadioStationIndex(vehicle, index) }
+    }
+
+    fn vehicle_is_siren_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsSirenActive(vehicle) }
+    }
+
+    fn vehicle_set_siren_active(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        unsafe { alt_IVehicle_SetSirenActive(vehicle, state) }
+    }
+
+    fn vehicle_get_lock_state(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetLockState(vehicle) }
+    }
+
+    fn vehicle_set_lock_state(&self, vehicle: *mut alt_IVehicle, lock_state: u8) {
+        unsafe { alt_IVehicle_SetLockState(vehicle, lock_state) }
+    }
+
+    fn vehicle_get_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8) -> u8 {
+        unsafe { alt_IVehicle_GetDoorState(vehicle, door_id) }
+    }
+
+    fn vehicle_set_door_state(&self, vehicle: *mut alt_IVehicle, door_id: u8, state: u8) {
+        unsafe { alt_IVehicle_SetDoorState(vehicle, door_id, state) }
+    }
+
+    fn vehicle_is_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsWindowOpened(vehicle, window_id) }
+    }
+
+    fn vehicle_set_window_opened(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWindowOpened(vehicle, window_id, state) }
+    }
+
+    fn vehicle_is_daylight_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsDaylightOn(vehicle) }
+    }
+
+    fn vehicle_is_nightlight_on(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsNightlightOn(vehicle) }
+    }
+
+    fn vehicle_is_roof_opened(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsRoofOpened(vehicle) }
+    }
+
+    fn vehicle_set_roof_opened(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        unsafe { alt_IVehicle_SetRoofOpened(vehicle, state) }
+    }
+
+    fn vehicle_is_flamethrower_active(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsFlamethrowerActive(vehicle) }
+    }
+
+    fn vehicle_get_lights_multiplier(&self, vehicle: *mut alt_IVehicle) -> f32 {
+        unsafe { alt_IVehicle_GetLightsMultiplier(vehicle) }
+    }
+
+    fn vehicle_set_lights_multiplier(&self, vehicle: *mut alt_IVehicle, multiplier: f32) {
+        unsafe { alt_IVehicle_SetLightsMultiplier(vehicle, multiplier) }
+    }
+
+    fn vehicle_get_engine_health(&self, vehicle: *mut alt_IVehicle) -> i32 {
+        unsafe { alt_IVehicle_GetEngineHealth(vehicle) }
+    }
+
+    fn vehicle_set_engine_health(&self, vehicle: *mut alt_IVehicle, health: i32) {
+        unsafe { alt_IVehicle_SetEngineHealth(vehicle, health) }
+    }
+
+    fn vehicle_get_petrol_tank_health(&self, vehicle: *mut alt_IVehicle) -> i32 {
+        unsafe { alt_IVehicle_GetPetrolTankHealth(vehicle) }
+    }
+
+    fn vehicle_set_petrol_tank_health(&self, vehicle: *mut alt_IVehicle, health: i32) {
+        unsafe { alt_IVehicle_SetPetrolTankHealth(vehicle, health) }
+    }
+
+    fn vehicle_get_wheels_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetWheelsCount(vehicle) }
+    }
+
+    fn vehicle_is_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsWheelBurst(vehicle, wheel_id) }
+    }
+
+    fn vehicle_set_wheel_burst(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWheelBurst(vehicle, wheel_id, state) }
+    }
+
+    fn vehicle_does_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        unsafe { alt_IVehicle_DoesWheelHasTire(vehicle, wheel_id) }
+    }
+
+    fn vehicle_set_wheel_has_tire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWheelHasTire(vehicle, wheel_id, state) }
+    }
+
+    fn vehicle_is_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsWheelDetached(vehicle, wheel_id) }
+    }
+
+    fn vehicle_set_wheel_detached(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWheelDetached(vehicle, wheel_id, state) }
+    }
+
+    fn vehicle_is_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsWheelOnFire(vehicle, wheel_id) }
+    }
+
+    fn vehicle_set_wheel_on_fire(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWheelOnFire(vehicle, wheel_id, state) }
+    }
+
+    fn vehicle_get_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8) -> f32 {
+        unsafe { alt_IVehicle_GetWheelHealth(vehicle, wheel_id) }
+    }
+
+    fn vehicle_set_wheel_health(&self, vehicle: *mut alt_IVehicle, wheel_id: u8, health: f32) {
+        unsafe { alt_IVehicle_SetWheelHealth(vehicle, wheel_id, health) }
+    }
+
+    fn vehicle_get_repairs_count(&self, vehicle: *mut alt_IVehicle) -> u8 {
+        unsafe { alt_IVehicle_GetRepairsCount(vehicle) }
+    }
+
+    fn vehicle_get_body_health(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        unsafe { alt_IVehicle_GetBodyHealth(vehicle) }
+    }
+
+    fn vehicle_set_body_health(&self, vehicle: *mut alt_IVehicle, health: u32) {
+        unsafe { alt_IVehicle_SetBodyHealth(vehicle, health) }
+    }
+
+    fn vehicle_get_body_additional_health(&self, vehicle: *mut alt_IVehicle) -> u32 {
+        unsafe { alt_IVehicle_GetBodyAdditionalHealth(vehicle) }
+    }
+
+    fn vehicle_set_body_additional_health(&self, vehicle: *mut alt_IVehicle, health: u32) {
+        unsafe { alt_IVehicle_SetBodyAdditionalHealth(vehicle, health) }
+    }
+
+    fn vehicle_get_health_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let b = alt_IVehicle_GetHealthDataBase64_CAPI_Heap(vehicle);
+            crate::string::String::from(*b).get_data()
+        }
+    }
+
+    fn vehicle_load_health_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        unsafe { alt_IVehicle_LoadHealthDataFromBase64(vehicle, string_view(base64)) }
+    }
+
+    fn vehicle_get_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8 {
+        unsafe { alt_IVehicle_GetPartDamageLevel(vehicle, part_id) }
+    }
+
+    fn vehicle_set_part_damage_level(&self, vehicle: *mut alt_IVehicle, part_id: u8, level: u8) {
+        unsafe { alt_IVehicle_SetPartDamageLevel(vehicle, part_id, level) }
+    }
+
+    fn vehicle_get_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8) -> u8 {
+        unsafe { alt_IVehicle_GetPartBulletHoles(vehicle, part_id) }
+    }
+
+    fn vehicle_set_part_bullet_holes(&self, vehicle: *mut alt_IVehicle, part_id: u8, count: u8) {
+        unsafe { alt_IVehicle_SetPartBulletHoles(vehicle, part_id, count) }
+    }
+
+    fn vehicle_is_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsLightDamaged(vehicle, light_id) }
+    }
+
+    fn vehicle_set_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetLightDamaged(vehicle, light_id, state) }
+    }
+
+    fn vehicle_is_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsWindowDamaged(vehicle, window_id) }
+    }
+
+    fn vehicle_set_window_damaged(&self, vehicle: *mut alt_IVehicle, window_id: u8, state: bool) {
+        unsafe { alt_IVehicle_SetWindowDamaged(vehicle, window_id, state) }
+    }
+
+    fn vehicle_is_special_light_damaged(&self, vehicle: *mut alt_IVehicle, light_id: u8) -> bool {
+        unsafe { alt_IVehicle_IsSpecialLightDamaged(vehicle, light_id) }
+    }
+
+    fn vehicle_set_special_light_damaged(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        light_id: u8,
+        state: bool,
+    ) {
+        unsafe { alt_IVehicle_SetSpecialLightDamaged(vehicle, light_id, state) }
+    }
+
+    fn vehicle_has_armored_windows(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_HasArmoredWindows(vehicle) }
+    }
+
+    fn vehicle_get_armored_window_health(&self, vehicle: *mut alt_IVehicle, window_id: u8) -> f32 {
+        unsafe { alt_IVehicle_GetArmoredWindowHealth(vehicle, window_id) }
+    }
+
+    fn vehicle_set_armored_window_health(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        health: f32,
+    ) {
+        unsafe { alt_IVehicle_SetArmoredWindowHealth(vehicle, window_id, health) }
+    }
+
+    fn vehicle_get_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+    ) -> u8 {
+        unsafe { alt_IVehicle_GetArmoredWindowShootCount(vehicle, window_id) }
+    }
+
+    fn vehicle_set_armored_window_shoot_count(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        window_id: u8,
+        count: u8,
+    ) {
+        unsafe { alt_IVehicle_SetArmoredWindowShootCount(vehicle, window_id, count) }
+    }
+
+    fn vehicle_get_bumper_damage_level(&self, vehicle: *mut alt_IVehicle, bumper_id: u8) -> u8 {
+        unsafe { alt_IVehicle_GetBumperDamageLevel(vehicle, bumper_id) }
+    }
+
+    fn vehicle_set_bumper_damage_level(
+        &self,
+        vehicle: *mut alt_IVehicle,
+        bumper_id: u8,
+        level: u8,
+    ) {
+        unsafe { alt_IVehicle_SetBumperDamageLevel(vehicle, bumper_id, level) }
+    }
+
+    fn vehicle_get_damage_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let b = alt_IVehicle_GetDamageDataBase64_CAPI_Heap(vehicle);
+            crate::string::String::from(*b).get_data()
+        }
+    }
+
+    fn vehicle_load_damage_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        unsafe { alt_IVehicle_LoadDamageDataFromBase64(vehicle, string_view(base64)) }
+    }
+
+    fn vehicle_set_manual_engine_control(&self, vehicle: *mut alt_IVehicle, state: bool) {
+        unsafe { alt_IVehicle_SetManualEngineControl(vehicle, state) }
+    }
+
+    fn vehicle_is_manual_engine_control(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsManualEngineControl(vehicle) }
+    }
+
+    fn vehicle_get_script_data_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let b = alt_IVehicle_GetScriptDataBase64_CAPI_Heap(vehicle);
+            crate::string::String::from(*b).get_data()
+        }
+    }
+
+    fn vehicle_load_script_data_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        unsafe { alt_IVehicle_LoadScriptDataFromBase64(vehicle, string_view(base64)) }
+    }
+
+    fn vehicle_get_game_state_base64(&self, vehicle: *mut alt_IVehicle) -> String {
+        unsafe {
+            let b = alt_IVehicle_GetGameStateBase64_CAPI_Heap(vehicle);
+            crate::string::String::from(*b).get_data()
+        }
+    }
+
+    fn vehicle_load_game_state_from_base64(&self, vehicle: *mut alt_IVehicle, base64: &str) {
+        unsafe { alt_IVehicle_LoadGameStateFromBase64(vehicle, string_view(base64)) }
+    }
+
+    fn vehicle_is_destroyed(&self, vehicle: *mut alt_IVehicle) -> bool {
+        unsafe { alt_IVehicle_IsDestroyed(vehicle) }
+    }
+
+    fn col_shape_get_colshape_type(
+        &self,
+        col_shape: *mut alt_IColShape,
+    ) -> alt_IColShape_ColShapeType {
+        unsafe { alt_IColShape_GetColshapeType(col_shape) }
+    }
+
+    fn col_shape_is_entity_in(
+        &self,
+        col_shape: *mut alt_IColShape,
+        entity: *mut alt_IEntity,
+    ) -> bool {
+        unsafe {
+            alt_IColShape_IsEntityIn(
+                col_shape,
+                alt_RefBase_RefStore_IEntity_Create_4_CAPI_Heap(entity),
+            )
+        }
+    }
+
+    fn col_shape_is_point_in(&self, col_shape: *mut alt_IColShape, position: Vector3) -> bool {
+        unsafe { alt_IColShape_IsPointIn(col_shape, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn col_shape_get_dimension(&self, col_shape: *mut alt_IColShape) -> i32 {
+        unsafe { alt_IColShape_GetDimension(col_shape) }
+    }
+
+    fn col_shape_set_dimension(&self, col_shape: *mut alt_IColShape, dimension: i32) {
+        unsafe { alt_IColShape_SetDimension(col_shape, dimension) }
+    }
+
+    fn col_shape_get_position(&self, col_shape: *mut alt_IColShape) -> Vector3 {
+        unsafe { Vector3::from(*alt_IColShape_GetPosition_CAPI_Heap(col_shape)) }
+    }
+
+    fn col_shape_set_position(&self, col_shape: *mut alt_IColShape, position: Vector3) {
+        unsafe { alt_IColShape_SetPosition(col_shape, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn checkpoint_get_checkpoint_type(&self, checkpoint: *mut alt_ICheckpoint) -> u8 {
+        unsafe { alt_ICheckpoint_GetCheckpointType(checkpoint) }
+    }
+
+    fn checkpoint_get_height(&self, checkpoint: *mut alt_ICheckpoint) -> f32 {
+        unsafe { alt_ICheckpoint_GetHeight(checkpoint) }
+    }
+
+    fn checkpoint_get_radius(&self, checkpoint: *mut alt_ICheckpoint) -> f32 {
+        unsafe { alt_ICheckpoint_GetRadius(checkpoint) }
+    }
+
+    fn checkpoint_get_color(&self, checkpoint: *mut alt_ICheckpoint) -> Rgba {
+        unsafe { Rgba::from(*alt_ICheckpoint_GetColor_CAPI_Heap(checkpoint)) }
+    }
+
+    fn checkpoint_get_colshape_type(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+    ) -> alt_IColShape_ColShapeType {
+        unsafe { alt_ICheckpoint_GetColshapeType(checkpoint) }
+    }
+
+    fn checkpoint_is_entity_in(
+        &self,
+        checkpoint: *mut alt_ICheckpoint,
+        entity: *mut alt_IEntity,
+    ) -> bool {
+        unsafe {
+            alt_ICheckpoint_IsEntityIn(
+                checkpoint,
+                alt_RefBase_RefStore_IEntity_Create_4_CAPI_Heap(entity),
+            )
+        }
+    }
+
+    fn checkpoint_is_point_in(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3) -> bool {
+        unsafe { alt_ICheckpoint_IsPointIn(checkpoint, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn checkpoint_get_dimension(&self, checkpoint: *mut alt_ICheckpoint) -> i32 {
+        unsafe { alt_ICheckpoint_GetDimension(checkpoint) }
+    }
+
+    fn checkpoint_set_dimension(&self, checkpoint: *mut alt_ICheckpoint, dimension: i32) {
+        unsafe { alt_ICheckpoint_SetDimension(checkpoint, dimension) }
+    }
+
+    fn checkpoint_get_position(&self, checkpoint: *mut alt_ICheckpoint) -> Vector3 {
+        unsafe { Vector3::from(*alt_ICheckpoint_GetPosition_CAPI_Heap(checkpoint)) }
+    }
+
+    fn checkpoint_set_position(&self, checkpoint: *mut alt_ICheckpoint, position: Vector3) {
+        unsafe { alt_ICheckpoint_SetPosition(checkpoint, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn blip_is_global(&self, blip: *mut alt_IBlip) -> bool {
+        unsafe { alt_IBlip_IsGlobal(blip) }
+    }
+
+    fn blip_get_target(&self, blip: *mut alt_IBlip) -> *mut alt_IPlayer {
+        unsafe { (*alt_IBlip_GetTarget_CAPI_Heap(blip)).ptr }
+    }
+
+    fn blip_is_attached(&self, blip: *mut alt_IBlip) -> bool {
+        unsafe { alt_IBlip_IsAttached(blip) }
+    }
+
+    fn blip_attached_to(&self, blip: *mut alt_IBlip) -> *mut alt_IEntity {
+        unsafe { (*alt_IBlip_AttachedTo_CAPI_Heap(blip)).ptr }
+    }
+
+    fn blip_get_blip_type(&self, blip: *mut alt_IBlip) -> alt_IBlip_BlipType {
+        unsafe { alt_IBlip_GetBlipType(blip) }
+    }
+
+    fn blip_set_sprite(&self, blip: *mut alt_IBlip, sprite: u16) {
+        unsafe { alt_IBlip_SetSprite(blip, sprite) }
+    }
+
+    fn blip_set_color(&self, blip: *mut alt_IBlip, color: u8) {
+        unsafe { alt_IBlip_SetColor(blip, color) }
+    }
+
+    fn blip_set_route(&self, blip: *mut alt_IBlip, state: bool) {
+        unsafe { alt_IBlip_SetRoute(blip, state) }
+    }
+
+    fn blip_set_route_color(&self, blip: *mut alt_IBlip, color: u8) {
+        unsafe { alt_IBlip_SetRouteColor(blip, color) }
+    }
+
+    fn blip_get_dimension(&self, blip: *mut alt_IBlip) -> i32 {
+        unsafe { alt_IBlip_GetDimension(blip) }
+    }
+
+    fn blip_set_dimension(&self, blip: *mut alt_IBlip, dimension: i32) {
+        unsafe { alt_IBlip_SetDimension(blip, dimension) }
+    }
+
+    fn blip_get_position(&self, blip: *mut alt_IBlip) -> Vector3 {
+        unsafe { Vector3::from(*alt_IBlip_GetPosition_CAPI_Heap(blip)) }
+    }
+
+    fn blip_set_position(&self, blip: *mut alt_IBlip, position: Vector3) {
+        unsafe { alt_IBlip_SetPosition(blip, Box::into_raw(Box::new(position.into()))) }
+    }
+
+    fn voice_channel_is_spatial(&self, voice_channel: *mut alt_IVoiceChannel) -> bool {
+        unsafe { alt_IVoiceChannel_IsSpatial(voice_channel) }
+    }
+
+    fn voice_channel_get_max_distance(&self, voice_channel: *mut alt_IVoiceChannel) -> f32 {
+        unsafe { alt_IVoiceChannel_GetMaxDistance(voice_channel) }
+    }
+
+    fn voice_channel_has_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool {
+        unsafe {
+            alt_IVoiceChannel_HasPlayer(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+
+    fn voice_channel_add_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        unsafe {
+            alt_IVoiceChannel_AddPlayer(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+
+    fn voice_channel_remove_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        unsafe {
+            alt_IVoiceChannel_RemovePlayer(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+
+    fn voice_channel_is_player_muted(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) -> bool {
+        unsafe {
+            alt_IVoiceChannel_IsPlayerMuted(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+
+    fn voice_channel_mute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        unsafe {
+            alt_IVoiceChannel_MutePlayer(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+
+    fn voice_channel_unmute_player(
+        &self,
+        voice_channel: *mut alt_IVoiceChannel,
+        player: *mut alt_IPlayer,
+    ) {
+        unsafe {
+            alt_IVoiceChannel_UnmutePlayer(
+                voice_channel,
+                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player),
+            )
+        }
+    }
+}
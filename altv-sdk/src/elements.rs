@@ -1,11 +1,14 @@
+use crate::backend;
+use crate::error::{Error, Result};
+use crate::handle::Handle;
 use crate::mvalue::MValue;
 use crate::natives::*;
 use crate::rgba::Rgba;
-use crate::string_view::StringView;
 use crate::vector::{Rotation3, Vector3};
-use altv_core::ecs::{Component, Entity, ReadStorage, VecStorage, World, WorldExt};
+use altv_core::ecs::{Builder, Component, Entity, ReadStorage, VecStorage, World, WorldExt};
 use altv_core::AltResource;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::time::Duration;
 
 pub fn create_vehicle(
@@ -13,57 +16,32 @@ pub fn create_vehicle(
     model: u32,
     position: Vector3,
     rotation: Rotation3,
-) -> Option<Entity> {
-    let rotation: alt_RotationLayout = rotation.into();
-
-    unsafe {
-        let core = alt_ICore_Instance();
-        let veh = alt_ICore_CreateVehicle_CAPI_Heap(
-            core,
-            model,
-            Box::into_raw(Box::new(position.into())),
-            Box::into_raw(Box::new(rotation)) as *mut alt_Vector_float_3_RotationLayout,
-        );
-
-        if (*veh).ptr.is_null() {
-            return None;
-        }
-
-        let alt = world.read_resource::<AltResource>();
-        Some(*alt.vehicles.get(&((*veh).ptr as usize)).unwrap())
-    }
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let vehicle = backend.create_vehicle(model, position, rotation);
+    insert_created(world, vehicle, |vehicle| {
+        backend.vehicle_to_base_object(vehicle)
+    })
 }
 
-pub fn create_collision_shape_sphere(world: &World, position: Vector3, radius: f32) -> Entity {
-    unsafe {
-        let core = alt_ICore_Instance();
-        let cs = alt_ICore_CreateColShapeSphere_CAPI_Heap(
-            core,
-            Box::into_raw(Box::new(position.into())),
-            radius,
-        );
-
-        let alt = world.read_resource::<AltResource>();
-        *alt.collision_shapes.get(&((*cs).ptr as usize)).unwrap()
-    }
+pub fn create_collision_shape_sphere(
+    world: &World,
+    position: Vector3,
+    radius: f32,
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let cs = backend.create_col_shape_sphere(position, radius);
+    insert_created(world, cs, |cs| backend.col_shape_to_base_object(cs))
 }
 
 pub fn create_collision_shape_cube(
     world: &World,
     start_position: Vector3,
     end_position: Vector3,
-) -> Entity {
-    unsafe {
-        let core = alt_ICore_Instance();
-        let cs = alt_ICore_CreateColShapeCube_CAPI_Heap(
-            core,
-            Box::into_raw(Box::new(start_position.into())),
-            Box::into_raw(Box::new(end_position.into())),
-        );
-
-        let alt = world.read_resource::<AltResource>();
-        *alt.collision_shapes.get(&((*cs).ptr as usize)).unwrap()
-    }
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let cs = backend.create_col_shape_cube(start_position, end_position);
+    insert_created(world, cs, |cs| backend.col_shape_to_base_object(cs))
 }
 
 pub fn create_collision_shape_rectangle(
@@ -73,28 +51,20 @@ pub fn create_collision_shape_rectangle(
     x2: f32,
     y2: f32,
     z: f32,
-) -> Entity {
-    unsafe {
-        let core = alt_ICore_Instance();
-        let cs = alt_ICore_CreateColShapeRectangle_CAPI_Heap(core, x1, y1, x2, y2, z);
-
-        let alt = world.read_resource::<AltResource>();
-        *alt.collision_shapes.get(&((*cs).ptr as usize)).unwrap()
-    }
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let cs = backend.create_col_shape_rectangle(x1, y1, x2, y2, z);
+    insert_created(world, cs, |cs| backend.col_shape_to_base_object(cs))
 }
 
-pub fn create_collision_shape_circle(world: &World, position: Vector3, radius: f32) -> Entity {
-    unsafe {
-        let core = alt_ICore_Instance();
-        let cs = alt_ICore_CreateColShapeCircle_CAPI_Heap(
-            core,
-            Box::into_raw(Box::new(position.into())),
-            radius,
-        );
-
-        let alt = world.read_resource::<AltResource>();
-        *alt.collision_shapes.get(&((*cs).ptr as usize)).unwrap()
-    }
+pub fn create_collision_shape_circle(
+    world: &World,
+    position: Vector3,
+    radius: f32,
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let cs = backend.create_col_shape_circle(position, radius);
+    insert_created(world, cs, |cs| backend.col_shape_to_base_object(cs))
 }
 
 pub fn create_collision_shape_cylinder(
@@ -102,103 +72,320 @@ pub fn create_collision_shape_cylinder(
     position: Vector3,
     radius: f32,
     height: f32,
-) -> Entity {
-    unsafe {
-        let core = alt_ICore_Instance();
-        let cs = alt_ICore_CreateColShapeCylinder_CAPI_Heap(
-            core,
-            Box::into_raw(Box::new(position.into())),
-            radius,
-            height,
-        );
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let cs = backend.create_col_shape_cylinder(position, radius, height);
+    insert_created(world, cs, |cs| backend.col_shape_to_base_object(cs))
+}
 
+pub fn create_checkpoint(
+    world: &World,
+    checkpoint_type: CheckpointType,
+    position: Vector3,
+    radius: f32,
+    height: f32,
+    color: Rgba,
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let checkpoint =
+        backend.create_checkpoint(checkpoint_type as u8, position, radius, height, color);
+    insert_created(world, checkpoint, |checkpoint| {
+        backend.checkpoint_to_base_object(checkpoint)
+    })
+}
+
+/// Creates a blip at a position, it is only shown to `target` if one is given.
+pub fn create_blip(
+    world: &World,
+    target: Option<Entity>,
+    blip_type: BlipType,
+    position: Vector3,
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let target = {
         let alt = world.read_resource::<AltResource>();
-        *alt.collision_shapes.get(&((*cs).ptr as usize)).unwrap()
+        target.map(|target| get_player(&alt, target)).transpose()?
+    };
+    let blip = backend.create_blip(target, blip_type.into(), position);
+    insert_created(world, blip, |blip| backend.blip_to_base_object(blip))
+}
+
+/// Creates a blip following an entity, it is only shown to `target` if one is given.
+pub fn create_blip_attached(
+    world: &World,
+    target: Option<Entity>,
+    blip_type: BlipType,
+    attach_to: Entity,
+) -> Result<Entity> {
+    let backend = backend::instance();
+    let (target, attach_to) = {
+        let alt = world.read_resource::<AltResource>();
+        let target = target.map(|target| get_player(&alt, target)).transpose()?;
+        let attach_to = cast(&alt, attach_to, "player or vehicle", |base_obj| {
+            backend.base_object_to_entity(base_obj)
+        })?;
+        (target, attach_to)
+    };
+    let blip = backend.create_blip_attached(target, blip_type.into(), attach_to);
+    insert_created(world, blip, |blip| backend.blip_to_base_object(blip))
+}
+
+pub fn create_voice_channel(world: &World, spatial: bool, max_distance: f32) -> Result<Entity> {
+    let backend = backend::instance();
+    let voice_channel = backend.create_voice_channel(spatial, max_distance);
+    insert_created(world, voice_channel, |voice_channel| {
+        backend.voice_channel_to_base_object(voice_channel)
+    })
+}
+
+fn insert_created<T>(
+    world: &World,
+    ptr: *mut T,
+    to_base_object: impl FnOnce(*mut T) -> *mut alt_IBaseObject,
+) -> Result<Entity> {
+    if ptr.is_null() {
+        return Err(Error::CreationFailed);
     }
+
+    insert_base_object(world, to_base_object(ptr))
+}
+
+fn get_player(alt: &AltResource, player: Entity) -> Result<*mut alt_IPlayer> {
+    cast(alt, player, "player", |base_obj| {
+        backend::instance().base_object_to_player(base_obj)
+    })
 }
 
-// pub fn create_checkpoint(
-//     world: &World,
-//     cp_type: u8,
-//     position: Vector3,
-//     radius: f32,
-//     height: f32,
-//     color: Rgba,
-// ) -> Entity {
-//     let position: alt_Vector_float_3_PointLayout = position.into();
-//     let color: alt_RGBA = color.into();
-//
-//     unsafe {
-//         let core = alt_ICore_Instance();
-//         let cp = alt_ICore_CreateCheckpoint_CAPI_Heap(
-//             core,
-//             cp_type,
-//             Box::into_raw(Box::new(position)),
-//             radius,
-//             height,
-//             Box::into_raw(Box::new(color)),
-//         );
-//
-//         let alt = world.read_resource::<AltResource>();
-//         *alt.checkpoints
-//             .get(&((*cp).ptr as usize))
-//             .unwrap()
-//     }
-// }
-
-// pub fn create_blip(world: &World, blip_type: u8, position: Vector3) -> Entity {
-//     unsafe {
-//         let core = alt_ICore_Instance();
-//         let blip = alt_ICore_CreateBlip_CAPI_Heap(
-//             core,
-//             alt_RefBase_RefStore_IPlayer_Create_3_CAPI_Heap(),
-//             alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_OBJECT,
-//             Box::into_raw(Box::new(position.into())),
-//         );
-//
-//         let alt = world.read_resource::<AltResource>();
-//         *alt.blips.get(&((*blip).ptr as usize)).unwrap()
-//     }
-// }
-
-// pub fn create_voice_channel(world: &World, spacial: bool, max_distance: f32) -> Entity {
-//     unsafe {
-//         let core = alt_ICore_Instance();
-//         let voice_channel = alt_ICore_CreateVoiceChannel_CAPI_Heap(core, spacial, max_distance);
-//
-//         let alt = world.read_resource::<AltResource>();
-//         *alt.voice_channels.get(&((*voice_channel).ptr as usize)).unwrap()
-//     }
-// }
-
-pub fn delete(world: &mut World, entity: Entity) {
-    let ptr = world.exec(|cbase_objs: ReadStorage<CBaseObject>| {
-        let cbase_obj = cbase_objs.get(entity).unwrap();
-        cbase_obj.0.load(Ordering::Relaxed)
+/// Casts the base object of an entity, `expected` names the kind of object the call needs.
+fn cast<T>(
+    alt: &AltResource,
+    entity: Entity,
+    expected: &'static str,
+    f: impl FnOnce(*mut alt_IBaseObject) -> *mut T,
+) -> Result<*mut T> {
+    let base_obj = get_entity_base_object(alt, entity)?;
+    let ptr = f(base_obj);
+
+    if ptr.is_null() {
+        return Err(Error::WrongType { entity, expected });
+    }
+
+    Ok(ptr)
+}
+
+/// Destroys the alt:V object of an entity, players are only removed once they disconnect.
+pub fn delete(world: &mut World, entity: Entity) -> Result<()> {
+    // Removing the entity drops its handles, this one keeps the object referenced until it was
+    // destroyed.
+    let handle = world.exec(|cbase_objs: ReadStorage<CBaseObject>| {
+        cbase_objs.get(entity).map(|cbase_obj| cbase_obj.0.clone())
     });
+    let handle = handle.ok_or(Error::EntityNotFound(entity))?;
+    let ptr = handle.ptr();
+
+    let backend = backend::instance();
+
+    if backend.base_object_get_type(ptr) != alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER {
+        remove_base_object(world, ptr)?;
+    }
+
+    backend.destroy_base_object(ptr);
+    drop(handle);
+    Ok(())
+}
+
+/// Creates the entity and components for a base object, or returns the entity it is already mapped to.
+pub fn insert_base_object(world: &World, base_obj: *mut alt_IBaseObject) -> Result<Entity> {
+    let backend = backend::instance();
+    let object_type = backend.base_object_get_type(base_obj);
+    let key = get_key(base_obj, object_type)?;
+
+    if let Some(entity) = get_objects(&world.read_resource::<AltResource>(), object_type)?.get(&key)
+    {
+        return Ok(*entity);
+    }
+
+    let cref = Handle::new(backend.base_object_to_ref_countable(base_obj));
+    let builder = world
+        .create_entity_unchecked()
+        .with(CBaseObject(cref.cast(base_obj)));
+
+    let builder = match object_type {
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => builder
+            .with(CWorldObject(
+                cref.cast(backend.base_object_to_world_object(base_obj)),
+            ))
+            .with(CEntity(cref.cast(backend.base_object_to_entity(base_obj))))
+            .with(CPlayer(cref.cast(backend.base_object_to_player(base_obj)))),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => builder
+            .with(CWorldObject(
+                cref.cast(backend.base_object_to_world_object(base_obj)),
+            ))
+            .with(CEntity(cref.cast(backend.base_object_to_entity(base_obj))))
+            .with(CVehicle(
+                cref.cast(backend.base_object_to_vehicle(base_obj)),
+            )),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => builder
+            .with(CWorldObject(
+                cref.cast(backend.base_object_to_world_object(base_obj)),
+            ))
+            .with(CBlip(cref.cast(backend.base_object_to_blip(base_obj)))),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => builder.with(CVoiceChannel(
+            cref.cast(backend.base_object_to_voice_channel(base_obj)),
+        )),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => builder
+            .with(CWorldObject(
+                cref.cast(backend.base_object_to_world_object(base_obj)),
+            ))
+            .with(CCollisionShape(
+                cref.cast(backend.base_object_to_col_shape(base_obj)),
+            )),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => builder
+            .with(CWorldObject(
+                cref.cast(backend.base_object_to_world_object(base_obj)),
+            ))
+            .with(CCollisionShape(
+                cref.cast(backend.base_object_to_col_shape(base_obj)),
+            ))
+            .with(CCheckpoint(
+                cref.cast(backend.base_object_to_checkpoint(base_obj)),
+            )),
+        _ => builder,
+    };
+    let builder = builder.with(CRefCountable(cref));
+
+    let entity = builder.build();
+
+    let mut alt = world.write_resource::<AltResource>();
+    get_objects_mut(&mut alt, object_type)?.insert(key, entity);
+    alt.base_objects.insert(entity, base_obj as usize);
+
+    Ok(entity)
+}
+
+pub fn remove_base_object(world: &mut World, base_obj: *mut alt_IBaseObject) -> Result<Entity> {
+    let object_type = backend::instance().base_object_get_type(base_obj);
+    let key = get_key(base_obj, object_type)?;
 
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_DestroyBaseObject(
-            core,
-            alt_RefBase_RefStore_IBaseObject_Create_2_CAPI_Heap(ptr),
-        );
+    let entity = {
+        let mut alt = world.write_resource::<AltResource>();
+        let entity = get_objects_mut(&mut alt, object_type)?
+            .remove(&key)
+            .ok_or(Error::ObjectNotFound)?;
+        alt.base_objects.remove(&entity);
+        entity
+    };
+
+    // Handles cloned out of the components keep the object alive, but no longer see it as valid.
+    if let Some(cbase_obj) = world.read_storage::<CBaseObject>().get(entity) {
+        cbase_obj.0.invalidate();
     }
+
+    world
+        .delete_entity(entity)
+        .map_err(|_| Error::EntityNotFound(entity))?;
+    Ok(entity)
+}
+
+pub fn get_base_object_entity(alt: &AltResource, base_obj: *mut alt_IBaseObject) -> Result<Entity> {
+    let object_type = backend::instance().base_object_get_type(base_obj);
+    let key = get_key(base_obj, object_type)?;
+
+    get_objects(alt, object_type)?
+        .get(&key)
+        .copied()
+        .ok_or(Error::ObjectNotFound)
 }
 
-pub struct CRefCountable(pub AtomicPtr<alt_CRefCountable>);
+pub fn get_entity_base_object(alt: &AltResource, entity: Entity) -> Result<*mut alt_IBaseObject> {
+    alt.base_objects
+        .get(&entity)
+        .map(|base_obj| *base_obj as *mut alt_IBaseObject)
+        .ok_or(Error::EntityNotFound(entity))
+}
+
+pub fn get_entity(alt: &AltResource, entity: *mut alt_IEntity) -> Result<Entity> {
+    get_base_object_entity(alt, backend::instance().entity_to_base_object(entity))
+}
+
+fn get_key(base_obj: *mut alt_IBaseObject, object_type: alt_IBaseObject_Type) -> Result<usize> {
+    let backend = backend::instance();
+
+    let key = match object_type {
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
+            backend.base_object_to_player(base_obj) as usize
+        }
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
+            backend.base_object_to_vehicle(base_obj) as usize
+        }
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => {
+            backend.base_object_to_blip(base_obj) as usize
+        }
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => {
+            backend.base_object_to_voice_channel(base_obj) as usize
+        }
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => {
+            backend.base_object_to_col_shape(base_obj) as usize
+        }
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => {
+            backend.base_object_to_checkpoint(base_obj) as usize
+        }
+        _ => return Err(Error::UnsupportedType(object_type)),
+    };
+
+    Ok(key)
+}
+
+fn get_objects(
+    alt: &AltResource,
+    object_type: alt_IBaseObject_Type,
+) -> Result<&HashMap<usize, Entity>> {
+    match object_type {
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => Ok(&alt.players),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => Ok(&alt.vehicles),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => Ok(&alt.blips),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => Ok(&alt.voice_channels),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => Ok(&alt.collision_shapes),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => Ok(&alt.checkpoints),
+        _ => Err(Error::UnsupportedType(object_type)),
+    }
+}
+
+fn get_objects_mut(
+    alt: &mut AltResource,
+    object_type: alt_IBaseObject_Type,
+) -> Result<&mut HashMap<usize, Entity>> {
+    match object_type {
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => Ok(&mut alt.players),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => Ok(&mut alt.vehicles),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => Ok(&mut alt.blips),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => Ok(&mut alt.voice_channels),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => Ok(&mut alt.collision_shapes),
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => Ok(&mut alt.checkpoints),
+        _ => Err(Error::UnsupportedType(object_type)),
+    }
+}
+
+pub struct CRefCountable(Handle<alt_CRefCountable>);
 
 impl CRefCountable {
+    pub fn handle(&self) -> &Handle<alt_CRefCountable> {
+        &self.0
+    }
+
     pub fn get_ref_count(&self) -> u64 {
-        unsafe { alt_CRefCountable_GetRefCount(self.0.load(Ordering::Relaxed)) }
+        backend::instance().ref_countable_get_ref_count(self.0.ptr())
     }
 
     pub fn add_ref(&mut self) {
-        unsafe { alt_CRefCountable_AddRef(self.0.load(Ordering::Relaxed)) }
+        backend::instance().ref_countable_add_ref(self.0.ptr())
     }
 
-    pub fn remove_ref(&mut self) {
-        unsafe { alt_CRefCountable_RemoveRef(self.0.load(Ordering::Relaxed)) }
+    /// # Safety
+    ///
+    /// Only refs taken with `add_ref` may be released, the components hold one of their own.
+    pub unsafe fn remove_ref(&mut self) {
+        backend::instance().ref_countable_remove_ref(self.0.ptr())
     }
 }
 
@@ -206,45 +393,27 @@ impl Component for CRefCountable {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CBaseObject(pub AtomicPtr<alt_IBaseObject>);
+pub struct CBaseObject(Handle<alt_IBaseObject>);
 
 impl CBaseObject {
+    pub fn handle(&self) -> &Handle<alt_IBaseObject> {
+        &self.0
+    }
+
     pub fn has_meta_data(&self, key: &str) -> bool {
-        unsafe {
-            alt_IBaseObject_HasMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().base_object_has_meta_data(self.0.ptr(), key)
     }
 
-    pub fn get_meta_data(&self, key: &str) -> MValue {
-        unsafe {
-            let val = alt_IBaseObject_GetMetaData_CAPI_Heap(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            );
-            MValue::new((*val).ptr).into()
-        }
+    pub fn get_meta_data(&self, alt: &AltResource, key: &str) -> Result<MValue> {
+        backend::instance().base_object_get_meta_data(alt, self.0.ptr(), key)
     }
 
-    pub fn set_meta_data(&mut self, key: &str, value: MValue) {
-        unsafe {
-            alt_IBaseObject_SetMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-                value.into(),
-            )
-        }
+    pub fn set_meta_data(&mut self, alt: &AltResource, key: &str, value: MValue) -> Result<()> {
+        backend::instance().base_object_set_meta_data(alt, self.0.ptr(), key, value)
     }
 
     pub fn delete_meta_data(&mut self, key: &str) {
-        unsafe {
-            alt_IBaseObject_DeleteMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().base_object_delete_meta_data(self.0.ptr(), key)
     }
 }
 
@@ -252,31 +421,27 @@ impl Component for CBaseObject {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CWorldObject(pub AtomicPtr<alt_IWorldObject>);
+pub struct CWorldObject(Handle<alt_IWorldObject>);
 
 impl CWorldObject {
+    pub fn handle(&self) -> &Handle<alt_IWorldObject> {
+        &self.0
+    }
+
     pub fn get_dimension(&self) -> i32 {
-        unsafe { alt_IWorldObject_GetDimension(self.0.load(Ordering::Relaxed)) }
+        backend::instance().world_object_get_dimension(self.0.ptr())
     }
 
     pub fn set_dimension(&mut self, dimension: i32) {
-        unsafe { alt_IWorldObject_SetDimension(self.0.load(Ordering::Relaxed), dimension) }
+        backend::instance().world_object_set_dimension(self.0.ptr(), dimension)
     }
 
     pub fn get_position(&self) -> Vector3 {
-        unsafe {
-            let pos = alt_IWorldObject_GetPosition_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Vector3::from(*pos)
-        }
+        backend::instance().world_object_get_position(self.0.ptr())
     }
 
     pub fn set_position(&mut self, pos: Vector3) {
-        unsafe {
-            alt_IWorldObject_SetPosition(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(pos.into())),
-            )
-        }
+        backend::instance().world_object_set_position(self.0.ptr(), pos)
     }
 }
 
@@ -284,121 +449,79 @@ impl Component for CWorldObject {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CEntity(pub AtomicPtr<alt_IEntity>);
+pub struct CEntity(Handle<alt_IEntity>);
 
 impl CEntity {
+    pub fn handle(&self) -> &Handle<alt_IEntity> {
+        &self.0
+    }
+
     pub fn get_id(&self) -> u16 {
-        unsafe { alt_IEntity_GetID(self.0.load(Ordering::Relaxed)) }
+        backend::instance().entity_get_id(self.0.ptr())
     }
 
     pub fn get_network_owner(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let player = alt_IEntity_GetNetworkOwner_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let player = (*player).ptr;
-
-            if player.is_null() {
-                return None;
-            }
+        let player = backend::instance().entity_get_network_owner(self.0.ptr());
 
-            Some(*alt.players.get(&(player as usize)).unwrap())
+        if player.is_null() {
+            return None;
         }
+
+        alt.players.get(&(player as usize)).copied()
     }
 
     pub fn get_rotation(&self) -> Rotation3 {
-        unsafe {
-            let rot = alt_IEntity_GetRotation_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rotation3::from(*(rot as *mut alt_RotationLayout))
-        }
+        backend::instance().entity_get_rotation(self.0.ptr())
     }
 
     pub fn set_rotation(&mut self, rot: Rotation3) {
-        unsafe {
-            let rot: alt_RotationLayout = rot.into();
-            alt_IEntity_SetRotation(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(rot)) as *mut alt_Vector_float_3_RotationLayout,
-            )
-        }
+        backend::instance().entity_set_rotation(self.0.ptr(), rot)
     }
 
     pub fn has_synced_meta_data(&self, key: &str) -> bool {
-        unsafe {
-            alt_IEntity_HasSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().entity_has_synced_meta_data(self.0.ptr(), key)
     }
 
-    pub fn get_synced_meta_data(&self, key: &str) -> MValue {
-        unsafe {
-            let val = alt_IEntity_GetSyncedMetaData_CAPI_Heap(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            );
-            MValue::new((*val).ptr).into()
-        }
+    pub fn get_synced_meta_data(&self, alt: &AltResource, key: &str) -> Result<MValue> {
+        backend::instance().entity_get_synced_meta_data(alt, self.0.ptr(), key)
     }
 
     pub fn has_stream_synced_meta_data(&self, key: &str) -> bool {
-        unsafe {
-            alt_IEntity_HasStreamSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().entity_has_stream_synced_meta_data(self.0.ptr(), key)
     }
 
-    pub fn get_stream_synced_meta_data(&self, key: &str) -> MValue {
-        unsafe {
-            let val = alt_IEntity_GetStreamSyncedMetaData_CAPI_Heap(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            );
-            MValue::new((*val).ptr).into()
-        }
+    pub fn get_stream_synced_meta_data(&self, alt: &AltResource, key: &str) -> Result<MValue> {
+        backend::instance().entity_get_stream_synced_meta_data(alt, self.0.ptr(), key)
     }
 
-    pub fn set_synced_meta_data(&mut self, key: &str, value: MValue) {
-        unsafe {
-            alt_IEntity_SetSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-                value.into(),
-            )
-        }
+    pub fn set_synced_meta_data(
+        &mut self,
+        alt: &AltResource,
+        key: &str,
+        value: MValue,
+    ) -> Result<()> {
+        backend::instance().entity_set_synced_meta_data(alt, self.0.ptr(), key, value)
     }
 
     pub fn delete_synced_meta_data(&mut self, key: &str) {
-        unsafe {
-            alt_IEntity_DeleteSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().entity_delete_synced_meta_data(self.0.ptr(), key)
     }
 
-    pub fn set_stream_synced_meta_data(&mut self, key: &str, value: MValue) {
-        unsafe {
-            alt_IEntity_SetStreamSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-                value.into(),
-            )
-        }
+    pub fn set_stream_synced_meta_data(
+        &mut self,
+        alt: &AltResource,
+        key: &str,
+        value: MValue,
+    ) -> Result<()> {
+        backend::instance().entity_set_stream_synced_meta_data(alt, self.0.ptr(), key, value)
     }
 
     pub fn delete_stream_synced_meta_data(&mut self, key: &str) {
-        unsafe {
-            alt_IEntity_DeleteStreamSyncedMetaData(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(key).into())),
-            )
-        }
+        backend::instance().entity_delete_stream_synced_meta_data(self.0.ptr(), key)
     }
 
     pub fn get_model(&self) -> u32 {
-        unsafe { alt_IEntity_GetModel(self.0.load(Ordering::Relaxed)) }
+        backend::instance().entity_get_model(self.0.ptr())
     }
 }
 
@@ -406,78 +529,67 @@ impl Component for CEntity {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CPlayer(pub AtomicPtr<alt_IPlayer>);
+pub struct CPlayer(Handle<alt_IPlayer>);
 
 impl CPlayer {
+    pub fn handle(&self) -> &Handle<alt_IPlayer> {
+        &self.0
+    }
+
     pub fn is_connected(&self) -> bool {
-        unsafe { alt_IPlayer_IsConnected(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_connected(self.0.ptr())
     }
 
     pub fn get_ping(&self) -> u32 {
-        unsafe { alt_IPlayer_GetPing(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_ping(self.0.ptr())
     }
 
     pub fn get_ip(&self) -> String {
-        unsafe {
-            let ip = alt_IPlayer_GetIP_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            StringView::from(*ip).get_data()
-        }
+        backend::instance().player_get_ip(self.0.ptr())
     }
 
     pub fn spawn(&mut self, pos: Vector3, delay: Duration) {
-        unsafe {
-            alt_IPlayer_Spawn(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(pos.into())),
-                delay.as_millis() as u32,
-            )
-        }
+        backend::instance().player_spawn(self.0.ptr(), pos, delay)
     }
 
     pub fn despawn(&mut self) {
-        unsafe { alt_IPlayer_Despawn(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_despawn(self.0.ptr())
     }
 
     pub fn get_name(&self) -> String {
-        unsafe {
-            let name = alt_IPlayer_GetName_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            StringView::from(*name).get_data()
-        }
+        backend::instance().player_get_name(self.0.ptr())
     }
 
     pub fn get_social_id(&self) -> u64 {
-        unsafe { alt_IPlayer_GetSocialID(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_social_id(self.0.ptr())
     }
 
     pub fn get_hwid_hash(&self) -> u64 {
-        unsafe { alt_IPlayer_GetHwidHash(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_hwid_hash(self.0.ptr())
     }
 
     pub fn get_hwid_ex_hash(&self) -> u64 {
-        unsafe { alt_IPlayer_GetHwidExHash(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_hwid_ex_hash(self.0.ptr())
     }
 
     pub fn get_auth_token(&self) -> String {
-        unsafe {
-            let at = alt_IPlayer_GetAuthToken_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            StringView::from(*at).get_data()
-        }
+        backend::instance().player_get_auth_token(self.0.ptr())
     }
 
     pub fn get_health(&self) -> u16 {
-        unsafe { alt_IPlayer_GetHealth(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_health(self.0.ptr())
     }
 
     pub fn set_health(&mut self, health: u16) {
-        unsafe { alt_IPlayer_SetHealth(self.0.load(Ordering::Relaxed), health) }
+        backend::instance().player_set_health(self.0.ptr(), health)
     }
 
     pub fn get_max_health(&self) -> u16 {
-        unsafe { alt_IPlayer_GetMaxHealth(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_max_health(self.0.ptr())
     }
 
     pub fn set_max_health(&mut self, max_health: u16) {
-        unsafe { alt_IPlayer_SetMaxHealth(self.0.load(Ordering::Relaxed), max_health) }
+        backend::instance().player_set_max_health(self.0.ptr(), max_health)
     }
 
     pub fn set_date_time(
@@ -489,43 +601,39 @@ impl CPlayer {
         minute: i32,
         second: i32,
     ) {
-        unsafe {
-            alt_IPlayer_SetDateTime(
-                self.0.load(Ordering::Relaxed),
-                day,
-                month,
-                year,
-                hour,
-                minute,
-                second,
-            )
-        }
+        backend::instance().player_set_date_time(
+            self.0.ptr(),
+            day,
+            month,
+            year,
+            hour,
+            minute,
+            second,
+        )
     }
 
     pub fn set_weather(&mut self, weather: u32) {
-        unsafe { alt_IPlayer_SetWeather(self.0.load(Ordering::Relaxed), weather) }
+        backend::instance().player_set_weather(self.0.ptr(), weather)
     }
 
     pub fn give_weapon(&mut self, weapon: u32, ammo: i32, select: bool) {
-        unsafe { alt_IPlayer_GiveWeapon(self.0.load(Ordering::Relaxed), weapon, ammo, select) }
+        backend::instance().player_give_weapon(self.0.ptr(), weapon, ammo, select)
     }
 
     pub fn remove_weapon(&mut self, weapon: u32) {
-        unsafe { alt_IPlayer_RemoveWeapon(self.0.load(Ordering::Relaxed), weapon) }
+        backend::instance().player_remove_weapon(self.0.ptr(), weapon)
     }
 
     pub fn remove_all_weapons(&mut self) {
-        unsafe { alt_IPlayer_RemoveAllWeapons(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_remove_all_weapons(self.0.ptr())
     }
 
     pub fn add_weapon_component(&mut self, weapon: u32, component: u32) {
-        unsafe { alt_IPlayer_AddWeaponComponent(self.0.load(Ordering::Relaxed), weapon, component) }
+        backend::instance().player_add_weapon_component(self.0.ptr(), weapon, component)
     }
 
     pub fn remove_weapon_component(&mut self, weapon: u32, component: u32) {
-        unsafe {
-            alt_IPlayer_RemoveWeaponComponent(self.0.load(Ordering::Relaxed), weapon, component)
-        }
+        backend::instance().player_remove_weapon_component(self.0.ptr(), weapon, component)
     }
 
     // pub fn get_current_weapon_components(&self) -> Vec<u32> {
@@ -533,167 +641,127 @@ impl CPlayer {
     // }
 
     pub fn set_weapon_tint_index(&mut self, weapon: u32, tint_index: u8) {
-        unsafe {
-            alt_IPlayer_SetWeaponTintIndex(self.0.load(Ordering::Relaxed), weapon, tint_index)
-        }
+        backend::instance().player_set_weapon_tint_index(self.0.ptr(), weapon, tint_index)
     }
 
     pub fn get_current_weapon_tint_index(&self) -> u8 {
-        unsafe { alt_IPlayer_GetCurrentWeaponTintIndex(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_current_weapon_tint_index(self.0.ptr())
     }
 
     pub fn get_current_weapon(&self) -> u32 {
-        unsafe { alt_IPlayer_GetCurrentWeapon(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_current_weapon(self.0.ptr())
     }
 
     pub fn set_current_weapon(&mut self, weapon: u32) {
-        unsafe { alt_IPlayer_SetCurrentWeapon(self.0.load(Ordering::Relaxed), weapon) }
+        backend::instance().player_set_current_weapon(self.0.ptr(), weapon)
     }
 
     pub fn is_dead(&self) -> bool {
-        unsafe { alt_IPlayer_IsDead(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_dead(self.0.ptr())
     }
 
     pub fn is_jumping(&self) -> bool {
-        unsafe { alt_IPlayer_IsJumping(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_jumping(self.0.ptr())
     }
 
     pub fn is_in_ragdoll(&self) -> bool {
-        unsafe { alt_IPlayer_IsInRagdoll(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_in_ragdoll(self.0.ptr())
     }
 
     pub fn is_aiming(&self) -> bool {
-        unsafe { alt_IPlayer_IsAiming(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_aiming(self.0.ptr())
     }
 
     pub fn is_shooting(&self) -> bool {
-        unsafe { alt_IPlayer_IsShooting(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_shooting(self.0.ptr())
     }
 
     pub fn is_reloading(&self) -> bool {
-        unsafe { alt_IPlayer_IsReloading(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_reloading(self.0.ptr())
     }
 
     pub fn get_armor(&self) -> u16 {
-        unsafe { alt_IPlayer_GetArmour(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_armor(self.0.ptr())
     }
 
     pub fn set_armor(&mut self, armor: u16) {
-        unsafe { alt_IPlayer_SetArmour(self.0.load(Ordering::Relaxed), armor) }
+        backend::instance().player_set_armor(self.0.ptr(), armor)
     }
 
     pub fn get_max_armor(&self) -> u16 {
-        unsafe { alt_IPlayer_GetMaxArmour(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_max_armor(self.0.ptr())
     }
 
     pub fn set_max_armor(&mut self, max_armor: u16) {
-        unsafe { alt_IPlayer_SetMaxArmour(self.0.load(Ordering::Relaxed), max_armor) }
+        backend::instance().player_set_max_armor(self.0.ptr(), max_armor)
     }
 
     pub fn get_move_speed(&self) -> f32 {
-        unsafe { alt_IPlayer_GetMoveSpeed(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_move_speed(self.0.ptr())
     }
 
     pub fn get_weapon(&self) -> u32 {
-        unsafe { alt_IPlayer_GetWeapon(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_weapon(self.0.ptr())
     }
 
     pub fn get_ammo(&self) -> u16 {
-        unsafe { alt_IPlayer_GetAmmo(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_ammo(self.0.ptr())
     }
 
     pub fn get_aim_position(&self) -> Vector3 {
-        unsafe {
-            let pos = alt_IPlayer_GetAimPos_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Vector3::from(*pos)
-        }
+        backend::instance().player_get_aim_position(self.0.ptr())
     }
 
     pub fn get_head_rotation(&self) -> Rotation3 {
-        unsafe {
-            let rot = alt_IPlayer_GetHeadRotation_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rotation3::from(*(rot as *mut alt_RotationLayout))
-        }
+        backend::instance().player_get_head_rotation(self.0.ptr())
     }
 
     pub fn is_in_vehicle(&self) -> bool {
-        unsafe { alt_IPlayer_IsInVehicle(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_in_vehicle(self.0.ptr())
     }
 
     pub fn get_vehicle(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let vehicle = alt_IPlayer_GetVehicle_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let vehicle = (*vehicle).ptr;
+        let vehicle = backend::instance().player_get_vehicle(self.0.ptr());
 
-            if vehicle.is_null() {
-                return None;
-            }
-
-            Some(*alt.vehicles.get(&(vehicle as usize)).unwrap())
+        if vehicle.is_null() {
+            return None;
         }
+
+        alt.vehicles.get(&(vehicle as usize)).copied()
     }
 
     pub fn get_seat(&self) -> u8 {
-        unsafe { alt_IPlayer_GetSeat(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_get_seat(self.0.ptr())
     }
 
     pub fn get_entity_aiming_at(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let entity = alt_IPlayer_GetEntityAimingAt_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let entity = (*entity).ptr;
-
-            if entity.is_null() {
-                return None;
-            }
+        let entity = backend::instance().player_get_entity_aiming_at(self.0.ptr());
 
-            match alt_IEntity_GetType(entity) {
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
-                    let player = alt_IEntity_to_alt_IPlayer(entity);
-                    Some(*alt.players.get(&(player as usize)).unwrap())
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
-                    let vehicle = alt_IEntity_to_alt_IVehicle(entity);
-                    Some(*alt.vehicles.get(&(vehicle as usize)).unwrap())
-                }
-                _ => panic!(),
-            }
+        if entity.is_null() {
+            return None;
         }
+
+        get_entity(alt, entity).ok()
     }
 
     pub fn get_entity_aim_offset(&self) -> Vector3 {
-        unsafe {
-            let offset = alt_IPlayer_GetEntityAimOffset_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Vector3::from(*offset)
-        }
+        backend::instance().player_get_entity_aim_offset(self.0.ptr())
     }
 
     pub fn is_flashlight_active(&self) -> bool {
-        unsafe { alt_IPlayer_IsFlashlightActive(self.0.load(Ordering::Relaxed)) }
+        backend::instance().player_is_flashlight_active(self.0.ptr())
     }
 
     pub fn kick(&mut self, reason: &str) {
-        unsafe {
-            alt_IPlayer_Kick(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(reason).into())),
-            )
-        }
+        backend::instance().player_kick(self.0.ptr(), reason)
     }
 
     pub fn set_model(&mut self, model: u32) {
-        unsafe { alt_IPlayer_SetModel(self.0.load(Ordering::Relaxed), model) }
-    }
-
-    pub fn emit(&mut self, event_name: &str, args: &[MValue]) {
-        unsafe {
-            let core = alt_ICore_Instance();
-            alt_ICore_TriggerClientEvent(
-                core,
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(self.0.load(Ordering::Relaxed)),
-                Box::into_raw(Box::new(StringView::new(event_name).into())),
-                crate::array::convert_iter_to_array_mvalue(args.iter()),
-            )
-        }
+        backend::instance().player_set_model(self.0.ptr(), model)
+    }
+
+    pub fn emit(&mut self, alt: &AltResource, event_name: &str, args: &[MValue]) -> Result<()> {
+        backend::instance().trigger_client_event(alt, Some(self.0.ptr()), event_name, args)
     }
 }
 
@@ -701,631 +769,538 @@ impl Component for CPlayer {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CVehicle(pub AtomicPtr<alt_IVehicle>);
+pub struct CVehicle(Handle<alt_IVehicle>);
 
 impl CVehicle {
-    pub fn get_driver(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let player = alt_IVehicle_GetDriver_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let player = (*player).ptr;
+    pub fn handle(&self) -> &Handle<alt_IVehicle> {
+        &self.0
+    }
 
-            if player.is_null() {
-                return None;
-            }
+    pub fn get_driver(&self, alt: &AltResource) -> Option<Entity> {
+        let player = backend::instance().vehicle_get_driver(self.0.ptr());
 
-            Some(*alt.players.get(&(player as usize)).unwrap())
+        if player.is_null() {
+            return None;
         }
+
+        alt.players.get(&(player as usize)).copied()
     }
 
     pub fn get_mod(&self, category: u8) -> u8 {
-        unsafe { alt_IVehicle_GetMod(self.0.load(Ordering::Relaxed), category) }
+        backend::instance().vehicle_get_mod(self.0.ptr(), category)
     }
 
     pub fn get_mods_count(&self, category: u8) -> u8 {
-        unsafe { alt_IVehicle_GetModsCount(self.0.load(Ordering::Relaxed), category) }
+        backend::instance().vehicle_get_mods_count(self.0.ptr(), category)
     }
 
     pub fn set_mod(&self, category: u8, id: u8) -> bool {
-        unsafe { alt_IVehicle_SetMod(self.0.load(Ordering::Relaxed), category, id) }
+        backend::instance().vehicle_set_mod(self.0.ptr(), category, id)
     }
 
     pub fn get_mod_kit(&self) -> u8 {
-        unsafe { alt_IVehicle_GetModKit(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_mod_kit(self.0.ptr())
     }
 
     pub fn get_mod_kits_count(&self) -> u8 {
-        unsafe { alt_IVehicle_GetModKitsCount(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_mod_kits_count(self.0.ptr())
     }
 
     pub fn set_mod_kit(&mut self, id: u8) -> bool {
-        unsafe { alt_IVehicle_SetModKit(self.0.load(Ordering::Relaxed), id) }
+        backend::instance().vehicle_set_mod_kit(self.0.ptr(), id)
     }
 
     pub fn is_primary_color_rgb(&self) -> bool {
-        unsafe { alt_IVehicle_IsPrimaryColorRGB(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_primary_color_rgb(self.0.ptr())
     }
 
     pub fn get_primary_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetPrimaryColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_primary_color(self.0.ptr())
     }
 
     pub fn get_primary_color_rgb(&self) -> Rgba {
-        unsafe {
-            let c = alt_IVehicle_GetPrimaryColorRGB_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rgba::from(*c)
-        }
+        backend::instance().vehicle_get_primary_color_rgb(self.0.ptr())
     }
 
     pub fn set_primary_color(&mut self, primary_color: u8) {
-        unsafe { alt_IVehicle_SetPrimaryColor(self.0.load(Ordering::Relaxed), primary_color) }
+        backend::instance().vehicle_set_primary_color(self.0.ptr(), primary_color)
     }
 
     pub fn set_primary_color_rgb(&mut self, primary_color_rgb: Rgba) {
-        unsafe {
-            alt_IVehicle_SetPrimaryColorRGB(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(primary_color_rgb.into())),
-            )
-        }
+        backend::instance().vehicle_set_primary_color_rgb(self.0.ptr(), primary_color_rgb)
     }
 
     pub fn is_secondary_color_rgb(&self) -> bool {
-        unsafe { alt_IVehicle_IsSecondaryColorRGB(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_secondary_color_rgb(self.0.ptr())
     }
 
     pub fn get_secondary_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetSecondaryColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_secondary_color(self.0.ptr())
     }
 
     pub fn get_secondary_color_rgb(&self) -> Rgba {
-        unsafe {
-            let c = alt_IVehicle_GetSecondaryColorRGB_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rgba::from(*c)
-        }
+        backend::instance().vehicle_get_secondary_color_rgb(self.0.ptr())
     }
 
     pub fn set_secondary_color(&mut self, secondary_color: u8) {
-        unsafe { alt_IVehicle_SetSecondaryColor(self.0.load(Ordering::Relaxed), secondary_color) }
+        backend::instance().vehicle_set_secondary_color(self.0.ptr(), secondary_color)
     }
 
     pub fn set_secondary_color_rgb(&mut self, secondary_color_rgb: Rgba) {
-        unsafe {
-            alt_IVehicle_SetSecondaryColorRGB(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(secondary_color_rgb.into())),
-            )
-        }
+        backend::instance().vehicle_set_secondary_color_rgb(self.0.ptr(), secondary_color_rgb)
     }
 
     pub fn get_pearl_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetPearlColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_pearl_color(self.0.ptr())
     }
 
     pub fn set_pearl_color(&mut self, pearl_color: u8) {
-        unsafe { alt_IVehicle_SetPearlColor(self.0.load(Ordering::Relaxed), pearl_color) }
+        backend::instance().vehicle_set_pearl_color(self.0.ptr(), pearl_color)
     }
 
     pub fn get_wheel_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetWheelColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_wheel_color(self.0.ptr())
     }
 
     pub fn set_wheel_color(&mut self, wheel_color: u8) {
-        unsafe { alt_IVehicle_SetWheelColor(self.0.load(Ordering::Relaxed), wheel_color) }
+        backend::instance().vehicle_set_wheel_color(self.0.ptr(), wheel_color)
     }
 
     pub fn get_interior_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetInteriorColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_interior_color(self.0.ptr())
     }
 
     pub fn set_interior_color(&mut self, interior_color: u8) {
-        unsafe { alt_IVehicle_SetInteriorColor(self.0.load(Ordering::Relaxed), interior_color) }
+        backend::instance().vehicle_set_interior_color(self.0.ptr(), interior_color)
     }
 
     pub fn get_dashboard_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetDashboardColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_dashboard_color(self.0.ptr())
     }
 
     pub fn set_dashboard_color(&mut self, dashboard_color: u8) {
-        unsafe { alt_IVehicle_SetDashboardColor(self.0.load(Ordering::Relaxed), dashboard_color) }
+        backend::instance().vehicle_set_dashboard_color(self.0.ptr(), dashboard_color)
     }
 
     pub fn is_tire_smoke_color_custom(&self) -> bool {
-        unsafe { alt_IVehicle_IsTireSmokeColorCustom(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_tire_smoke_color_custom(self.0.ptr())
     }
 
     pub fn get_tire_smoke_color(&self) -> Rgba {
-        unsafe {
-            let c = alt_IVehicle_GetTireSmokeColor_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rgba::from(*c)
-        }
+        backend::instance().vehicle_get_tire_smoke_color(self.0.ptr())
     }
 
     pub fn set_tire_smoke_color(&mut self, tire_smoke_color: Rgba) {
-        unsafe {
-            alt_IVehicle_SetTireSmokeColor(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(tire_smoke_color.into())),
-            )
-        }
+        backend::instance().vehicle_set_tire_smoke_color(self.0.ptr(), tire_smoke_color)
     }
 
     pub fn get_wheel_type(&self) -> u8 {
-        unsafe { alt_IVehicle_GetWheelType(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_wheel_type(self.0.ptr())
     }
 
     pub fn get_wheel_variation(&self) -> u8 {
-        unsafe { alt_IVehicle_GetWheelVariation(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_wheel_variation(self.0.ptr())
     }
 
     pub fn get_rear_wheel_variation(&self) -> u8 {
-        unsafe { alt_IVehicle_GetRearWheelVariation(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_rear_wheel_variation(self.0.ptr())
     }
 
     pub fn set_wheels(&mut self, t: u8, variation: u8) {
-        unsafe { alt_IVehicle_SetWheels(self.0.load(Ordering::Relaxed), t, variation) }
+        backend::instance().vehicle_set_wheels(self.0.ptr(), t, variation)
     }
 
     pub fn set_rear_wheels(&mut self, variation: u8) {
-        unsafe { alt_IVehicle_SetRearWheels(self.0.load(Ordering::Relaxed), variation) }
+        backend::instance().vehicle_set_rear_wheels(self.0.ptr(), variation)
     }
 
     pub fn get_custom_tires(&self) -> bool {
-        unsafe { alt_IVehicle_GetCustomTires(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_custom_tires(self.0.ptr())
     }
 
     pub fn set_custom_tires(&mut self, state: bool) {
-        unsafe { alt_IVehicle_SetCustomTires(self.0.load(Ordering::Relaxed), state) }
+        backend::instance().vehicle_set_custom_tires(self.0.ptr(), state)
     }
 
     pub fn get_special_darkness(&self) -> u8 {
-        unsafe { alt_IVehicle_GetSpecialDarkness(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_special_darkness(self.0.ptr())
     }
 
     pub fn set_special_darkness(&mut self, special_darkness: u8) {
-        unsafe { alt_IVehicle_SetSpecialDarkness(self.0.load(Ordering::Relaxed), special_darkness) }
+        backend::instance().vehicle_set_special_darkness(self.0.ptr(), special_darkness)
     }
 
-    pub fn get_license_plate_index(&self) -> u32 {
-        unsafe { alt_IVehicle_GetNumberplateIndex(self.0.load(Ordering::Relaxed)) }
+    /// Returns `None` for a style the SDK doesn't know.
+    pub fn get_numberplate_style(&self) -> Option<NumberPlateStyle> {
+        NumberPlateStyle::try_from(backend::instance().vehicle_get_numberplate_index(self.0.ptr()))
+            .ok()
     }
 
-    pub fn set_license_plate_index(&mut self, index: u32) {
-        unsafe { alt_IVehicle_SetNumberplateIndex(self.0.load(Ordering::Relaxed), index) }
+    pub fn set_numberplate_style(&mut self, style: NumberPlateStyle) {
+        backend::instance().vehicle_set_numberplate_index(self.0.ptr(), style as u32)
     }
 
-    pub fn get_license_plate_text(&self) -> String {
-        unsafe {
-            let text = alt_IVehicle_GetNumberplateText_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            StringView::from(*text).get_data()
-        }
+    pub fn get_numberplate_text(&self) -> String {
+        backend::instance().vehicle_get_numberplate_text(self.0.ptr())
     }
 
-    pub fn set_license_plate_text(&mut self, text: &str) {
-        unsafe {
-            alt_IVehicle_SetNumberplateText(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(text).into())),
-            )
-        }
+    pub fn set_numberplate_text(&mut self, text: &str) {
+        backend::instance().vehicle_set_numberplate_text(self.0.ptr(), text)
     }
 
-    pub fn get_window_tint(&self) -> u8 {
-        unsafe { alt_IVehicle_GetWindowTint(self.0.load(Ordering::Relaxed)) }
+    /// Returns `None` for a tint the SDK doesn't know.
+    pub fn get_window_tint(&self) -> Option<WindowTint> {
+        WindowTint::try_from(backend::instance().vehicle_get_window_tint(self.0.ptr())).ok()
     }
 
-    pub fn set_window_tint(&mut self, window_tint: u8) {
-        unsafe { alt_IVehicle_SetWindowTint(self.0.load(Ordering::Relaxed), window_tint) }
+    pub fn set_window_tint(&mut self, window_tint: WindowTint) {
+        backend::instance().vehicle_set_window_tint(self.0.ptr(), window_tint as u8)
     }
 
     pub fn get_dirt_level(&self) -> u8 {
-        unsafe { alt_IVehicle_GetDirtLevel(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_dirt_level(self.0.ptr())
     }
 
     pub fn set_dirt_level(&mut self, dirt_level: u8) {
-        unsafe { alt_IVehicle_SetDirtLevel(self.0.load(Ordering::Relaxed), dirt_level) }
+        backend::instance().vehicle_set_dirt_level(self.0.ptr(), dirt_level)
     }
 
     pub fn is_extra_on(&self, extra_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsExtraOn(self.0.load(Ordering::Relaxed), extra_id) }
+        backend::instance().vehicle_is_extra_on(self.0.ptr(), extra_id)
     }
 
-    pub fn set_extra_on(&mut self, extra_id: u8, state: bool) {
-        unsafe { alt_IVehicle_ToggleExtra(self.0.load(Ordering::Relaxed), extra_id, state) }
+    pub fn toggle_extra(&mut self, extra_id: u8, state: bool) {
+        backend::instance().vehicle_toggle_extra(self.0.ptr(), extra_id, state)
     }
 
     pub fn is_neon_active(&self) -> bool {
-        unsafe { alt_IVehicle_IsNeonActive(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_neon_active(self.0.ptr())
     }
 
     pub fn get_neon_active(&self) -> (bool, bool, bool, bool) {
-        unsafe {
-            let left = Box::into_raw(Box::new(false));
-            let right = Box::into_raw(Box::new(false));
-            let front = Box::into_raw(Box::new(false));
-            let back = Box::into_raw(Box::new(false));
-            alt_IVehicle_GetNeonActive(self.0.load(Ordering::Relaxed), left, right, front, back);
-            (*left, *right, *front, *back)
-        }
+        backend::instance().vehicle_get_neon_active(self.0.ptr())
     }
 
     pub fn set_neon_active(&mut self, left: bool, right: bool, front: bool, back: bool) {
-        unsafe {
-            alt_IVehicle_SetNeonActive(self.0.load(Ordering::Relaxed), left, right, front, back)
-        }
+        backend::instance().vehicle_set_neon_active(self.0.ptr(), left, right, front, back)
     }
 
     pub fn get_neon_color(&self) -> Rgba {
-        unsafe {
-            let c = alt_IVehicle_GetNeonColor_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rgba::from(*c)
-        }
+        backend::instance().vehicle_get_neon_color(self.0.ptr())
     }
 
     pub fn set_neon_color(&mut self, neon_color: Rgba) {
-        unsafe {
-            alt_IVehicle_SetNeonColor(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(neon_color.into())),
-            )
-        }
+        backend::instance().vehicle_set_neon_color(self.0.ptr(), neon_color)
     }
 
     pub fn get_livery(&self) -> u8 {
-        unsafe { alt_IVehicle_GetLivery(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_livery(self.0.ptr())
     }
 
     pub fn set_livery(&mut self, livery: u8) {
-        unsafe { alt_IVehicle_SetLivery(self.0.load(Ordering::Relaxed), livery) }
+        backend::instance().vehicle_set_livery(self.0.ptr(), livery)
     }
 
     pub fn get_roof_livery(&self) -> u8 {
-        unsafe { alt_IVehicle_GetRoofLivery(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_roof_livery(self.0.ptr())
     }
 
     pub fn set_roof_livery(&mut self, roof_livery: u8) {
-        unsafe { alt_IVehicle_SetRoofLivery(self.0.load(Ordering::Relaxed), roof_livery) }
+        backend::instance().vehicle_set_roof_livery(self.0.ptr(), roof_livery)
     }
 
     pub fn get_appearance_data_base64(&self) -> String {
-        unsafe {
-            let b = alt_IVehicle_GetAppearanceDataBase64_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            crate::string::String::from(*b).get_data()
-        }
+        backend::instance().vehicle_get_appearance_data_base64(self.0.ptr())
     }
 
     pub fn load_appearance_data_from_base64(&mut self, base64: &str) {
-        unsafe {
-            alt_IVehicle_LoadAppearanceDataFromBase64(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(base64).into())),
-            )
-        }
+        backend::instance().vehicle_load_appearance_data_from_base64(self.0.ptr(), base64)
     }
 
     pub fn is_engine_on(&self) -> bool {
-        unsafe { alt_IVehicle_IsEngineOn(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_engine_on(self.0.ptr())
     }
 
     pub fn set_engine_on(&mut self, state: bool) {
-        unsafe { alt_IVehicle_SetEngineOn(self.0.load(Ordering::Relaxed), state) }
+        backend::instance().vehicle_set_engine_on(self.0.ptr(), state)
     }
 
     pub fn is_handbrake_active(&self) -> bool {
-        unsafe { alt_IVehicle_IsHandbrakeActive(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_handbrake_active(self.0.ptr())
     }
 
     pub fn get_headlight_color(&self) -> u8 {
-        unsafe { alt_IVehicle_GetHeadlightColor(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_headlight_color(self.0.ptr())
     }
 
     pub fn set_headlight_color(&mut self, headlight_color: u8) {
-        unsafe { alt_IVehicle_SetHeadlightColor(self.0.load(Ordering::Relaxed), headlight_color) }
+        backend::instance().vehicle_set_headlight_color(self.0.ptr(), headlight_color)
     }
 
     pub fn get_radio_station_index(&self) -> u32 {
-        unsafe { alt_IVehicle_GetRadioStationIndex(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_radio_station_index(self.0.ptr())
     }
 
     pub fn set_radio_station_index(&mut self, index: u32) {
-        unsafe { alt_IVehicle_SetRadioStationIndex(self.0.load(Ordering::Relaxed), index) }
+        backend::instance().vehicle_set_radio_station_index(self.0.ptr(), index)
     }
 
     pub fn is_siren_active(&self) -> bool {
-        unsafe { alt_IVehicle_IsSirenActive(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_siren_active(self.0.ptr())
+    }
+
+    pub fn set_siren_active(&mut self, state: bool) {
+        backend::instance().vehicle_set_siren_active(self.0.ptr(), state)
+    }
+
+    /// Returns `None` for a lock state the SDK doesn't know.
+    pub fn get_lock_state(&self) -> Option<LockState> {
+        LockState::try_from(backend::instance().vehicle_get_lock_state(self.0.ptr())).ok()
     }
 
-    pub fn get_lock_state(&self) -> u8 {
-        unsafe { alt_IVehicle_GetLockState(self.0.load(Ordering::Relaxed)) }
+    pub fn set_lock_state(&mut self, lock_state: LockState) {
+        backend::instance().vehicle_set_lock_state(self.0.ptr(), lock_state as u8)
     }
 
-    pub fn set_lock_state(&mut self, lock_state: u8) {
-        unsafe { alt_IVehicle_SetLockState(self.0.load(Ordering::Relaxed), lock_state) }
+    /// Returns how far a door is open, doors are numbered from the front left one to the trunk (5).
+    pub fn get_door_state(&self, door_id: u8) -> DoorState {
+        DoorState::from(backend::instance().vehicle_get_door_state(self.0.ptr(), door_id))
+    }
+
+    pub fn set_door_state(&mut self, door_id: u8, state: DoorState) {
+        backend::instance().vehicle_set_door_state(self.0.ptr(), door_id, state as u8)
     }
 
     pub fn is_window_opened(&self, window_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsWindowOpened(self.0.load(Ordering::Relaxed), window_id) }
+        backend::instance().vehicle_is_window_opened(self.0.ptr(), window_id)
     }
 
     pub fn set_window_opened(&mut self, window_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWindowOpened(self.0.load(Ordering::Relaxed), window_id, state) }
+        backend::instance().vehicle_set_window_opened(self.0.ptr(), window_id, state)
     }
 
     pub fn is_daylight_on(&self) -> bool {
-        unsafe { alt_IVehicle_IsDaylightOn(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_daylight_on(self.0.ptr())
     }
 
     pub fn is_nightlight_on(&self) -> bool {
-        unsafe { alt_IVehicle_IsNightlightOn(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_nightlight_on(self.0.ptr())
     }
 
     pub fn is_roof_opened(&self) -> bool {
-        unsafe { alt_IVehicle_IsRoofOpened(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_roof_opened(self.0.ptr())
     }
 
     pub fn set_roof_opened(&mut self, state: bool) {
-        unsafe { alt_IVehicle_SetRoofOpened(self.0.load(Ordering::Relaxed), state) }
+        backend::instance().vehicle_set_roof_opened(self.0.ptr(), state)
     }
 
     pub fn is_flamethrower_active(&self) -> bool {
-        unsafe { alt_IVehicle_IsFlamethrowerActive(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_flamethrower_active(self.0.ptr())
     }
 
     pub fn get_lights_multiplier(&self) -> f32 {
-        unsafe { alt_IVehicle_GetLightsMultiplier(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_lights_multiplier(self.0.ptr())
     }
 
     pub fn set_lights_multiplier(&mut self, multiplier: f32) {
-        unsafe { alt_IVehicle_SetLightsMultiplier(self.0.load(Ordering::Relaxed), multiplier) }
+        backend::instance().vehicle_set_lights_multiplier(self.0.ptr(), multiplier)
     }
 
     pub fn get_game_state_base64(&self) -> String {
-        unsafe {
-            let b = alt_IVehicle_GetScriptDataBase64_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            crate::string::String::from(*b).get_data()
-        }
+        backend::instance().vehicle_get_game_state_base64(self.0.ptr())
     }
 
-    pub fn load_game_state_base64(&mut self, base64: &str) {
-        unsafe {
-            alt_IVehicle_LoadScriptDataFromBase64(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(base64).into())),
-            )
-        }
+    pub fn load_game_state_from_base64(&mut self, base64: &str) {
+        backend::instance().vehicle_load_game_state_from_base64(self.0.ptr(), base64)
     }
 
     pub fn get_engine_health(&self) -> i32 {
-        unsafe { alt_IVehicle_GetEngineHealth(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_engine_health(self.0.ptr())
     }
 
     pub fn set_engine_health(&mut self, health: i32) {
-        unsafe { alt_IVehicle_SetEngineHealth(self.0.load(Ordering::Relaxed), health) }
+        backend::instance().vehicle_set_engine_health(self.0.ptr(), health)
     }
 
-    pub fn get_fuel_tank_health(&self) -> i32 {
-        unsafe { alt_IVehicle_GetPetrolTankHealth(self.0.load(Ordering::Relaxed)) }
+    pub fn get_petrol_tank_health(&self) -> i32 {
+        backend::instance().vehicle_get_petrol_tank_health(self.0.ptr())
     }
 
-    pub fn set_fuel_tank_health(&mut self, health: i32) {
-        unsafe { alt_IVehicle_SetPetrolTankHealth(self.0.load(Ordering::Relaxed), health) }
+    pub fn set_petrol_tank_health(&mut self, health: i32) {
+        backend::instance().vehicle_set_petrol_tank_health(self.0.ptr(), health)
     }
 
     pub fn get_wheels_count(&self) -> u8 {
-        unsafe { alt_IVehicle_GetWheelsCount(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_wheels_count(self.0.ptr())
     }
 
     pub fn is_wheel_burst(&self, wheel_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsWheelBurst(self.0.load(Ordering::Relaxed), wheel_id) }
+        backend::instance().vehicle_is_wheel_burst(self.0.ptr(), wheel_id)
     }
 
     pub fn set_wheel_burst(&mut self, wheel_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWheelBurst(self.0.load(Ordering::Relaxed), wheel_id, state) }
+        backend::instance().vehicle_set_wheel_burst(self.0.ptr(), wheel_id, state)
     }
 
     pub fn does_wheel_have_tire(&self, wheel_id: u8) -> bool {
-        unsafe { alt_IVehicle_DoesWheelHasTire(self.0.load(Ordering::Relaxed), wheel_id) }
+        backend::instance().vehicle_does_wheel_has_tire(self.0.ptr(), wheel_id)
     }
 
     pub fn set_wheel_has_tire(&mut self, wheel_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWheelHasTire(self.0.load(Ordering::Relaxed), wheel_id, state) }
+        backend::instance().vehicle_set_wheel_has_tire(self.0.ptr(), wheel_id, state)
     }
 
     pub fn is_wheel_detached(&self, wheel_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsWheelDetached(self.0.load(Ordering::Relaxed), wheel_id) }
+        backend::instance().vehicle_is_wheel_detached(self.0.ptr(), wheel_id)
     }
 
     pub fn set_wheel_detached(&mut self, wheel_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWheelDetached(self.0.load(Ordering::Relaxed), wheel_id, state) }
+        backend::instance().vehicle_set_wheel_detached(self.0.ptr(), wheel_id, state)
     }
 
     pub fn is_wheel_on_fire(&self, wheel_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsWheelOnFire(self.0.load(Ordering::Relaxed), wheel_id) }
+        backend::instance().vehicle_is_wheel_on_fire(self.0.ptr(), wheel_id)
     }
 
     pub fn set_wheel_on_fire(&mut self, wheel_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWheelOnFire(self.0.load(Ordering::Relaxed), wheel_id, state) }
+        backend::instance().vehicle_set_wheel_on_fire(self.0.ptr(), wheel_id, state)
     }
 
     pub fn get_wheel_health(&self, wheel_id: u8) -> f32 {
-        unsafe { alt_IVehicle_GetWheelHealth(self.0.load(Ordering::Relaxed), wheel_id) }
+        backend::instance().vehicle_get_wheel_health(self.0.ptr(), wheel_id)
     }
 
     pub fn set_wheel_health(&mut self, wheel_id: u8, health: f32) {
-        unsafe { alt_IVehicle_SetWheelHealth(self.0.load(Ordering::Relaxed), wheel_id, health) }
+        backend::instance().vehicle_set_wheel_health(self.0.ptr(), wheel_id, health)
     }
 
     pub fn get_repairs_count(&self) -> u8 {
-        unsafe { alt_IVehicle_GetRepairsCount(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_repairs_count(self.0.ptr())
     }
 
     pub fn get_body_health(&self) -> u32 {
-        unsafe { alt_IVehicle_GetBodyHealth(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_body_health(self.0.ptr())
     }
 
     pub fn set_body_health(&mut self, health: u32) {
-        unsafe { alt_IVehicle_SetBodyHealth(self.0.load(Ordering::Relaxed), health) }
+        backend::instance().vehicle_set_body_health(self.0.ptr(), health)
     }
 
     pub fn get_body_additional_health(&self) -> u32 {
-        unsafe { alt_IVehicle_GetBodyAdditionalHealth(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_get_body_additional_health(self.0.ptr())
     }
 
     pub fn set_body_additional_health(&mut self, health: u32) {
-        unsafe { alt_IVehicle_SetBodyAdditionalHealth(self.0.load(Ordering::Relaxed), health) }
+        backend::instance().vehicle_set_body_additional_health(self.0.ptr(), health)
     }
 
     pub fn get_health_data_base64(&self) -> String {
-        unsafe {
-            let b = alt_IVehicle_GetHealthDataBase64_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            crate::string::String::from(*b).get_data()
-        }
+        backend::instance().vehicle_get_health_data_base64(self.0.ptr())
     }
 
     pub fn load_health_data_from_base64(&mut self, base64: &str) {
-        unsafe {
-            alt_IVehicle_LoadHealthDataFromBase64(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(base64).into())),
-            )
-        }
+        backend::instance().vehicle_load_health_data_from_base64(self.0.ptr(), base64)
     }
 
     pub fn get_part_damage_level(&self, part_id: u8) -> u8 {
-        unsafe { alt_IVehicle_GetPartDamageLevel(self.0.load(Ordering::Relaxed), part_id) }
+        backend::instance().vehicle_get_part_damage_level(self.0.ptr(), part_id)
     }
 
     pub fn set_part_damage_level(&mut self, part_id: u8, damage_level: u8) {
-        unsafe {
-            alt_IVehicle_SetPartDamageLevel(self.0.load(Ordering::Relaxed), part_id, damage_level)
-        }
+        backend::instance().vehicle_set_part_damage_level(self.0.ptr(), part_id, damage_level)
     }
 
     pub fn get_part_bullet_holes(&self, part_id: u8) -> u8 {
-        unsafe { alt_IVehicle_GetPartBulletHoles(self.0.load(Ordering::Relaxed), part_id) }
+        backend::instance().vehicle_get_part_bullet_holes(self.0.ptr(), part_id)
     }
 
     pub fn set_part_bullet_holes(&mut self, part_id: u8, count: u8) {
-        unsafe { alt_IVehicle_SetPartBulletHoles(self.0.load(Ordering::Relaxed), part_id, count) }
+        backend::instance().vehicle_set_part_bullet_holes(self.0.ptr(), part_id, count)
     }
 
     pub fn is_light_damaged(&self, light_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsLightDamaged(self.0.load(Ordering::Relaxed), light_id) }
+        backend::instance().vehicle_is_light_damaged(self.0.ptr(), light_id)
     }
 
     pub fn set_light_damaged(&mut self, light_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetLightDamaged(self.0.load(Ordering::Relaxed), light_id, state) }
+        backend::instance().vehicle_set_light_damaged(self.0.ptr(), light_id, state)
     }
 
     pub fn is_window_damaged(&self, window_id: u8) -> bool {
-        unsafe { alt_IVehicle_IsWindowDamaged(self.0.load(Ordering::Relaxed), window_id) }
+        backend::instance().vehicle_is_window_damaged(self.0.ptr(), window_id)
     }
 
     pub fn set_window_damaged(&mut self, window_id: u8, state: bool) {
-        unsafe { alt_IVehicle_SetWindowDamaged(self.0.load(Ordering::Relaxed), window_id, state) }
+        backend::instance().vehicle_set_window_damaged(self.0.ptr(), window_id, state)
     }
 
     pub fn is_special_light_damaged(&self, special_light_id: u8) -> bool {
-        unsafe {
-            alt_IVehicle_IsSpecialLightDamaged(self.0.load(Ordering::Relaxed), special_light_id)
-        }
+        backend::instance().vehicle_is_special_light_damaged(self.0.ptr(), special_light_id)
     }
 
     pub fn set_special_light_damaged(&mut self, special_light_id: u8, state: bool) {
-        unsafe {
-            alt_IVehicle_SetSpecialLightDamaged(
-                self.0.load(Ordering::Relaxed),
-                special_light_id,
-                state,
-            )
-        }
+        backend::instance().vehicle_set_special_light_damaged(self.0.ptr(), special_light_id, state)
     }
 
     pub fn has_armored_windows(&self) -> bool {
-        unsafe { alt_IVehicle_HasArmoredWindows(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_has_armored_windows(self.0.ptr())
     }
 
     pub fn get_armored_window_health(&self, window_id: u8) -> f32 {
-        unsafe { alt_IVehicle_GetArmoredWindowHealth(self.0.load(Ordering::Relaxed), window_id) }
+        backend::instance().vehicle_get_armored_window_health(self.0.ptr(), window_id)
     }
 
     pub fn set_armored_window_health(&self, window_id: u8, health: f32) {
-        unsafe {
-            alt_IVehicle_SetArmoredWindowHealth(self.0.load(Ordering::Relaxed), window_id, health)
-        }
+        backend::instance().vehicle_set_armored_window_health(self.0.ptr(), window_id, health)
     }
 
     pub fn get_armored_window_shoot_count(&self, window_id: u8) -> u8 {
-        unsafe {
-            alt_IVehicle_GetArmoredWindowShootCount(self.0.load(Ordering::Relaxed), window_id)
-        }
+        backend::instance().vehicle_get_armored_window_shoot_count(self.0.ptr(), window_id)
     }
 
     pub fn set_armored_window_shoot_count(&mut self, window_id: u8, shoot_count: u8) {
-        unsafe {
-            alt_IVehicle_SetArmoredWindowShootCount(
-                self.0.load(Ordering::Relaxed),
-                window_id,
-                shoot_count,
-            )
-        }
+        backend::instance().vehicle_set_armored_window_shoot_count(
+            self.0.ptr(),
+            window_id,
+            shoot_count,
+        )
     }
 
     pub fn get_bumper_damage_level(&self, bumper_id: u8) -> u8 {
-        unsafe { alt_IVehicle_GetBumperDamageLevel(self.0.load(Ordering::Relaxed), bumper_id) }
+        backend::instance().vehicle_get_bumper_damage_level(self.0.ptr(), bumper_id)
     }
 
     pub fn set_bumper_damage_level(&mut self, bumper_id: u8, damage_level: u8) {
-        unsafe {
-            alt_IVehicle_SetBumperDamageLevel(
-                self.0.load(Ordering::Relaxed),
-                bumper_id,
-                damage_level,
-            )
-        }
+        backend::instance().vehicle_set_bumper_damage_level(self.0.ptr(), bumper_id, damage_level)
     }
 
     pub fn get_damage_data_base64(&self) -> String {
-        unsafe {
-            let b = alt_IVehicle_GetDamageDataBase64_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            crate::string::String::from(*b).get_data()
-        }
+        backend::instance().vehicle_get_damage_data_base64(self.0.ptr())
     }
 
     pub fn load_damage_data_from_base64(&mut self, base64: &str) {
-        unsafe {
-            alt_IVehicle_LoadDamageDataFromBase64(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(base64).into())),
-            )
-        }
+        backend::instance().vehicle_load_damage_data_from_base64(self.0.ptr(), base64)
     }
 
     pub fn set_manual_engine_control(&mut self, state: bool) {
-        unsafe { alt_IVehicle_SetManualEngineControl(self.0.load(Ordering::Relaxed), state) }
+        backend::instance().vehicle_set_manual_engine_control(self.0.ptr(), state)
     }
 
     pub fn is_manual_engine_control(&self) -> bool {
-        unsafe { alt_IVehicle_IsManualEngineControl(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_manual_engine_control(self.0.ptr())
     }
 
     pub fn get_script_data_base64(&self) -> String {
-        unsafe {
-            let b = alt_IVehicle_GetScriptDataBase64_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            crate::string::String::from(*b).get_data()
-        }
+        backend::instance().vehicle_get_script_data_base64(self.0.ptr())
     }
 
     pub fn load_script_data_from_base64(&mut self, base64: &str) {
-        unsafe {
-            alt_IVehicle_LoadScriptDataFromBase64(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(StringView::new(base64).into())),
-            )
-        }
+        backend::instance().vehicle_load_script_data_from_base64(self.0.ptr(), base64)
     }
 
     pub fn is_destroyed(&self) -> bool {
-        unsafe { alt_IVehicle_IsDestroyed(self.0.load(Ordering::Relaxed)) }
+        backend::instance().vehicle_is_destroyed(self.0.ptr())
     }
 }
 
@@ -1333,29 +1308,41 @@ impl Component for CVehicle {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CCollisionShape(pub AtomicPtr<alt_IColShape>);
+pub struct CCollisionShape(Handle<alt_IColShape>);
 
 impl CCollisionShape {
-    pub fn get_type(&self) -> u8 {
-        unsafe { alt_IColShape_GetColshapeType(self.0.load(Ordering::Relaxed)) as u8 }
+    pub fn handle(&self) -> &Handle<alt_IColShape> {
+        &self.0
+    }
+
+    pub fn get_type(&self) -> CollisionShapeType {
+        backend::instance()
+            .col_shape_get_colshape_type(self.0.ptr())
+            .into()
     }
 
     pub fn is_entity_in(&self, entity: &CEntity) -> bool {
-        unsafe {
-            alt_IColShape_IsEntityIn(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IEntity_Create_4_CAPI_Heap(entity.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().col_shape_is_entity_in(self.0.ptr(), entity.0.ptr())
     }
 
     pub fn is_point_in(&self, position: Vector3) -> bool {
-        unsafe {
-            alt_IColShape_IsPointIn(
-                self.0.load(Ordering::Relaxed),
-                Box::into_raw(Box::new(position.into())),
-            )
-        }
+        backend::instance().col_shape_is_point_in(self.0.ptr(), position)
+    }
+
+    pub fn get_dimension(&self) -> i32 {
+        backend::instance().col_shape_get_dimension(self.0.ptr())
+    }
+
+    pub fn set_dimension(&mut self, dimension: i32) {
+        backend::instance().col_shape_set_dimension(self.0.ptr(), dimension)
+    }
+
+    pub fn get_position(&self) -> Vector3 {
+        backend::instance().col_shape_get_position(self.0.ptr())
+    }
+
+    pub fn set_position(&mut self, pos: Vector3) {
+        backend::instance().col_shape_set_position(self.0.ptr(), pos)
     }
 }
 
@@ -1363,26 +1350,59 @@ impl Component for CCollisionShape {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CCheckpoint(pub AtomicPtr<alt_ICheckpoint>);
+pub struct CCheckpoint(Handle<alt_ICheckpoint>);
 
 impl CCheckpoint {
-    pub fn get_type(&self) -> u8 {
-        unsafe { alt_ICheckpoint_GetCheckpointType(self.0.load(Ordering::Relaxed)) as u8 }
+    pub fn handle(&self) -> &Handle<alt_ICheckpoint> {
+        &self.0
+    }
+
+    /// Returns `None` if the checkpoint was created with a type the SDK doesn't know.
+    pub fn get_type(&self) -> Option<CheckpointType> {
+        CheckpointType::try_from(backend::instance().checkpoint_get_checkpoint_type(self.0.ptr()))
+            .ok()
+    }
+
+    pub fn get_colshape_type(&self) -> CollisionShapeType {
+        backend::instance()
+            .checkpoint_get_colshape_type(self.0.ptr())
+            .into()
+    }
+
+    pub fn is_entity_in(&self, entity: &CEntity) -> bool {
+        backend::instance().checkpoint_is_entity_in(self.0.ptr(), entity.0.ptr())
+    }
+
+    pub fn is_point_in(&self, position: Vector3) -> bool {
+        backend::instance().checkpoint_is_point_in(self.0.ptr(), position)
+    }
+
+    pub fn get_dimension(&self) -> i32 {
+        backend::instance().checkpoint_get_dimension(self.0.ptr())
+    }
+
+    pub fn set_dimension(&mut self, dimension: i32) {
+        backend::instance().checkpoint_set_dimension(self.0.ptr(), dimension)
+    }
+
+    pub fn get_position(&self) -> Vector3 {
+        backend::instance().checkpoint_get_position(self.0.ptr())
+    }
+
+    pub fn set_position(&mut self, pos: Vector3) {
+        backend::instance().checkpoint_set_position(self.0.ptr(), pos)
     }
 
     pub fn get_height(&self) -> f32 {
-        unsafe { alt_ICheckpoint_GetHeight(self.0.load(Ordering::Relaxed)) }
+        backend::instance().checkpoint_get_height(self.0.ptr())
     }
 
     pub fn get_radius(&self) -> f32 {
-        unsafe { alt_ICheckpoint_GetRadius(self.0.load(Ordering::Relaxed)) }
+        backend::instance().checkpoint_get_radius(self.0.ptr())
     }
 
     pub fn get_color(&self) -> Rgba {
-        unsafe {
-            let c = alt_ICheckpoint_GetColor_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            Rgba::from(*c)
-        }
+        backend::instance().checkpoint_get_color(self.0.ptr())
     }
 }
 
@@ -1390,67 +1410,75 @@ impl Component for CCheckpoint {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CBlip(pub AtomicPtr<alt_IBlip>);
+pub struct CBlip(Handle<alt_IBlip>);
 
 impl CBlip {
+    pub fn handle(&self) -> &Handle<alt_IBlip> {
+        &self.0
+    }
+
     pub fn is_global(&self) -> bool {
-        unsafe { alt_IBlip_IsGlobal(self.0.load(Ordering::Relaxed)) }
+        backend::instance().blip_is_global(self.0.ptr())
     }
 
     pub fn get_target(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let player = alt_IBlip_GetTarget_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let player = (*player).ptr;
-
-            if player.is_null() {
-                return None;
-            }
+        let player = backend::instance().blip_get_target(self.0.ptr());
 
-            Some(*alt.players.get(&(player as usize)).unwrap())
+        if player.is_null() {
+            return None;
         }
+
+        alt.players.get(&(player as usize)).copied()
     }
 
-    pub fn attached_to(&self, alt: &AltResource) -> Option<Entity> {
-        unsafe {
-            let entity = alt_IBlip_AttachedTo_CAPI_Heap(self.0.load(Ordering::Relaxed));
-            let entity = (*entity).ptr;
+    pub fn is_attached(&self) -> bool {
+        backend::instance().blip_is_attached(self.0.ptr())
+    }
 
-            if entity.is_null() {
-                return None;
-            }
+    pub fn attached_to(&self, alt: &AltResource) -> Option<Entity> {
+        let entity = backend::instance().blip_attached_to(self.0.ptr());
 
-            match alt_IEntity_GetType(entity) {
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
-                    let player = alt_IEntity_to_alt_IPlayer(entity);
-                    Some(*alt.players.get(&(player as usize)).unwrap())
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
-                    let vehicle = alt_IEntity_to_alt_IVehicle(entity);
-                    Some(*alt.vehicles.get(&(vehicle as usize)).unwrap())
-                }
-                _ => panic!(),
-            }
+        if entity.is_null() {
+            return None;
         }
+
+        get_entity(alt, entity).ok()
+    }
+
+    pub fn get_blip_type(&self) -> BlipType {
+        backend::instance().blip_get_blip_type(self.0.ptr()).into()
     }
 
-    pub fn get_blip_type(&self) -> u8 {
-        unsafe { alt_IBlip_GetBlipType(self.0.load(Ordering::Relaxed)) as u8 }
+    pub fn get_dimension(&self) -> i32 {
+        backend::instance().blip_get_dimension(self.0.ptr())
+    }
+
+    pub fn set_dimension(&mut self, dimension: i32) {
+        backend::instance().blip_set_dimension(self.0.ptr(), dimension)
+    }
+
+    pub fn get_position(&self) -> Vector3 {
+        backend::instance().blip_get_position(self.0.ptr())
+    }
+
+    pub fn set_position(&mut self, pos: Vector3) {
+        backend::instance().blip_set_position(self.0.ptr(), pos)
     }
 
     pub fn set_sprite(&mut self, sprite: u16) {
-        unsafe { alt_IBlip_SetSprite(self.0.load(Ordering::Relaxed), sprite) }
+        backend::instance().blip_set_sprite(self.0.ptr(), sprite)
     }
 
     pub fn set_color(&mut self, color: u8) {
-        unsafe { alt_IBlip_SetColor(self.0.load(Ordering::Relaxed), color) }
+        backend::instance().blip_set_color(self.0.ptr(), color)
     }
 
     pub fn set_route(&mut self, state: bool) {
-        unsafe { alt_IBlip_SetRoute(self.0.load(Ordering::Relaxed), state) }
+        backend::instance().blip_set_route(self.0.ptr(), state)
     }
 
     pub fn set_route_color(&mut self, color: u8) {
-        unsafe { alt_IBlip_SetRouteColor(self.0.load(Ordering::Relaxed), color) }
+        backend::instance().blip_set_route_color(self.0.ptr(), color)
     }
 }
 
@@ -1458,69 +1486,43 @@ impl Component for CBlip {
     type Storage = VecStorage<Self>;
 }
 
-pub struct CVoiceChannel(pub AtomicPtr<alt_IVoiceChannel>);
+pub struct CVoiceChannel(Handle<alt_IVoiceChannel>);
 
 impl CVoiceChannel {
+    pub fn handle(&self) -> &Handle<alt_IVoiceChannel> {
+        &self.0
+    }
+
     pub fn is_spatial(&self) -> bool {
-        unsafe { alt_IVoiceChannel_IsSpatial(self.0.load(Ordering::Relaxed)) }
+        backend::instance().voice_channel_is_spatial(self.0.ptr())
     }
 
     pub fn get_max_distance(&self) -> f32 {
-        unsafe { alt_IVoiceChannel_GetMaxDistance(self.0.load(Ordering::Relaxed)) }
+        backend::instance().voice_channel_get_max_distance(self.0.ptr())
     }
 
     pub fn has_player(&self, player: &CPlayer) -> bool {
-        unsafe {
-            alt_IVoiceChannel_HasPlayer(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_has_player(self.0.ptr(), player.0.ptr())
     }
 
     pub fn add_player(&mut self, player: &CPlayer) {
-        unsafe {
-            alt_IVoiceChannel_AddPlayer(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_add_player(self.0.ptr(), player.0.ptr())
     }
 
     pub fn remove_player(&mut self, player: &CPlayer) {
-        unsafe {
-            alt_IVoiceChannel_RemovePlayer(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_remove_player(self.0.ptr(), player.0.ptr())
     }
 
     pub fn is_player_muted(&self, player: &CPlayer) -> bool {
-        unsafe {
-            alt_IVoiceChannel_IsPlayerMuted(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_is_player_muted(self.0.ptr(), player.0.ptr())
     }
 
     pub fn mute_player(&mut self, player: &CPlayer) {
-        unsafe {
-            alt_IVoiceChannel_MutePlayer(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_mute_player(self.0.ptr(), player.0.ptr())
     }
 
     pub fn unmute_player(&mut self, player: &CPlayer) {
-        unsafe {
-            alt_IVoiceChannel_UnmutePlayer(
-                self.0.load(Ordering::Relaxed),
-                alt_RefBase_RefStore_IPlayer_Create_4_CAPI_Heap(player.0.load(Ordering::Relaxed)),
-            )
-        }
+        backend::instance().voice_channel_unmute_player(self.0.ptr(), player.0.ptr())
     }
 }
 
@@ -1528,6 +1530,7 @@ impl Component for CVoiceChannel {
     type Storage = VecStorage<Self>;
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CollisionShapeType {
     Sphere = 0,
     Cylinder = 1,
@@ -1536,3 +1539,353 @@ pub enum CollisionShapeType {
     Rectangle = 4,
     CheckpointCylinder = 5,
 }
+
+impl From<alt_IColShape_ColShapeType> for CollisionShapeType {
+    fn from(colshape_type: alt_IColShape_ColShapeType) -> Self {
+        match colshape_type {
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_SPHERE => {
+                CollisionShapeType::Sphere
+            }
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CYLINDER => {
+                CollisionShapeType::Cylinder
+            }
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CIRCLE => {
+                CollisionShapeType::Circle
+            }
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CUBOID => {
+                CollisionShapeType::Cuboid
+            }
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_RECT => {
+                CollisionShapeType::Rectangle
+            }
+            alt_IColShape_ColShapeType::ALT_ICOLSHAPE_COLSHAPETYPE_CHECKPOINT_CYLINDER => {
+                CollisionShapeType::CheckpointCylinder
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlipType {
+    Vehicle = 1,
+    Ped = 2,
+    Object = 3,
+    Destination = 4,
+    Cont = 5,
+    PickupUnk = 6,
+    Radius = 7,
+    Pickup = 8,
+    Cop = 9,
+    Area = 11,
+    Gallery = 12,
+    PickupObject = 13,
+}
+
+impl From<BlipType> for alt_IBlip_BlipType {
+    fn from(blip_type: BlipType) -> Self {
+        match blip_type {
+            BlipType::Vehicle => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_VEHICLE,
+            BlipType::Ped => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PED,
+            BlipType::Object => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_OBJECT,
+            BlipType::Destination => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_DESTINATION,
+            BlipType::Cont => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_CONT,
+            BlipType::PickupUnk => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_UNK,
+            BlipType::Radius => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_RADIUS,
+            BlipType::Pickup => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP,
+            BlipType::Cop => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_COP,
+            BlipType::Area => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_AREA,
+            BlipType::Gallery => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_GALLERY,
+            BlipType::PickupObject => alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_OBJECT,
+        }
+    }
+}
+
+impl From<alt_IBlip_BlipType> for BlipType {
+    fn from(blip_type: alt_IBlip_BlipType) -> Self {
+        match blip_type {
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_VEHICLE => BlipType::Vehicle,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PED => BlipType::Ped,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_OBJECT => BlipType::Object,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_DESTINATION => BlipType::Destination,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_CONT => BlipType::Cont,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_UNK => BlipType::PickupUnk,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_RADIUS => BlipType::Radius,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP => BlipType::Pickup,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_COP => BlipType::Cop,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_AREA => BlipType::Area,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_GALLERY => BlipType::Gallery,
+            alt_IBlip_BlipType::ALT_IBLIP_BLIPTYPE_PICKUP_OBJECT => BlipType::PickupObject,
+        }
+    }
+}
+
+/// The marker drawn for a checkpoint, the numbering follows the game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckpointType {
+    CylinderSingleArrow = 0,
+    CylinderDoubleArrow = 1,
+    CylinderTripleArrow = 2,
+    CylinderCycleArrow = 3,
+    CylinderCheckerboard = 4,
+    CylinderWrench = 5,
+    CylinderSingleArrow2 = 6,
+    CylinderDoubleArrow2 = 7,
+    CylinderTripleArrow2 = 8,
+    CylinderCycleArrow2 = 9,
+    CylinderCheckerboard2 = 10,
+    CylinderWrench2 = 11,
+    RingSingleArrow = 12,
+    RingDoubleArrow = 13,
+    RingTripleArrow = 14,
+    RingCycleArrow = 15,
+    RingCheckerboard = 16,
+    SingleArrow = 17,
+    DoubleArrow = 18,
+    TripleArrow = 19,
+    CycleArrow = 20,
+    Checkerboard = 21,
+    CylinderSingleArrow3 = 22,
+    CylinderDoubleArrow3 = 23,
+    CylinderTripleArrow3 = 24,
+    CylinderCycleArrow3 = 25,
+    CylinderCheckerboard3 = 26,
+    CylinderSingleArrow4 = 27,
+    CylinderDoubleArrow4 = 28,
+    CylinderTripleArrow4 = 29,
+    CylinderCycleArrow4 = 30,
+    CylinderCheckerboard4 = 31,
+    CylinderSingleArrow5 = 32,
+    CylinderDoubleArrow5 = 33,
+    CylinderTripleArrow5 = 34,
+    CylinderCycleArrow5 = 35,
+    CylinderCheckerboard5 = 36,
+    RingPlaneUp = 37,
+    RingPlaneLeft = 38,
+    RingPlaneRight = 39,
+    RingPlaneDown = 40,
+    Empty = 41,
+    Ring = 42,
+    Empty2 = 43,
+    Cylinder = 44,
+    Cylinder1 = 45,
+    Cylinder2 = 46,
+    Cylinder3 = 47,
+    Cylinder4 = 48,
+    Cylinder5 = 49,
+    Empty3 = 50,
+    Empty4 = 51,
+    Empty5 = 52,
+    Empty6 = 53,
+    RingDollar = 54,
+    RingWolf = 55,
+    RingQuestionMark = 56,
+    RingPlane = 57,
+    RingChopper = 58,
+    RingBoat = 59,
+    RingCar = 60,
+    RingBike = 61,
+    RingBicycle = 62,
+    RingTruck = 63,
+    RingParachute = 64,
+    RingJetpack = 65,
+    RingWhirl = 66,
+}
+
+impl TryFrom<u8> for CheckpointType {
+    type Error = u8;
+
+    fn try_from(value: u8) -> std::result::Result<Self, u8> {
+        let checkpoint_type = match value {
+            0 => CheckpointType::CylinderSingleArrow,
+            1 => CheckpointType::CylinderDoubleArrow,
+            2 => CheckpointType::CylinderTripleArrow,
+            3 => CheckpointType::CylinderCycleArrow,
+            4 => CheckpointType::CylinderCheckerboard,
+            5 => CheckpointType::CylinderWrench,
+            6 => CheckpointType::CylinderSingleArrow2,
+            7 => CheckpointType::CylinderDoubleArrow2,
+            8 => CheckpointType::CylinderTripleArrow2,
+            9 => CheckpointType::CylinderCycleArrow2,
+            10 => CheckpointType::CylinderCheckerboard2,
+            11 => CheckpointType::CylinderWrench2,
+            12 => CheckpointType::RingSingleArrow,
+            13 => CheckpointType::RingDoubleArrow,
+            14 => CheckpointType::RingTripleArrow,
+            15 => CheckpointType::RingCycleArrow,
+            16 => CheckpointType::RingCheckerboard,
+            17 => CheckpointType::SingleArrow,
+            18 => CheckpointType::DoubleArrow,
+            19 => CheckpointType::TripleArrow,
+            20 => CheckpointType::CycleArrow,
+            21 => CheckpointType::Checkerboard,
+            22 => CheckpointType::CylinderSingleArrow3,
+            23 => CheckpointType::CylinderDoubleArrow3,
+            24 => CheckpointType::CylinderTripleArrow3,
+            25 => CheckpointType::CylinderCycleArrow3,
+            26 => CheckpointType::CylinderCheckerboard3,
+            27 => CheckpointType::CylinderSingleArrow4,
+            28 => CheckpointType::CylinderDoubleArrow4,
+            29 => CheckpointType::CylinderTripleArrow4,
+            30 => CheckpointType::CylinderCycleArrow4,
+            31 => CheckpointType::CylinderCheckerboard4,
+            32 => CheckpointType::CylinderSingleArrow5,
+            33 => CheckpointType::CylinderDoubleArrow5,
+            34 => CheckpointType::CylinderTripleArrow5,
+            35 => CheckpointType::CylinderCycleArrow5,
+            36 => CheckpointType::CylinderCheckerboard5,
+            37 => CheckpointType::RingPlaneUp,
+            38 => CheckpointType::RingPlaneLeft,
+            39 => CheckpointType::RingPlaneRight,
+            40 => CheckpointType::RingPlaneDown,
+            41 => CheckpointType::Empty,
+            42 => CheckpointType::Ring,
+            43 => CheckpointType::Empty2,
+            44 => CheckpointType::Cylinder,
+            45 => CheckpointType::Cylinder1,
+            46 => CheckpointType::Cylinder2,
+            47 => CheckpointType::Cylinder3,
+            48 => CheckpointType::Cylinder4,
+            49 => CheckpointType::Cylinder5,
+            50 => CheckpointType::Empty3,
+            51 => CheckpointType::Empty4,
+            52 => CheckpointType::Empty5,
+            53 => CheckpointType::Empty6,
+            54 => CheckpointType::RingDollar,
+            55 => CheckpointType::RingWolf,
+            56 => CheckpointType::RingQuestionMark,
+            57 => CheckpointType::RingPlane,
+            58 => CheckpointType::RingChopper,
+            59 => CheckpointType::RingBoat,
+            60 => CheckpointType::RingCar,
+            61 => CheckpointType::RingBike,
+            62 => CheckpointType::RingBicycle,
+            63 => CheckpointType::RingTruck,
+            64 => CheckpointType::RingParachute,
+            65 => CheckpointType::RingJetpack,
+            66 => CheckpointType::RingWhirl,
+            _ => return Err(value),
+        };
+
+        Ok(checkpoint_type)
+    }
+}
+
+/// How far a vehicle door is open, anything the game doesn't report is `Unknown`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DoorState {
+    Closed = 0,
+    OpenedLevel1 = 1,
+    OpenedLevel2 = 2,
+    OpenedLevel3 = 3,
+    OpenedLevel4 = 4,
+    OpenedLevel5 = 5,
+    OpenedLevel6 = 6,
+    OpenedLevel7 = 7,
+    Unknown = 255,
+}
+
+impl From<u8> for DoorState {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DoorState::Closed,
+            1 => DoorState::OpenedLevel1,
+            2 => DoorState::OpenedLevel2,
+            3 => DoorState::OpenedLevel3,
+            4 => DoorState::OpenedLevel4,
+            5 => DoorState::OpenedLevel5,
+            6 => DoorState::OpenedLevel6,
+            7 => DoorState::OpenedLevel7,
+            _ => DoorState::Unknown,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LockState {
+    None = 0,
+    Unlocked = 1,
+    Locked = 2,
+    LockoutPlayerOnly = 3,
+    LockPlayerInside = 4,
+    InitiallyLocked = 5,
+    ForceDoorsShut = 6,
+    LockedCanBeDamaged = 7,
+}
+
+impl TryFrom<u8> for LockState {
+    type Error = u8;
+
+    fn try_from(value: u8) -> std::result::Result<Self, u8> {
+        let lock_state = match value {
+            0 => LockState::None,
+            1 => LockState::Unlocked,
+            2 => LockState::Locked,
+            3 => LockState::LockoutPlayerOnly,
+            4 => LockState::LockPlayerInside,
+            5 => LockState::InitiallyLocked,
+            6 => LockState::ForceDoorsShut,
+            7 => LockState::LockedCanBeDamaged,
+            _ => return Err(value),
+        };
+
+        Ok(lock_state)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WindowTint {
+    None = 0,
+    PureBlack = 1,
+    DarkSmoke = 2,
+    LightSmoke = 3,
+    Stock = 4,
+    Limo = 5,
+    Green = 6,
+}
+
+impl TryFrom<u8> for WindowTint {
+    type Error = u8;
+
+    fn try_from(value: u8) -> std::result::Result<Self, u8> {
+        let window_tint = match value {
+            0 => WindowTint::None,
+            1 => WindowTint::PureBlack,
+            2 => WindowTint::DarkSmoke,
+            3 => WindowTint::LightSmoke,
+            4 => WindowTint::Stock,
+            5 => WindowTint::Limo,
+            6 => WindowTint::Green,
+            _ => return Err(value),
+        };
+
+        Ok(window_tint)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberPlateStyle {
+    BlueWhite = 0,
+    YellowBlack = 1,
+    YellowBlue = 2,
+    BlueWhite2 = 3,
+    BlueWhite3 = 4,
+    Yankton = 5,
+}
+
+impl TryFrom<u32> for NumberPlateStyle {
+    type Error = u32;
+
+    fn try_from(value: u32) -> std::result::Result<Self, u32> {
+        let style = match value {
+            0 => NumberPlateStyle::BlueWhite,
+            1 => NumberPlateStyle::YellowBlack,
+            2 => NumberPlateStyle::YellowBlue,
+            3 => NumberPlateStyle::BlueWhite2,
+            4 => NumberPlateStyle::BlueWhite3,
+            5 => NumberPlateStyle::Yankton,
+            _ => return Err(value),
+        };
+
+        Ok(style)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::natives::{alt_IBaseObject_Type, alt_IMValue_Type};
+use altv_core::ecs::Entity;
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The entity has no alt:V object, it was removed already or never had one.
+    EntityNotFound(Entity),
+    /// The alt:V object isn't mapped to an entity, e.g. it was removed within the same tick.
+    ObjectNotFound,
+    /// The entity is another kind of object than the call needs.
+    WrongType {
+        entity: Entity,
+        expected: &'static str,
+    },
+    /// The core didn't create the object, e.g. for an unknown vehicle model.
+    CreationFailed,
+    /// The SDK has no components for this kind of object, such as web views.
+    UnsupportedType(alt_IBaseObject_Type),
+    /// The SDK can't convert this kind of value, such as functions.
+    UnsupportedValue(alt_IMValue_Type),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EntityNotFound(entity) => {
+                write!(f, "entity {} has no alt:V object", entity.id())
+            }
+            Error::ObjectNotFound => write!(f, "the alt:V object has no entity"),
+            Error::WrongType { entity, expected } => {
+                write!(f, "entity {} is not a {}", entity.id(), expected)
+            }
+            Error::CreationFailed => write!(f, "the core could not create the object"),
+            Error::UnsupportedType(object_type) => {
+                write!(f, "{} objects are not supported", type_name(*object_type))
+            }
+            Error::UnsupportedValue(value_type) => {
+                write!(f, "{:?} values are not supported", value_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn type_name(object_type: alt_IBaseObject_Type) -> &'static str {
+    match object_type {
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => "player",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => "vehicle",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => "blip",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_WEBVIEW => "web view",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => "voice channel",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => "collision shape",
+        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => "checkpoint",
+    }
+}
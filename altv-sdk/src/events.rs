@@ -2,28 +2,30 @@ use crate::mvalue::MValue;
 use crate::natives::alt_CEvent_Type;
 use crate::vector::Vector3;
 use altv_core::ecs::Entity;
+use std::sync::{Arc, Mutex};
 
+#[derive(Clone)]
 pub enum CEvent {
     None,
     PlayerConnect(CPlayerConnectEvent),
     PlayerDisconnect(CPlayerDisconnectEvent),
-    // ResourceStart(CResourceStartEvent),
-    // ResourceStop(CResourceStopEvent),
-    // ResourceError(CResourceErrorEvent),
+    ResourceStart(CResourceStartEvent),
+    ResourceStop(CResourceStopEvent),
+    ResourceError(CResourceErrorEvent),
     ServerScript(CServerScriptEvent),
     ClientScript(CClientScriptEvent),
-    // MetaChange(CMetaChangeEvent),
+    MetaChange(CMetaChangeEvent),
     SyncedMetaChange(CSyncedMetaChangeEvent),
     StreamSyncedMetaChange(CStreamSyncedMetaChangeEvent),
     GlobalMetaChange(CGlobalMetaChangeEvent),
     GlobalSyncedMetaChange(CGlobalSyncedMetaChangeEvent),
     PlayerDamage(CPlayerDamageEvent),
     PlayerDeath(CPlayerDeathEvent),
-    // FireEvent(CFireEvent),
+    FireEvent(CFireEvent),
     ExplosionEvent(CExplosionEvent),
     WeaponDamageEvent(CWeaponDamageEvent),
-    // VehicleDestroyEvent(CVehicleDestroyEvent),
-    // CheckpointEvent(CCheckpointEvent),
+    VehicleDestroyEvent(CVehicleDestroyEvent),
+    CheckpointEvent(CCheckpointEvent),
     CollisionShapeEvent(CCollisionShapeEvent),
     PlayerEnterVehicle(CPlayerEnterVehicleEvent),
     PlayerLeaveVehicle(CPlayerLeaveVehicleEvent),
@@ -34,17 +36,35 @@ pub enum CEvent {
 }
 
 impl CEvent {
+    pub fn was_cancelled(&self) -> bool {
+        self.get_cancellation()
+            .map(Cancellation::is_cancelled)
+            .unwrap_or(false)
+    }
+
+    /// Returns the cancellation of events the core lets resources cancel.
+    pub fn get_cancellation(&self) -> Option<&Cancellation> {
+        match self {
+            CEvent::PlayerConnect(e) => Some(&e.cancellation),
+            CEvent::FireEvent(e) => Some(&e.cancellation),
+            CEvent::ExplosionEvent(e) => Some(&e.cancellation),
+            CEvent::WeaponDamageEvent(e) => Some(&e.cancellation),
+            // The core ignores the cancellation of every other event.
+            _ => None,
+        }
+    }
+
     pub fn get_type(&self) -> u32 {
         (match *self {
             CEvent::None => alt_CEvent_Type::ALT_CEVENT_TYPE_NONE,
-            // CEvent::ResourceStart(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_START,
-            // CEvent::ResourceStop(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_STOP,
-            // CEvent::ResourceError(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_ERROR,
+            CEvent::ResourceStart(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_START,
+            CEvent::ResourceStop(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_STOP,
+            CEvent::ResourceError(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_ERROR,
             CEvent::PlayerConnect(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CONNECT,
             CEvent::PlayerDisconnect(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DISCONNECT,
             CEvent::ServerScript(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_SERVER_SCRIPT_EVENT,
             CEvent::ClientScript(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_CLIENT_SCRIPT_EVENT,
-            // CEvent::MetaChange(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_META_CHANGE,
+            CEvent::MetaChange(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_META_CHANGE,
             CEvent::SyncedMetaChange(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_SYNCED_META_CHANGE,
             CEvent::StreamSyncedMetaChange(_) => {
                 alt_CEvent_Type::ALT_CEVENT_TYPE_STREAM_SYNCED_META_CHANGE
@@ -55,11 +75,11 @@ impl CEvent {
             }
             CEvent::PlayerDamage(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DAMAGE,
             CEvent::PlayerDeath(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DEATH,
-            // CEvent::FireEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_FIRE_EVENT,
+            CEvent::FireEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_FIRE_EVENT,
             CEvent::ExplosionEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_EXPLOSION_EVENT,
             CEvent::WeaponDamageEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_WEAPON_DAMAGE_EVENT,
-            // CEvent::VehicleDestroyEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_VEHICLE_DESTROY,
-            // CEvent::CheckpointEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_CHECKPOINT_EVENT,
+            CEvent::VehicleDestroyEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_VEHICLE_DESTROY,
+            CEvent::CheckpointEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_CHECKPOINT_EVENT,
             CEvent::CollisionShapeEvent(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_COLSHAPE_EVENT,
             CEvent::PlayerEnterVehicle(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_ENTER_VEHICLE,
             CEvent::PlayerLeaveVehicle(_) => alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_LEAVE_VEHICLE,
@@ -75,14 +95,62 @@ impl CEvent {
     }
 }
 
+#[derive(Default)]
+struct CancellationState {
+    cancelled: bool,
+    reason: Option<String>,
+}
+
+/// Shared by an event and its dispatcher, so handlers can cancel an event they only see by value.
+///
+/// Only state and client event handlers run before the core gets the answer. Systems read their
+/// copy from an `EventChannel` on the next tick, so cancelling it there has no effect.
+#[derive(Clone, Default)]
+pub struct Cancellation(Arc<Mutex<CancellationState>>);
+
+impl Cancellation {
+    pub fn cancel(&self, reason: Option<String>) {
+        let mut state = self.0.lock().unwrap();
+        state.cancelled = true;
+
+        if reason.is_some() {
+            state.reason = reason;
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.lock().unwrap().cancelled
+    }
+
+    pub fn get_reason(&self) -> Option<String> {
+        self.0.lock().unwrap().reason.clone()
+    }
+}
+
+#[derive(Clone)]
 pub struct CPlayerConnectEvent {
     target: Entity,
     reason: String,
+    cancellation: Cancellation,
 }
 
 impl CPlayerConnectEvent {
     pub fn new(target: Entity, reason: String) -> Self {
-        CPlayerConnectEvent { target, reason }
+        CPlayerConnectEvent {
+            target,
+            reason,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    /// Rejects the connecting player, the server is told once every handler ran.
+    pub fn cancel(&self) {
+        self.cancellation.cancel(None);
+    }
+
+    /// Rejects the connecting player, `reason` is shown to them.
+    pub fn cancel_with_reason(&self, reason: &str) {
+        self.cancellation.cancel(Some(reason.to_owned()));
     }
 
     pub fn get_target(&self) -> Entity {
@@ -94,6 +162,7 @@ impl CPlayerConnectEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerDisconnectEvent {
     target: Entity,
     reason: String,
@@ -113,6 +182,55 @@ impl CPlayerDisconnectEvent {
     }
 }
 
+#[derive(Clone)]
+pub struct CResourceStartEvent {
+    resource: String,
+}
+
+impl CResourceStartEvent {
+    pub fn new(resource: String) -> Self {
+        CResourceStartEvent { resource }
+    }
+
+    /// Name of the resource.
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+}
+
+#[derive(Clone)]
+pub struct CResourceStopEvent {
+    resource: String,
+}
+
+impl CResourceStopEvent {
+    pub fn new(resource: String) -> Self {
+        CResourceStopEvent { resource }
+    }
+
+    /// Name of the resource.
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+}
+
+#[derive(Clone)]
+pub struct CResourceErrorEvent {
+    resource: String,
+}
+
+impl CResourceErrorEvent {
+    pub fn new(resource: String) -> Self {
+        CResourceErrorEvent { resource }
+    }
+
+    /// Name of the resource.
+    pub fn get_resource(&self) -> &str {
+        &self.resource
+    }
+}
+
+#[derive(Clone)]
 pub struct CServerScriptEvent {
     name: String,
     args: Vec<MValue>,
@@ -132,6 +250,7 @@ impl CServerScriptEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CClientScriptEvent {
     target: Entity,
     name: String,
@@ -156,6 +275,42 @@ impl CClientScriptEvent {
     }
 }
 
+#[derive(Clone)]
+pub struct CMetaChangeEvent {
+    target: Entity,
+    key: String,
+    value: MValue,
+    old_value: MValue,
+}
+
+impl CMetaChangeEvent {
+    pub fn new(target: Entity, key: String, value: MValue, old_value: MValue) -> Self {
+        CMetaChangeEvent {
+            target,
+            key,
+            value,
+            old_value,
+        }
+    }
+
+    pub fn get_target(&self) -> Entity {
+        self.target
+    }
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn get_value(&self) -> &MValue {
+        &self.value
+    }
+
+    pub fn get_old_value(&self) -> &MValue {
+        &self.old_value
+    }
+}
+
+#[derive(Clone)]
 pub struct CSyncedMetaChangeEvent {
     target: Entity,
     key: String,
@@ -190,6 +345,7 @@ impl CSyncedMetaChangeEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CStreamSyncedMetaChangeEvent {
     target: Entity,
     key: String,
@@ -224,6 +380,7 @@ impl CStreamSyncedMetaChangeEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CGlobalMetaChangeEvent {
     key: String,
     value: MValue,
@@ -252,6 +409,7 @@ impl CGlobalMetaChangeEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CGlobalSyncedMetaChangeEvent {
     key: String,
     value: MValue,
@@ -280,6 +438,7 @@ impl CGlobalSyncedMetaChangeEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerDamageEvent {
     target: Entity,
     attacker: Option<Entity>,
@@ -314,6 +473,7 @@ impl CPlayerDamageEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerDeathEvent {
     target: Entity,
     killer: Option<Entity>,
@@ -342,11 +502,63 @@ impl CPlayerDeathEvent {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FireInfo {
+    position: Vector3,
+    weapon: u32,
+}
+
+impl FireInfo {
+    pub fn new(position: Vector3, weapon: u32) -> Self {
+        FireInfo { position, weapon }
+    }
+
+    pub fn get_position(&self) -> Vector3 {
+        self.position
+    }
+
+    pub fn get_weapon(&self) -> u32 {
+        self.weapon
+    }
+}
+
+#[derive(Clone)]
+pub struct CFireEvent {
+    source: Entity,
+    fires: Vec<FireInfo>,
+    cancellation: Cancellation,
+}
+
+impl CFireEvent {
+    pub fn new(source: Entity, fires: Vec<FireInfo>) -> Self {
+        CFireEvent {
+            source,
+            fires,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    /// Stops the fires from being synced, the server is told once every handler ran.
+    pub fn cancel(&self) {
+        self.cancellation.cancel(None);
+    }
+
+    pub fn get_source(&self) -> Entity {
+        self.source
+    }
+
+    pub fn get_fires(&self) -> &Vec<FireInfo> {
+        &self.fires
+    }
+}
+
+#[derive(Clone)]
 pub struct CExplosionEvent {
     source: Entity,
     explosion_type: u8,
     position: Vector3,
     explosion_fx: u32,
+    cancellation: Cancellation,
 }
 
 impl CExplosionEvent {
@@ -356,9 +568,15 @@ impl CExplosionEvent {
             explosion_type,
             position,
             explosion_fx,
+            cancellation: Cancellation::default(),
         }
     }
 
+    /// Stops the explosion from being synced, the server is told once every handler ran.
+    pub fn cancel(&self) {
+        self.cancellation.cancel(None);
+    }
+
     pub fn get_source(&self) -> Entity {
         self.source
     }
@@ -376,6 +594,7 @@ impl CExplosionEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CWeaponDamageEvent {
     source: Entity,
     target: Option<Entity>,
@@ -383,6 +602,7 @@ pub struct CWeaponDamageEvent {
     damage: u16,
     shot_offset: Vector3,
     body_part: u8,
+    cancellation: Cancellation,
 }
 
 impl CWeaponDamageEvent {
@@ -401,9 +621,15 @@ impl CWeaponDamageEvent {
             damage,
             shot_offset,
             body_part,
+            cancellation: Cancellation::default(),
         }
     }
 
+    /// Stops the damage from being applied, the server is told once every handler ran.
+    pub fn cancel(&self) {
+        self.cancellation.cancel(None);
+    }
+
     pub fn get_source(&self) -> Entity {
         self.source
     }
@@ -429,6 +655,51 @@ impl CWeaponDamageEvent {
     }
 }
 
+#[derive(Clone)]
+pub struct CVehicleDestroyEvent {
+    target: Entity,
+}
+
+impl CVehicleDestroyEvent {
+    pub fn new(target: Entity) -> Self {
+        CVehicleDestroyEvent { target }
+    }
+
+    pub fn get_target(&self) -> Entity {
+        self.target
+    }
+}
+
+#[derive(Clone)]
+pub struct CCheckpointEvent {
+    target: Entity,
+    entity: Entity,
+    state: bool,
+}
+
+impl CCheckpointEvent {
+    pub fn new(target: Entity, entity: Entity, state: bool) -> Self {
+        CCheckpointEvent {
+            target,
+            entity,
+            state,
+        }
+    }
+
+    pub fn get_target(&self) -> Entity {
+        self.target
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    pub fn get_state(&self) -> bool {
+        self.state
+    }
+}
+
+#[derive(Clone)]
 pub struct CCollisionShapeEvent {
     target: Entity,
     entity: Entity,
@@ -457,6 +728,7 @@ impl CCollisionShapeEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerEnterVehicleEvent {
     target: Entity,
     player: Entity,
@@ -485,6 +757,7 @@ impl CPlayerEnterVehicleEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerLeaveVehicleEvent {
     target: Entity,
     player: Entity,
@@ -513,6 +786,7 @@ impl CPlayerLeaveVehicleEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CPlayerChangeVehicleSeatEvent {
     target: Entity,
     player: Entity,
@@ -547,6 +821,7 @@ impl CPlayerChangeVehicleSeatEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CRemoveEntityEvent {
     target: Entity,
 }
@@ -561,6 +836,7 @@ impl CRemoveEntityEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CDataNodeReceivedEvent {
     name: String,
     json: String,
@@ -580,6 +856,7 @@ impl CDataNodeReceivedEvent {
     }
 }
 
+#[derive(Clone)]
 pub struct CConsoleCommandEvent {
     name: String,
     args: Vec<String>,
@@ -0,0 +1,121 @@
+use crate::backend;
+use crate::natives::alt_CRefCountable;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+/// Refs dropped on another thread than the one that took them, with that thread.
+static QUEUED_RELEASES: Mutex<Vec<(ThreadId, usize)>> = Mutex::new(Vec::new());
+
+/// Releases the refs of handles that were dropped on other threads, such as in a task or a
+/// background thread. The core may only be called from the thread that took the ref.
+pub fn release_queued() {
+    let current = thread::current().id();
+    let released = {
+        let mut queued = QUEUED_RELEASES.lock().unwrap();
+        let (released, kept) = queued.drain(..).partition(|(thread, _)| *thread == current);
+        *queued = kept;
+        released
+    };
+
+    for (_, ref_countable) in released {
+        backend::instance().ref_countable_remove_ref(ref_countable as *mut alt_CRefCountable);
+    }
+}
+
+/// The ref a resource holds on an alt:V object, shared by every handle to it.
+struct ObjectRef {
+    ref_countable: AtomicPtr<alt_CRefCountable>,
+    valid: AtomicBool,
+    thread: ThreadId,
+}
+
+impl Drop for ObjectRef {
+    fn drop(&mut self) {
+        let ref_countable = self.ref_countable.load(Ordering::Relaxed);
+
+        if thread::current().id() == self.thread {
+            backend::instance().ref_countable_remove_ref(ref_countable);
+        } else {
+            QUEUED_RELEASES
+                .lock()
+                .unwrap()
+                .push((self.thread, ref_countable as usize));
+        }
+    }
+}
+
+/// Keeps an alt:V object alive, the ref is released once the last handle to it is dropped.
+///
+/// Handles can be moved to other threads, but the ref is only released on the thread that took
+/// it. If the last handle is dropped elsewhere, the release waits for `release_queued`.
+///
+/// The object stays allocated after it was removed from the game, `is_valid` tells the two apart.
+pub struct Handle<T> {
+    ptr: AtomicPtr<T>,
+    object: Arc<ObjectRef>,
+}
+
+impl Handle<alt_CRefCountable> {
+    pub(crate) fn new(ref_countable: *mut alt_CRefCountable) -> Self {
+        backend::instance().ref_countable_add_ref(ref_countable);
+
+        Handle {
+            ptr: AtomicPtr::new(ref_countable),
+            object: Arc::new(ObjectRef {
+                ref_countable: AtomicPtr::new(ref_countable),
+                valid: AtomicBool::new(true),
+                thread: thread::current().id(),
+            }),
+        }
+    }
+}
+
+impl<T> Handle<T> {
+    /// Returns whether the object is still part of the game, it turns false once it was removed.
+    pub fn is_valid(&self) -> bool {
+        self.object.valid.load(Ordering::Relaxed)
+    }
+
+    /// Returns the native object.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only kept alive by this handle, it must not be used after the handle was dropped
+    /// or be passed to `RemoveRef`.
+    pub unsafe fn as_ptr(&self) -> *mut T {
+        self.ptr()
+    }
+
+    pub(crate) fn ptr(&self) -> *mut T {
+        self.ptr.load(Ordering::Relaxed)
+    }
+
+    /// Creates a handle to another interface of the same object, sharing its ref.
+    pub(crate) fn cast<U>(&self, ptr: *mut U) -> Handle<U> {
+        Handle {
+            ptr: AtomicPtr::new(ptr),
+            object: self.object.clone(),
+        }
+    }
+
+    pub(crate) fn invalidate(&self) {
+        self.object.valid.store(false, Ordering::Relaxed);
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        self.cast(self.ptr())
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("ptr", &self.ptr())
+            .field("valid", &self.is_valid())
+            .finish()
+    }
+}
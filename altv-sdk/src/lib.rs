@@ -1,9 +1,12 @@
 use std::num::Wrapping;
 
 pub mod array;
+pub mod backend;
 pub mod core;
 pub mod elements;
+pub mod error;
 pub mod events;
+pub mod handle;
 pub mod mvalue;
 pub mod rgba;
 pub mod string;
@@ -19,6 +22,11 @@ pub mod log;
 #[allow(dead_code)]
 pub mod natives;
 
+#[allow(non_camel_case_types)]
+#[allow(non_snake_case)]
+pub mod natives_ext;
+
+pub use crate::error::{Error, Result};
 pub use nalgebra;
 
 pub fn hash(text: &str) -> u32 {
@@ -1,11 +1,7 @@
-use crate::natives::*;
-use crate::string_view::StringView;
+use crate::backend;
 
 pub fn info(msg: &str) {
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_LogInfo(core, Box::into_raw(Box::new(StringView::new(msg).into())));
-    }
+    backend::instance().log_info(msg);
 }
 #[macro_export]
 macro_rules! logi {
@@ -17,10 +13,7 @@ macro_rules! logi {
 }
 
 pub fn error(msg: &str) {
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_LogError(core, Box::into_raw(Box::new(StringView::new(msg).into())));
-    }
+    backend::instance().log_error(msg);
 }
 #[macro_export]
 macro_rules! loge {
@@ -32,10 +25,7 @@ macro_rules! loge {
 }
 
 pub fn warning(msg: &str) {
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_LogWarning(core, Box::into_raw(Box::new(StringView::new(msg).into())));
-    }
+    backend::instance().log_warning(msg);
 }
 #[macro_export]
 macro_rules! logw {
@@ -47,10 +37,7 @@ macro_rules! logw {
 }
 
 pub fn debug(msg: &str) {
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_LogDebug(core, Box::into_raw(Box::new(StringView::new(msg).into())));
-    }
+    backend::instance().log_debug(msg);
 }
 #[macro_export]
 macro_rules! logd {
@@ -62,10 +49,7 @@ macro_rules! logd {
 }
 
 pub fn colored(msg: &str) {
-    unsafe {
-        let core = alt_ICore_Instance();
-        alt_ICore_LogColored(core, Box::into_raw(Box::new(StringView::new(msg).into())));
-    }
+    backend::instance().log_colored(msg);
 }
 #[macro_export]
 macro_rules! logc {
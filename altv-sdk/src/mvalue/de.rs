@@ -0,0 +1,269 @@
+use super::{Error, MValue};
+use crate::rgba::Rgba;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Error as _, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Deserializes any `DeserializeOwned` value from an `MValue`.
+pub fn from_mvalue<T: DeserializeOwned>(value: MValue) -> Result<T, Error> {
+    T::deserialize(value)
+}
+
+/// Deserializes event arguments, the slice is read like a list so tuples and structs both work.
+pub fn from_args<T: DeserializeOwned>(args: &[MValue]) -> Result<T, Error> {
+    T::deserialize(MValue::List(args.to_vec()))
+}
+
+fn visit_list<'de, V: Visitor<'de>>(vec: Vec<MValue>, visitor: V) -> Result<V::Value, Error> {
+    let mut deserializer = SeqDeserializer::new(vec.into_iter());
+    let value = visitor.visit_seq(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+fn visit_dict<'de, V: Visitor<'de>>(
+    map: BTreeMap<String, MValue>,
+    visitor: V,
+) -> Result<V::Value, Error> {
+    let mut deserializer = MapDeserializer::new(map.into_iter());
+    let value = visitor.visit_map(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+impl<'de> de::Deserializer<'de> for MValue {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            MValue::None | MValue::Nil => visitor.visit_unit(),
+            MValue::Bool(v) => visitor.visit_bool(v),
+            MValue::Int(v) => visitor.visit_i64(v),
+            MValue::Uint(v) => visitor.visit_u64(v),
+            MValue::Double(v) => visitor.visit_f64(v),
+            MValue::String(v) => visitor.visit_string(v),
+            MValue::List(v) => visit_list(v, visitor),
+            MValue::Dict(v) => visit_dict(v, visitor),
+            MValue::BaseObject(_) => Err(Error::custom("base objects can't be deserialized")),
+            MValue::Vector3(v) => visit_list(
+                vec![
+                    MValue::Double(f64::from(v.x)),
+                    MValue::Double(f64::from(v.y)),
+                    MValue::Double(f64::from(v.z)),
+                ],
+                visitor,
+            ),
+            MValue::Rgba(v) => visit_list(
+                vec![
+                    MValue::Uint(u64::from(v.r)),
+                    MValue::Uint(u64::from(v.g)),
+                    MValue::Uint(u64::from(v.b)),
+                    MValue::Uint(u64::from(v.a)),
+                ],
+                visitor,
+            ),
+            MValue::ByteArray(v) => visitor.visit_byte_buf(v),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            MValue::None | MValue::Nil => visitor.visit_none(),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (variant, value) = match self {
+            MValue::String(variant) => (variant, None),
+            MValue::Dict(map) if map.len() == 1 => {
+                let (variant, value) = map.into_iter().next().unwrap();
+                (variant, Some(value))
+            }
+            value => {
+                return Err(Error::custom(format!(
+                    "expected a string or a dict with a single key for an enum, got {:?}",
+                    value
+                )))
+            }
+        };
+
+        visitor.visit_enum(EnumDeserializer { variant, value })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for MValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+struct EnumDeserializer {
+    variant: String,
+    value: Option<MValue>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = Error;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantDeserializer), Error> {
+        let variant = seed.deserialize(MValue::String(self.variant))?;
+        Ok((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+struct VariantDeserializer {
+    value: Option<MValue>,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantDeserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            None => Ok(()),
+            Some(value) => de::Deserialize::deserialize(value),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        match self.value {
+            Some(value) => seed.deserialize(value),
+            None => Err(Error::custom(
+                "expected a newtype variant, got a unit variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Some(MValue::List(v)) => visit_list(v, visitor),
+            _ => Err(Error::custom("expected a list for a tuple variant")),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Some(MValue::Dict(v)) => visit_dict(v, visitor),
+            _ => Err(Error::custom("expected a dict for a struct variant")),
+        }
+    }
+}
+
+struct MValueVisitor;
+
+impl<'de> Visitor<'de> for MValueVisitor {
+    type Value = MValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<MValue, E> {
+        Ok(MValue::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<MValue, E> {
+        Ok(MValue::Int(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<MValue, E> {
+        Ok(MValue::Uint(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<MValue, E> {
+        Ok(MValue::Double(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<MValue, E> {
+        Ok(MValue::String(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<MValue, E> {
+        Ok(MValue::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<MValue, E> {
+        Ok(MValue::ByteArray(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<MValue, E> {
+        Ok(MValue::ByteArray(v))
+    }
+
+    fn visit_none<E>(self) -> Result<MValue, E> {
+        Ok(MValue::Nil)
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<MValue, D::Error> {
+        de::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<MValue, E> {
+        Ok(MValue::Nil)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<MValue, A::Error> {
+        let mut vec = Vec::new();
+
+        while let Some(value) = seq.next_element()? {
+            vec.push(value);
+        }
+
+        Ok(MValue::List(vec))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<MValue, A::Error> {
+        let mut dict = BTreeMap::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            dict.insert(key, value);
+        }
+
+        Ok(MValue::Dict(dict))
+    }
+}
+
+impl<'de> de::Deserialize<'de> for MValue {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MValueVisitor)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Rgba {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (r, g, b, a) = de::Deserialize::deserialize(deserializer)?;
+        Ok(Rgba::new(r, g, b, a))
+    }
+}
@@ -0,0 +1,174 @@
+use super::{Error, MValue};
+use crate::rgba::Rgba;
+use crate::vector::Vector3;
+use altv_core::ecs::Entity;
+use serde::de::Error as _;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// Extracts a typed value out of a single `MValue`.
+pub trait FromMValue: Sized {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error>;
+}
+
+/// Extracts typed event arguments, implemented for tuples of `FromMValue` types.
+pub trait FromArgs: Sized {
+    fn from_args(args: &[MValue]) -> Result<Self, Error>;
+}
+
+fn invalid_type(expected: &str, value: &MValue) -> Error {
+    Error::custom(format!("expected {}, got {:?}", expected, value))
+}
+
+impl FromMValue for MValue {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        Ok(value.clone())
+    }
+}
+
+impl FromMValue for bool {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::Bool(v) => Ok(*v),
+            value => Err(invalid_type("bool", value)),
+        }
+    }
+}
+
+macro_rules! impl_from_mvalue_int {
+    ($($ty:ident)*) => {
+        $(
+            impl FromMValue for $ty {
+                fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+                    let v = match value {
+                        MValue::Int(v) => $ty::try_from(*v).ok(),
+                        MValue::Uint(v) => $ty::try_from(*v).ok(),
+                        value => return Err(invalid_type(stringify!($ty), value)),
+                    };
+
+                    v.ok_or_else(|| {
+                        Error::custom(format!("{:?} is out of range for {}", value, stringify!($ty)))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_mvalue_int!(i8 i16 i32 i64 u8 u16 u32 u64);
+
+impl FromMValue for f32 {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        f64::from_mvalue(value).map(|v| v as f32)
+    }
+}
+
+impl FromMValue for f64 {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::Double(v) => Ok(*v),
+            MValue::Int(v) => Ok(*v as f64),
+            MValue::Uint(v) => Ok(*v as f64),
+            value => Err(invalid_type("f64", value)),
+        }
+    }
+}
+
+impl FromMValue for String {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::String(v) => Ok(v.clone()),
+            value => Err(invalid_type("String", value)),
+        }
+    }
+}
+
+impl FromMValue for Entity {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::BaseObject(v) => Ok(*v),
+            value => Err(invalid_type("Entity", value)),
+        }
+    }
+}
+
+impl FromMValue for Vector3 {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::Vector3(v) => Ok(*v),
+            value => Err(invalid_type("Vector3", value)),
+        }
+    }
+}
+
+impl FromMValue for Rgba {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::Rgba(v) => Ok(*v),
+            value => Err(invalid_type("Rgba", value)),
+        }
+    }
+}
+
+impl<T: FromMValue> FromMValue for Option<T> {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::None | MValue::Nil => Ok(None),
+            value => T::from_mvalue(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromMValue> FromMValue for Vec<T> {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::List(v) => v.iter().map(T::from_mvalue).collect(),
+            value => Err(invalid_type("List", value)),
+        }
+    }
+}
+
+impl<T: FromMValue> FromMValue for BTreeMap<String, T> {
+    fn from_mvalue(value: &MValue) -> Result<Self, Error> {
+        match value {
+            MValue::Dict(v) => v
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), T::from_mvalue(value)?)))
+                .collect(),
+            value => Err(invalid_type("Dict", value)),
+        }
+    }
+}
+
+macro_rules! impl_from_args {
+    ($len:expr, $($ty:ident $index:tt)*) => {
+        impl<$($ty: FromMValue),*> FromArgs for ($($ty,)*) {
+            #[allow(unused_variables)]
+            fn from_args(args: &[MValue]) -> Result<Self, Error> {
+                if args.len() != $len {
+                    return Err(Error::custom(format!(
+                        "expected {} arguments, got {}",
+                        $len,
+                        args.len()
+                    )));
+                }
+
+                Ok(($(
+                    $ty::from_mvalue(&args[$index]).map_err(|e| {
+                        Error::custom(format!("argument {}: {}", $index, e))
+                    })?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_from_args!(0,);
+impl_from_args!(1, A 0);
+impl_from_args!(2, A 0 B 1);
+impl_from_args!(3, A 0 B 1 C 2);
+impl_from_args!(4, A 0 B 1 C 2 D 3);
+impl_from_args!(5, A 0 B 1 C 2 D 3 E 4);
+impl_from_args!(6, A 0 B 1 C 2 D 3 E 4 F 5);
+impl_from_args!(7, A 0 B 1 C 2 D 3 E 4 F 5 G 6);
+impl_from_args!(8, A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7);
@@ -0,0 +1,332 @@
+use crate::backend;
+use crate::elements;
+use crate::natives::*;
+use crate::rgba::Rgba;
+use crate::vector::Vector3;
+use altv_core::ecs::Entity;
+use altv_core::AltResource;
+use std::collections::BTreeMap;
+use std::fmt;
+
+mod de;
+mod error;
+mod from;
+mod ser;
+
+pub use de::{from_args, from_mvalue};
+pub use error::Error;
+pub use from::{FromArgs, FromMValue};
+pub use ser::{to_mvalue, Serializer};
+
+const VECTOR3: &str = "$altv::mvalue::Vector3";
+const RGBA: &str = "$altv::mvalue::Rgba";
+
+/// Maps a `Vector3` field to `MValue::Vector3`, use it with `#[serde(with = "altv_sdk::mvalue::vector3")]`.
+pub mod vector3 {
+    use super::VECTOR3;
+    use crate::vector::Vector3;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &Vector3, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(VECTOR3, &(v.x, v.y, v.z))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vector3, D::Error> {
+        let (x, y, z) = Deserialize::deserialize(deserializer)?;
+        Ok(Vector3::new(x, y, z))
+    }
+}
+
+// #[derive(FromPrimitive, ToPrimitive)]
+#[derive(Clone, PartialEq)]
+pub enum MValue {
+    None,
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Double(f64),
+    String(String),
+    List(Vec<MValue>),
+    Dict(BTreeMap<String, MValue>),
+    BaseObject(Entity),
+    // Function,
+    Vector3(Vector3),
+    Rgba(Rgba),
+    ByteArray(Vec<u8>),
+}
+
+impl MValue {
+    /// Reads a value of the core, base objects are resolved to the entities of `alt`.
+    pub fn new(alt: &AltResource, ptr: *mut alt_IMValue) -> crate::Result<MValue> {
+        let backend = backend::instance();
+
+        let value = match backend.mvalue_get_type(ptr) {
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_NONE => MValue::None,
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_NIL => MValue::Nil,
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_BOOL => MValue::Bool(backend.mvalue_get_bool(ptr)),
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_INT => MValue::Int(backend.mvalue_get_int(ptr)),
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_UINT => MValue::Uint(backend.mvalue_get_uint(ptr)),
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_DOUBLE => {
+                MValue::Double(backend.mvalue_get_double(ptr))
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_STRING => {
+                MValue::String(backend.mvalue_get_string(ptr))
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_LIST => {
+                let size = backend.mvalue_list_get_size(ptr);
+
+                let mut vec = Vec::with_capacity(size as usize);
+
+                for n in 0..size {
+                    vec.push(MValue::new(alt, backend.mvalue_list_get(ptr, n))?);
+                }
+
+                MValue::List(vec)
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_DICT => {
+                let mut map = BTreeMap::new();
+
+                for key in backend.mvalue_dict_get_keys(ptr) {
+                    let val = MValue::new(alt, backend.mvalue_dict_get(ptr, &key))?;
+                    map.insert(key, val);
+                }
+
+                MValue::Dict(map)
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_BASE_OBJECT => {
+                let base_obj = backend.mvalue_get_base_object(ptr);
+                MValue::BaseObject(elements::get_base_object_entity(alt, base_obj)?)
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_VECTOR3 => {
+                MValue::Vector3(backend.mvalue_get_vector3(ptr))
+            }
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_RGBA => MValue::Rgba(backend.mvalue_get_rgba(ptr)),
+            alt_IMValue_Type::ALT_IMVALUE_TYPE_BYTE_ARRAY => {
+                MValue::ByteArray(backend.mvalue_get_byte_array(ptr))
+            }
+            value_type => return Err(crate::Error::UnsupportedValue(value_type)),
+        };
+
+        Ok(value)
+    }
+
+    /// Creates the value in the core, entities are resolved to the base objects of `alt`.
+    pub fn into_ref(self, alt: &AltResource) -> crate::Result<*mut alt_RefBase_RefStore_IMValue> {
+        // Checked up front, so no containers are left half built in the core.
+        self.check_entities(alt)?;
+
+        self.create_ref(alt)
+    }
+
+    pub(crate) fn check_entities(&self, alt: &AltResource) -> crate::Result<()> {
+        match self {
+            MValue::List(v) => v.iter().try_for_each(|v| v.check_entities(alt)),
+            MValue::Dict(v) => v.values().try_for_each(|v| v.check_entities(alt)),
+            MValue::BaseObject(v) => elements::get_entity_base_object(alt, *v).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    fn create_ref(self, alt: &AltResource) -> crate::Result<*mut alt_RefBase_RefStore_IMValue> {
+        let backend = backend::instance();
+
+        let val = match self {
+            MValue::None => backend.create_mvalue_none(),
+            MValue::Nil => backend.create_mvalue_nil(),
+            MValue::Bool(v) => backend.create_mvalue_bool(v),
+            MValue::Int(v) => backend.create_mvalue_int(v),
+            MValue::Uint(v) => backend.create_mvalue_uint(v),
+            MValue::Double(v) => backend.create_mvalue_double(v),
+            MValue::String(v) => backend.create_mvalue_string(&v),
+            MValue::List(v) => {
+                let val = backend.create_mvalue_list(v.len() as u64);
+
+                for (n, v) in v.into_iter().enumerate() {
+                    backend.mvalue_list_set(val, n as u64, v.create_ref(alt)?);
+                }
+
+                val
+            }
+            MValue::Dict(v) => {
+                let val = backend.create_mvalue_dict();
+
+                for (key, v) in v {
+                    backend.mvalue_dict_set(val, &key, v.create_ref(alt)?);
+                }
+
+                val
+            }
+            MValue::BaseObject(v) => {
+                backend.create_mvalue_base_object(elements::get_entity_base_object(alt, v)?)
+            }
+            MValue::Vector3(v) => backend.create_mvalue_vector3(v),
+            MValue::Rgba(v) => backend.create_mvalue_rgba(v),
+            MValue::ByteArray(v) => backend.create_mvalue_byte_array(v),
+        };
+
+        Ok(val)
+    }
+
+    pub fn test() {
+        unsafe {
+            dbg!();
+            let core = alt_ICore_Instance();
+            dbg!();
+
+            let val = alt_ICore_CreateMValueRGBA_CAPI_Heap(
+                core,
+                Box::into_raw(Box::new(Rgba::new(53, 1, 12, 255).into())),
+            );
+            dbg!();
+            let val = alt_RefBase_RefStore_constIMValue_Get(
+                val as *mut alt_RefBase_RefStore_constIMValue,
+            );
+            dbg!(*val);
+            let val = alt_IMValue_to_alt_IMValueRGBA(val);
+            dbg!(alt_IMValueRGBA_GetType(val));
+            dbg!();
+            dbg!(*alt_IMValueRGBA_Value_CAPI_Heap(val));
+            dbg!();
+
+            // let val = dbg!(alt_ICore_CreateMValueNone(core));
+            // let val = dbg!(val as *mut alt_RefBase_RefStore_constIMValue);
+            // dbg!((*val).ptr.as_ref());
+
+            // let val = alt_ICore_CreateMValueString(core, Box::into_raw(Box::new(string::String::new("hii").into())));
+            // let val = alt_RefBase_RefStore_constIMValue_Get(val as *mut alt_RefBase_RefStore_constIMValue);
+            // dbg!(alt_IMValue_GetType(val));
+            // let val = alt_IMValue_to_alt_IMValueString(val);
+            // dbg!(StringView::from(*alt_IMValueString_Value(val)).get_data());
+
+            // let val = alt_ICore_CreateMValueInt_CAPI_Heap(core, 33);
+            // let val = alt_RefBase_RefStore_constIMValue_Get(
+            //     val as *mut alt_RefBase_RefStore_constIMValue,
+            // );
+            // dbg!(alt_IMValue_GetType(val));
+            // let val = alt_IMValue_to_alt_IMValueInt(val);
+            // dbg!(alt_IMValueInt_Value(val));
+
+            // let val = dbg!(alt_IMValue_to_alt_IMValueNone(val));
+        }
+    }
+}
+
+impl fmt::Debug for MValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MValue::None => write!(f, ""),
+            MValue::Nil => write!(f, ""),
+            MValue::Bool(v) => write!(f, "{}", v),
+            MValue::Int(v) => write!(f, "{}L", v),
+            MValue::Uint(v) => write!(f, "{}uL", v),
+            MValue::Double(v) => write!(f, "{:.1}", v),
+            MValue::String(ref v) => write!(f, "\"{}\"", *v),
+            MValue::List(ref v) => write!(f, "{:?}", *v),
+            MValue::Dict(ref v) => write!(f, "{:?}", *v),
+            MValue::BaseObject(ref v) => write!(f, "{:?}", *v),
+            MValue::Vector3(ref v) => write!(f, "{}", *v),
+            MValue::Rgba(ref v) => write!(f, "{}", *v),
+            MValue::ByteArray(ref v) => write!(f, "{:?}", *v),
+        }
+    }
+}
+
+impl fmt::Display for MValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MValue::None => write!(f, ""),
+            MValue::Nil => write!(f, ""),
+            MValue::Bool(v) => write!(f, "{}", v),
+            MValue::Int(v) => write!(f, "{}L", v),
+            MValue::Uint(v) => write!(f, "{}uL", v),
+            MValue::Double(v) => write!(f, "{:.1}", v),
+            MValue::String(ref v) => write!(f, "\"{}\"", *v),
+            MValue::List(ref v) => write!(f, "{:?}", *v),
+            MValue::Dict(ref v) => write!(f, "{:?}", *v),
+            MValue::BaseObject(ref v) => write!(f, "{:?}", *v),
+            MValue::Vector3(ref v) => write!(f, "{}", *v),
+            MValue::Rgba(ref v) => write!(f, "{}", *v),
+            MValue::ByteArray(ref v) => write!(f, "{:?}", *v),
+        }
+    }
+}
+
+impl From<MValue> for Option<()> {
+    fn from(v: MValue) -> Self {
+        match v {
+            MValue::None => Some(()),
+            MValue::Nil => Some(()),
+            _ => None,
+        }
+    }
+}
+
+impl From<MValue> for Option<bool> {
+    fn from(v: MValue) -> Self {
+        match v {
+            MValue::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for MValue {
+    fn from(v: bool) -> Self {
+        MValue::Bool(v)
+    }
+}
+
+impl From<i64> for MValue {
+    fn from(v: i64) -> Self {
+        MValue::Int(v)
+    }
+}
+
+impl From<u64> for MValue {
+    fn from(v: u64) -> Self {
+        MValue::Uint(v)
+    }
+}
+
+impl From<f64> for MValue {
+    fn from(v: f64) -> Self {
+        MValue::Double(v)
+    }
+}
+
+impl From<String> for MValue {
+    fn from(v: String) -> Self {
+        MValue::String(v)
+    }
+}
+
+impl From<Vector3> for MValue {
+    fn from(v: Vector3) -> Self {
+        MValue::Vector3(v)
+    }
+}
+
+impl From<Rgba> for MValue {
+    fn from(v: Rgba) -> Self {
+        MValue::Rgba(v)
+    }
+}
+
+impl From<BTreeMap<String, MValue>> for MValue {
+    fn from(v: BTreeMap<String, MValue>) -> Self {
+        MValue::Dict(v)
+    }
+}
+
+impl From<Entity> for MValue {
+    fn from(v: Entity) -> Self {
+        MValue::BaseObject(v)
+    }
+}
+
+impl From<Vec<u8>> for MValue {
+    fn from(v: Vec<u8>) -> Self {
+        MValue::ByteArray(v)
+    }
+}
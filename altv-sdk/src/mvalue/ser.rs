@@ -0,0 +1,367 @@
+use super::{Error, MValue, RGBA, VECTOR3};
+use crate::rgba::Rgba;
+use crate::vector::Vector3;
+use serde::ser::{self, Error as _, Serialize};
+use std::collections::BTreeMap;
+
+/// Serializes any `Serialize` value into an `MValue`.
+pub fn to_mvalue<T: Serialize + ?Sized>(value: &T) -> Result<MValue, Error> {
+    value.serialize(Serializer)
+}
+
+pub struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = MValue;
+    type Error = Error;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeTupleVariant;
+    type SerializeMap = SerializeDict;
+    type SerializeStruct = SerializeDict;
+    type SerializeStructVariant = SerializeStructVariant;
+
+    fn serialize_bool(self, v: bool) -> Result<MValue, Error> {
+        Ok(MValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<MValue, Error> {
+        Ok(MValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<MValue, Error> {
+        Ok(MValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<MValue, Error> {
+        Ok(MValue::Int(i64::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<MValue, Error> {
+        Ok(MValue::Int(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<MValue, Error> {
+        Ok(MValue::Uint(u64::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<MValue, Error> {
+        Ok(MValue::Uint(u64::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<MValue, Error> {
+        Ok(MValue::Uint(u64::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<MValue, Error> {
+        Ok(MValue::Uint(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<MValue, Error> {
+        Ok(MValue::Double(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<MValue, Error> {
+        Ok(MValue::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<MValue, Error> {
+        Ok(MValue::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<MValue, Error> {
+        Ok(MValue::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<MValue, Error> {
+        Ok(MValue::ByteArray(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<MValue, Error> {
+        Ok(MValue::Nil)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<MValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<MValue, Error> {
+        Ok(MValue::Nil)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<MValue, Error> {
+        Ok(MValue::Nil)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<MValue, Error> {
+        Ok(MValue::String(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<MValue, Error> {
+        let value = value.serialize(self)?;
+
+        match (name, value) {
+            (VECTOR3, MValue::List(v)) => match v.as_slice() {
+                [MValue::Double(x), MValue::Double(y), MValue::Double(z)] => Ok(MValue::Vector3(
+                    Vector3::new(*x as f32, *y as f32, *z as f32),
+                )),
+                _ => Err(Error::custom("expected three floats for a vector3")),
+            },
+            (RGBA, MValue::List(v)) => match v.as_slice() {
+                [MValue::Uint(r), MValue::Uint(g), MValue::Uint(b), MValue::Uint(a)] => Ok(
+                    MValue::Rgba(Rgba::new(*r as u8, *g as u8, *b as u8, *a as u8)),
+                ),
+                _ => Err(Error::custom("expected four bytes for a rgba")),
+            },
+            (_, value) => Ok(value),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<MValue, Error> {
+        let mut map = BTreeMap::new();
+        map.insert(variant.to_owned(), value.serialize(self)?);
+        Ok(MValue::Dict(map))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, Error> {
+        Ok(SerializeList {
+            vec: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTupleVariant, Error> {
+        Ok(SerializeTupleVariant {
+            variant,
+            vec: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeDict, Error> {
+        Ok(SerializeDict {
+            map: BTreeMap::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeDict, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeStructVariant, Error> {
+        Ok(SerializeStructVariant {
+            variant,
+            map: BTreeMap::new(),
+        })
+    }
+}
+
+pub struct SerializeList {
+    vec: Vec<MValue>,
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.vec.push(to_mvalue(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        Ok(MValue::List(self.vec))
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub struct SerializeTupleVariant {
+    variant: &'static str,
+    vec: Vec<MValue>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.vec.push(to_mvalue(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        let mut map = BTreeMap::new();
+        map.insert(self.variant.to_owned(), MValue::List(self.vec));
+        Ok(MValue::Dict(map))
+    }
+}
+
+pub struct SerializeDict {
+    map: BTreeMap<String, MValue>,
+    next_key: Option<String>,
+}
+
+impl ser::SerializeMap for SerializeDict {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        match to_mvalue(key)? {
+            MValue::String(key) => {
+                self.next_key = Some(key);
+                Ok(())
+            }
+            key => Err(Error::custom(format!(
+                "dict keys must be strings, got {:?}",
+                key
+            ))),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| Error::custom("serialize_value called before serialize_key"))?;
+
+        self.map.insert(key, to_mvalue(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        Ok(MValue::Dict(self.map))
+    }
+}
+
+impl ser::SerializeStruct for SerializeDict {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.map.insert(key.to_owned(), to_mvalue(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        Ok(MValue::Dict(self.map))
+    }
+}
+
+pub struct SerializeStructVariant {
+    variant: &'static str,
+    map: BTreeMap<String, MValue>,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant {
+    type Ok = MValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.map.insert(key.to_owned(), to_mvalue(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<MValue, Error> {
+        let mut map = BTreeMap::new();
+        map.insert(self.variant.to_owned(), MValue::Dict(self.map));
+        Ok(MValue::Dict(map))
+    }
+}
+
+impl Serialize for MValue {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MValue::None | MValue::Nil => serializer.serialize_unit(),
+            MValue::Bool(v) => serializer.serialize_bool(*v),
+            MValue::Int(v) => serializer.serialize_i64(*v),
+            MValue::Uint(v) => serializer.serialize_u64(*v),
+            MValue::Double(v) => serializer.serialize_f64(*v),
+            MValue::String(v) => serializer.serialize_str(v),
+            MValue::List(v) => v.serialize(serializer),
+            MValue::Dict(v) => v.serialize(serializer),
+            MValue::BaseObject(_) => Err(ser::Error::custom("base objects can't be serialized")),
+            MValue::Vector3(v) => super::vector3::serialize(v, serializer),
+            MValue::Rgba(v) => v.serialize(serializer),
+            MValue::ByteArray(v) => serializer.serialize_bytes(v),
+        }
+    }
+}
+
+impl Serialize for Rgba {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(RGBA, &(self.r, self.g, self.b, self.a))
+    }
+}
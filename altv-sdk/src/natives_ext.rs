@@ -0,0 +1,54 @@
+//! Layouts the generated C API doesn't expose, mirrored from the C++ SDK.
+
+use crate::natives::*;
+
+#[repr(C)]
+pub struct alt_CMetaChangeEvent {
+    pub vtable: *mut ::std::os::raw::c_void,
+    pub type_: alt_CEvent_Type,
+    pub cancelled: bool,
+    pub target: alt_RefBase_RefStore_IEntity,
+    pub key: alt_String,
+    pub val: alt_RefBase_RefStore_constIMValue,
+    pub oldVal: alt_RefBase_RefStore_constIMValue,
+}
+
+#[repr(C)]
+pub struct alt_CFireEvent_FireInfo {
+    pub position: alt_Vector_float_3_PointLayout,
+    pub weaponHash: ::std::os::raw::c_uint,
+}
+
+#[repr(C)]
+pub struct alt_Array_CFireEvent_FireInfo {
+    pub data: *mut alt_CFireEvent_FireInfo,
+    pub size: ::std::os::raw::c_ulonglong,
+    pub capacity: ::std::os::raw::c_ulonglong,
+}
+
+#[repr(C)]
+pub struct alt_CFireEvent {
+    pub vtable: *mut ::std::os::raw::c_void,
+    pub type_: alt_CEvent_Type,
+    pub cancelled: bool,
+    pub source: alt_RefBase_RefStore_IPlayer,
+    pub fires: alt_Array_CFireEvent_FireInfo,
+}
+
+#[repr(C)]
+pub struct alt_CCheckpointEvent {
+    pub vtable: *mut ::std::os::raw::c_void,
+    pub type_: alt_CEvent_Type,
+    pub cancelled: bool,
+    // The generated `alt_RefBase_RefStore_ICheckpoint` is opaque, a ref store is only the pointer.
+    pub target: *mut alt_ICheckpoint,
+    pub entity: alt_RefBase_RefStore_IEntity,
+    pub state: bool,
+}
+
+#[repr(C)]
+pub struct alt_Array_RefBase_RefStore_IEntity_Layout {
+    pub data: *mut alt_RefBase_RefStore_IEntity,
+    pub size: ::std::os::raw::c_ulonglong,
+    pub capacity: ::std::os::raw::c_ulonglong,
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use altv_core::ecs::{World, WorldExt};
+    use altv_core::AltResource;
+    use altv_sdk::backend::{self, MockCore};
+    use altv_sdk::elements::*;
+    use altv_sdk::mvalue::MValue;
+    use altv_sdk::rgba::Rgba;
+    use altv_sdk::vector::{Rotation3, Vector3};
+    use altv_sdk::Error;
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    fn round_trip(alt: &AltResource, value: MValue) -> altv_sdk::Result<MValue> {
+        let ptr = value.into_ref(alt)?;
+        MValue::new(alt, backend::instance().mvalue_ref_get(ptr))
+    }
+
+    fn setup() {
+        backend::set_instance(Rc::new(MockCore::new()));
+    }
+
+    #[test]
+    fn primitives() {
+        setup();
+        let alt = AltResource::default();
+
+        let values = vec![
+            MValue::None,
+            MValue::Nil,
+            MValue::Bool(true),
+            MValue::Int(i64::MIN),
+            MValue::Uint(u64::MAX),
+            MValue::Double(0.5),
+            MValue::String("test".to_owned()),
+            MValue::Vector3(Vector3::new(1.0, 2.0, 3.0)),
+            MValue::Rgba(Rgba::new(0, 64, 128, 255)),
+            MValue::ByteArray(vec![0, 64, 128, 255]),
+        ];
+
+        for value in values {
+            assert_eq!(round_trip(&alt, value.clone()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn list() {
+        setup();
+        let alt = AltResource::default();
+
+        let value = MValue::List(vec![MValue::Int(0), MValue::Int(1), MValue::Int(2)]);
+        assert_eq!(round_trip(&alt, value.clone()), Ok(value));
+
+        assert_eq!(
+            round_trip(&alt, MValue::List(vec![])),
+            Ok(MValue::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn nested_list() {
+        setup();
+        let alt = AltResource::default();
+
+        let value = MValue::List(vec![MValue::List(vec![
+            MValue::Bool(false),
+            MValue::Bool(true),
+            MValue::List(vec![MValue::Int(0)]),
+        ])]);
+        assert_eq!(round_trip(&alt, value.clone()), Ok(value));
+    }
+
+    #[test]
+    fn mixed_list() {
+        setup();
+        let alt = AltResource::default();
+
+        let mut dict = BTreeMap::new();
+        dict.insert("position".to_owned(), MValue::Vector3(Vector3::zeros()));
+        dict.insert(
+            "tags".to_owned(),
+            MValue::List(vec![MValue::String("a".to_owned()), MValue::Nil]),
+        );
+
+        let value = MValue::List(vec![
+            MValue::String("SERVER".to_owned()),
+            MValue::Uint(1),
+            MValue::Dict(dict),
+            MValue::ByteArray(vec![1, 2, 3]),
+        ]);
+        assert_eq!(round_trip(&alt, value.clone()), Ok(value));
+    }
+
+    #[test]
+    fn base_object() {
+        setup();
+
+        let mut world = World::new();
+        world.insert(AltResource::default());
+        world.register::<CRefCountable>();
+        world.register::<CBaseObject>();
+        world.register::<CWorldObject>();
+        world.register::<CEntity>();
+        world.register::<CVehicle>();
+
+        let vehicle = create_vehicle(
+            &world,
+            altv_sdk::hash("tyrant"),
+            Vector3::zeros(),
+            Rotation3::identity(),
+        )
+        .unwrap();
+
+        let value = MValue::List(vec![MValue::BaseObject(vehicle), MValue::Int(1)]);
+        let ptr = {
+            let alt = world.read_resource::<AltResource>();
+            assert_eq!(round_trip(&alt, value.clone()), Ok(value.clone()));
+            value.into_ref(&alt).unwrap()
+        };
+
+        delete(&mut world, vehicle).unwrap();
+
+        let alt = world.read_resource::<AltResource>();
+        assert_eq!(
+            round_trip(&alt, MValue::BaseObject(vehicle)),
+            Err(Error::EntityNotFound(vehicle))
+        );
+        assert_eq!(
+            MValue::new(&alt, backend::instance().mvalue_ref_get(ptr)),
+            Err(Error::ObjectNotFound)
+        );
+    }
+}
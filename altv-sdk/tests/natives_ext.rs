@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use altv_sdk::core::SDK_VERSION;
+    use altv_sdk::natives::*;
+    use altv_sdk::natives_ext::*;
+    use std::mem::{offset_of, size_of};
+
+    // The mirrors follow the C++ SDK of this version, check them again when it changes.
+    #[test]
+    fn sdk_version() {
+        assert_eq!(SDK_VERSION, 36);
+    }
+
+    #[test]
+    fn meta_change_event_layout() {
+        assert_eq!(size_of::<alt_CMetaChangeEvent>(), 56);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, type_), 8);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, cancelled), 12);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, target), 16);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, key), 24);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, val), 40);
+        assert_eq!(offset_of!(alt_CMetaChangeEvent, oldVal), 48);
+
+        // Same members as the synced meta event, which the generated bindings do cover.
+        assert_eq!(
+            size_of::<alt_CMetaChangeEvent>(),
+            size_of::<alt_CSyncedMetaDataChangeEvent>()
+        );
+        assert_eq!(
+            offset_of!(alt_CMetaChangeEvent, oldVal),
+            offset_of!(alt_CSyncedMetaDataChangeEvent, oldVal)
+        );
+    }
+
+    #[test]
+    fn fire_event_layout() {
+        assert_eq!(size_of::<alt_CFireEvent_FireInfo>(), 16);
+        assert_eq!(offset_of!(alt_CFireEvent_FireInfo, weaponHash), 12);
+
+        assert_eq!(size_of::<alt_Array_CFireEvent_FireInfo>(), 24);
+        assert_eq!(offset_of!(alt_Array_CFireEvent_FireInfo, size), 8);
+        assert_eq!(offset_of!(alt_Array_CFireEvent_FireInfo, capacity), 16);
+
+        assert_eq!(size_of::<alt_CFireEvent>(), 48);
+        assert_eq!(offset_of!(alt_CFireEvent, source), 16);
+        assert_eq!(offset_of!(alt_CFireEvent, fires), 24);
+    }
+
+    #[test]
+    fn checkpoint_event_layout() {
+        assert_eq!(size_of::<alt_CCheckpointEvent>(), 40);
+        assert_eq!(offset_of!(alt_CCheckpointEvent, target), 16);
+        assert_eq!(offset_of!(alt_CCheckpointEvent, entity), 24);
+        assert_eq!(offset_of!(alt_CCheckpointEvent, state), 32);
+
+        // Same members as the collision shape event, which the generated bindings do cover.
+        assert_eq!(
+            size_of::<alt_CCheckpointEvent>(),
+            size_of::<alt_CColShapeEvent>()
+        );
+        assert_eq!(
+            offset_of!(alt_CCheckpointEvent, state),
+            offset_of!(alt_CColShapeEvent, state)
+        );
+    }
+
+    #[test]
+    fn entity_array_layout() {
+        assert_eq!(size_of::<alt_Array_RefBase_RefStore_IEntity_Layout>(), 24);
+        assert_eq!(
+            offset_of!(alt_Array_RefBase_RefStore_IEntity_Layout, size),
+            8
+        );
+        assert_eq!(
+            offset_of!(alt_Array_RefBase_RefStore_IEntity_Layout, capacity),
+            16
+        );
+    }
+}
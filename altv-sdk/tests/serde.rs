@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use altv_sdk::mvalue::{self, MValue};
+    use altv_sdk::rgba::Rgba;
+    use altv_sdk::vector::Vector3;
+    use serde::{Deserialize, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Team {
+        Red,
+        Blue,
+        Custom(String),
+        Mixed { red: u8, blue: u8 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Spawn {
+        name: String,
+        #[serde(with = "mvalue::vector3")]
+        position: Vector3,
+        color: Rgba,
+        team: Team,
+        weapons: Vec<u32>,
+        vehicle: Option<String>,
+    }
+
+    struct Bytes(Vec<u8>);
+
+    impl Serialize for Bytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    fn spawn() -> Spawn {
+        Spawn {
+            name: "airport".to_owned(),
+            position: Vector3::new(1.0, 2.0, 71.2),
+            color: Rgba::new(0, 64, 128, 255),
+            team: Team::Mixed { red: 1, blue: 2 },
+            weapons: vec![0x1B06D571, 0x83BF0278],
+            vehicle: None,
+        }
+    }
+
+    #[test]
+    fn struct_to_dict() {
+        let mut team = BTreeMap::new();
+        team.insert("red".to_owned(), MValue::Uint(1));
+        team.insert("blue".to_owned(), MValue::Uint(2));
+
+        let mut mixed = BTreeMap::new();
+        mixed.insert("Mixed".to_owned(), MValue::Dict(team));
+
+        let mut dict = BTreeMap::new();
+        dict.insert("name".to_owned(), MValue::String("airport".to_owned()));
+        dict.insert(
+            "position".to_owned(),
+            MValue::Vector3(Vector3::new(1.0, 2.0, 71.2)),
+        );
+        dict.insert("color".to_owned(), MValue::Rgba(Rgba::new(0, 64, 128, 255)));
+        dict.insert("team".to_owned(), MValue::Dict(mixed));
+        dict.insert(
+            "weapons".to_owned(),
+            MValue::List(vec![MValue::Uint(0x1B06D571), MValue::Uint(0x83BF0278)]),
+        );
+        dict.insert("vehicle".to_owned(), MValue::Nil);
+
+        assert_eq!(mvalue::to_mvalue(&spawn()), Ok(MValue::Dict(dict)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = mvalue::to_mvalue(&spawn()).unwrap();
+        assert_eq!(mvalue::from_mvalue::<Spawn>(value), Ok(spawn()));
+
+        for team in [Team::Red, Team::Blue, Team::Custom("green".to_owned())] {
+            let value = mvalue::to_mvalue(&team).unwrap();
+            assert_eq!(mvalue::from_mvalue::<Team>(value), Ok(team));
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(
+            mvalue::to_mvalue(&Bytes(vec![0, 64, 128, 255])),
+            Ok(MValue::ByteArray(vec![0, 64, 128, 255]))
+        );
+    }
+
+    #[test]
+    fn args() {
+        let args = vec![
+            MValue::String("/veh tyrant".to_owned()),
+            MValue::Int(3),
+            MValue::Vector3(Vector3::new(1.0, 2.0, 3.0)),
+        ];
+
+        let (msg, seat, position): (String, u8, [f32; 3]) = mvalue::from_args(&args).unwrap();
+        assert_eq!(msg, "/veh tyrant");
+        assert_eq!(seat, 3);
+        assert_eq!(position, [1.0, 2.0, 3.0]);
+
+        assert!(mvalue::from_args::<(String, u8)>(&args[..1]).is_err());
+        assert!(mvalue::from_args::<(u8,)>(&args[..1]).is_err());
+    }
+}
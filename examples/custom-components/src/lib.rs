@@ -3,7 +3,7 @@ use altv::ecs::{Component, ReadStorage, VecStorage, WriteStorage};
 use altv::game_data::{GameData, GameDataBuilder, StateData};
 use altv::sdk::elements::CPlayer;
 use altv::sdk::events::*;
-use altv::state::State;
+use altv::state::{State, Trans};
 use std::error::Error;
 use std::time::Instant;
 
@@ -18,18 +18,20 @@ impl Component for CCreationInfo {
 pub struct GameState;
 
 impl State for GameState {
-    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) {
+    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) -> Trans {
         match &event {
             CEvent::PlayerConnect(event) => {
                 // add the component for the player entity
                 data.world
                     .exec(|mut ccreation_infos: WriteStorage<CCreationInfo>| {
-                        ccreation_infos.insert(
-                            event.get_target(),
-                            CCreationInfo {
-                                joined_at: Instant::now(),
-                            },
-                        ).unwrap();
+                        ccreation_infos
+                            .insert(
+                                event.get_target(),
+                                CCreationInfo {
+                                    joined_at: Instant::now(),
+                                },
+                            )
+                            .unwrap();
                     });
             }
             CEvent::PlayerDisconnect(event) => {
@@ -54,14 +56,17 @@ impl State for GameState {
             }
             _ => {}
         };
+
+        Trans::None
     }
 }
 
-#[no_mangle]
-pub fn main(core: usize) -> Result<CoreApplication, Box<dyn Error>> {
+fn main() -> Result<CoreApplication, Box<dyn Error>> {
     let game_data_builder = GameDataBuilder::new();
-    let application = ApplicationBuilder::new(core, Box::new(GameState))
+    let application = ApplicationBuilder::new(Box::new(GameState))
         .register::<CCreationInfo>() // register the component
         .build(game_data_builder);
     Ok(application)
 }
+
+altv::resource!(main);
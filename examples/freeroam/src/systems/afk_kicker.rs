@@ -1,45 +1,115 @@
-use altv::ecs::{Join, ReadStorage, System, WriteStorage};
+use altv::core::AltResource;
+use altv::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use altv::schedule::Schedule;
 use altv::sdk::elements::*;
 use altv::sdk::mvalue::MValue;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
-pub struct AfkKicker {
-    last_check: Instant,
-}
+pub const SCHEDULE: Schedule = Schedule::Interval(Duration::from_secs(300));
+
+pub struct AfkKicker;
 
 impl AfkKicker {
     pub fn new() -> Self {
-        AfkKicker {
-            last_check: Instant::now(),
-        }
+        AfkKicker
     }
 }
 
 impl<'a> System<'a> for AfkKicker {
     type SystemData = (
+        Read<'a, AltResource>,
         WriteStorage<'a, CBaseObject>,
         ReadStorage<'a, CWorldObject>,
         WriteStorage<'a, CPlayer>,
     );
 
-    fn run(&mut self, (mut cbase_objs, cworld_objs, mut cplayers): Self::SystemData) {
-        if self.last_check.elapsed() >= Duration::from_secs(300) {
-            for (cbase_obj, cworld_obj, cplayer) in
-                (&mut cbase_objs, &cworld_objs, &mut cplayers).join()
-            {
-                let last_position = cbase_obj.get_meta_data("lastPos");
-                let current_position = cworld_obj.get_position();
-
-                if let MValue::Vector3(last_position) = last_position {
-                    if last_position.metric_distance(&current_position) < 0.5 {
-                        cplayer.kick("");
-                    }
-                } else {
-                    cbase_obj.set_meta_data("lastPos", MValue::Vector3(current_position.clone()))
+    fn run(&mut self, (alt, mut cbase_objs, cworld_objs, mut cplayers): Self::SystemData) {
+        for (cbase_obj, cworld_obj, cplayer) in
+            (&mut cbase_objs, &cworld_objs, &mut cplayers).join()
+        {
+            let current_position = cworld_obj.get_position();
+
+            if let Ok(MValue::Vector3(last_position)) = cbase_obj.get_meta_data(&alt, "lastPos") {
+                if last_position.metric_distance(&current_position) < 0.5 {
+                    cplayer.kick("");
+                    continue;
                 }
             }
 
-            self.last_check = Instant::now();
+            if let Err(e) =
+                cbase_obj.set_meta_data(&alt, "lastPos", MValue::Vector3(current_position))
+            {
+                altv::sdk::log::error(format!("Could not store the last position: {}", e).as_str());
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+    use altv::app::ApplicationBuilder;
+    use altv::ecs::WorldExt;
+    use altv::game_data::GameDataBuilder;
+    use altv::sdk::backend::{self, MockCore};
+    use altv::sdk::vector::Vector3;
+    use altv::simulator::Simulator;
+    use std::rc::Rc;
+
+    fn is_connected(simulator: &Simulator, player: altv::ecs::Entity) -> bool {
+        simulator
+            .world()
+            .read_storage::<CPlayer>()
+            .get(player)
+            .unwrap()
+            .is_connected()
+    }
+
+    fn last_position(simulator: &Simulator, player: altv::ecs::Entity) -> Option<Vector3> {
+        let world = simulator.world();
+        let alt = world.read_resource::<AltResource>();
+
+        match world
+            .read_storage::<CBaseObject>()
+            .get(player)
+            .unwrap()
+            .get_meta_data(&alt, "lastPos")
+        {
+            Ok(MValue::Vector3(position)) => Some(position),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn kicks_idle_players() {
+        let core = Rc::new(MockCore::new());
+        backend::set_instance(core.clone());
+
+        let application = ApplicationBuilder::with_backend(core.clone(), Box::new(GameState))
+            .build(GameDataBuilder::new().with_scheduled(AfkKicker::new(), SCHEDULE));
+        let mut simulator = Simulator::new(core, application);
+        simulator.start();
+
+        let idle = simulator.connect_player("idle");
+        let active = simulator.connect_player("active");
+
+        simulator.run_for(Duration::from_secs(300), Duration::from_secs(60));
+        assert!(is_connected(&simulator, idle));
+        assert!(is_connected(&simulator, active));
+
+        simulator.move_entity(active, Vector3::new(100.0, 0.0, 71.2));
+        simulator.advance(Duration::from_secs(300));
+
+        assert!(!is_connected(&simulator, idle));
+        assert!(is_connected(&simulator, active));
+        assert_eq!(
+            last_position(&simulator, active),
+            Some(Vector3::new(100.0, 0.0, 71.2))
+        );
+
+        // Every sample updates the last position, so a player who stops moving is kicked too.
+        simulator.advance(Duration::from_secs(300));
+        assert!(!is_connected(&simulator, active));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::get_direction_from_rotation;
+use altv::core::AltResource;
+use altv::ecs::{
+    Entity, LazyUpdate, Read, ReadStorage, System, SystemData, World, WorldExt, WriteStorage,
+};
+use altv::event_channel::{EventChannel, ReaderId};
+use altv::sdk::elements::*;
+use altv::sdk::events::CClientScriptEvent;
+use altv::sdk::mvalue::MValue;
+use altv::sdk::vector::Rotation3;
+use std::str::FromStr;
+
+fn send_message(alt: &AltResource, cplayer: &mut CPlayer, msg: String) {
+    let args = [MValue::String("SERVER".to_owned()), MValue::String(msg)];
+
+    if let Err(e) = cplayer.emit(alt, "chatmessage", &args) {
+        altv::sdk::log::error(format!("Could not send a chat message: {}", e).as_str());
+    }
+}
+
+pub struct ChatCommands {
+    reader: Option<ReaderId<CClientScriptEvent>>,
+}
+
+impl ChatCommands {
+    pub fn new() -> Self {
+        ChatCommands { reader: None }
+    }
+
+    fn spawn_vehicle(
+        lazy: &LazyUpdate,
+        target: Entity,
+        model: String,
+        cworld_obj: &CWorldObject,
+        centity: &CEntity,
+    ) {
+        let dimension = cworld_obj.get_dimension();
+        let position = cworld_obj.get_position();
+        let rotation = centity.get_rotation();
+
+        // Creating the vehicle needs the world, so it happens once the system ran.
+        lazy.exec(move |world| {
+            let euler = rotation.euler_angles();
+
+            let vehicle = create_vehicle(
+                world,
+                altv::sdk::hash(&model),
+                position + get_direction_from_rotation(rotation) * 3.0,
+                Rotation3::from_euler_angles(0.0, 0.0, -euler.2 + std::f32::consts::PI / 2.0),
+            );
+
+            let msg = if let Ok(vehicle) = vehicle {
+                let mut cworld_objs = world.write_storage::<CWorldObject>();
+                let mut cvehicles = world.write_storage::<CVehicle>();
+                let cworld_obj = cworld_objs.get_mut(vehicle).unwrap();
+                let cvehicle = cvehicles.get_mut(vehicle).unwrap();
+
+                cworld_obj.set_dimension(dimension);
+
+                cvehicle.set_primary_color(5);
+                cvehicle.set_secondary_color(7);
+
+                cvehicle.set_numberplate_text("RUST");
+
+                format!("{{00FF00}}Spawned vehicle {}.", &model)
+            } else {
+                format!("{{FF0000}}Could not find vehicle with model {}.", &model)
+            };
+
+            if let Some(cplayer) = world.write_storage::<CPlayer>().get_mut(target) {
+                send_message(&world.read_resource::<AltResource>(), cplayer, msg);
+            }
+        });
+    }
+}
+
+impl<'a> System<'a> for ChatCommands {
+    type SystemData = (
+        Read<'a, EventChannel<CClientScriptEvent>>,
+        Read<'a, LazyUpdate>,
+        Read<'a, AltResource>,
+        WriteStorage<'a, CPlayer>,
+        ReadStorage<'a, CWorldObject>,
+        ReadStorage<'a, CEntity>,
+    );
+
+    fn run(&mut self, (events, lazy, alt, mut cplayers, cworld_objs, centities): Self::SystemData) {
+        for event in events.read(self.reader.as_mut().unwrap()) {
+            if event.get_name() != "chatmessage" {
+                continue;
+            }
+
+            let msg = match event.get_args().first() {
+                Some(MValue::String(msg)) if msg.starts_with('/') => msg,
+                _ => continue,
+            };
+
+            let target = event.get_target();
+            let command: Vec<_> = msg[1..].split_ascii_whitespace().collect();
+
+            let (cplayer, cworld_obj, centity) = match (
+                cplayers.get_mut(target),
+                cworld_objs.get(target),
+                centities.get(target),
+            ) {
+                (Some(cplayer), Some(cworld_obj), Some(centity)) => (cplayer, cworld_obj, centity),
+                _ => continue,
+            };
+
+            match command.first().copied() {
+                Some("pos") => {
+                    let position = cworld_obj.get_position();
+
+                    send_message(
+                        &alt,
+                        cplayer,
+                        format!(
+                            "Position: [X: {}, Y: {}, Z: {}] | Dimension: {}",
+                            position.x,
+                            position.y,
+                            position.z,
+                            cworld_obj.get_dimension()
+                        ),
+                    );
+                }
+                Some("rot") => {
+                    let euler = centity.get_rotation().euler_angles();
+
+                    send_message(
+                        &alt,
+                        cplayer,
+                        format!(
+                            "Rotation: [Roll: {} Pitch: {} Yaw: {}]",
+                            euler.0, euler.1, euler.2
+                        ),
+                    );
+                }
+                Some("veh") => {
+                    if let Some(model) = command.get(1) {
+                        Self::spawn_vehicle(
+                            &lazy,
+                            target,
+                            (*model).to_owned(),
+                            cworld_obj,
+                            centity,
+                        );
+                    }
+                }
+                Some("weapon") => {
+                    let model = command.get(1);
+                    let ammo = command.get(2);
+
+                    if let Some(model) = model {
+                        if let Some(ammo) = ammo {
+                            if let Ok(ammo) = i32::from_str(ammo) {
+                                cplayer.give_weapon(altv::sdk::hash(model), ammo, true);
+                                send_message(
+                                    &alt,
+                                    cplayer,
+                                    format!(
+                                        "{{00FF00}}Spawned weapon {} with {} ammunition.",
+                                        *model, ammo
+                                    ),
+                                );
+                            } else {
+                                send_message(
+                                    &alt,
+                                    cplayer,
+                                    format!("{{00FF00}}Invalid ammunition {}.", ammo),
+                                );
+                            }
+                        } else {
+                            cplayer.give_weapon(altv::sdk::hash(model), i32::MAX, true);
+                            send_message(
+                                &alt,
+                                cplayer,
+                                format!("{{00FF00}}Spawned weapon {}.", *model),
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<CClientScriptEvent>>()
+                .register_reader(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+    use altv::app::ApplicationBuilder;
+    use altv::game_data::GameDataBuilder;
+    use altv::sdk::backend::{self, MockCore};
+    use altv::sdk::vector::Vector3;
+    use altv::simulator::{SentClientEvent, Simulator};
+    use std::rc::Rc;
+
+    #[test]
+    fn position_command() {
+        let core = Rc::new(MockCore::new());
+        backend::set_instance(core.clone());
+
+        let application = ApplicationBuilder::with_backend(core.clone(), Box::new(GameState))
+            .build(GameDataBuilder::new().with_thread_local(ChatCommands::new()));
+        let mut simulator = Simulator::new(core, application);
+        simulator.start();
+
+        let player = simulator.connect_player("rust");
+        simulator.move_entity(player, Vector3::new(1.0, 2.0, 3.0));
+        simulator.take_client_events();
+
+        simulator.client_script_event(
+            player,
+            "chatmessage",
+            vec![MValue::String("/pos".to_owned())],
+        );
+        assert!(simulator.take_client_events().is_empty());
+
+        simulator.tick();
+        assert_eq!(
+            simulator.take_client_events(),
+            vec![SentClientEvent {
+                target: Some(player),
+                name: "chatmessage".to_owned(),
+                args: vec![
+                    MValue::String("SERVER".to_owned()),
+                    MValue::String("Position: [X: 1, Y: 2, Z: 3] | Dimension: 0".to_owned()),
+                ],
+            }]
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod afk_kicker;
+pub mod chat_commands;
+pub mod respawn;
 pub mod weather_sync;
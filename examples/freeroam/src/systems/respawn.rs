@@ -0,0 +1,84 @@
+use altv::ecs::{Read, ReadStorage, System, SystemData, World, WriteStorage};
+use altv::event_channel::{EventChannel, ReaderId};
+use altv::sdk::elements::*;
+use altv::sdk::events::CPlayerDeathEvent;
+use std::time::Duration;
+
+pub struct Respawn {
+    reader: Option<ReaderId<CPlayerDeathEvent>>,
+}
+
+impl Respawn {
+    pub fn new() -> Self {
+        Respawn { reader: None }
+    }
+}
+
+impl<'a> System<'a> for Respawn {
+    type SystemData = (
+        Read<'a, EventChannel<CPlayerDeathEvent>>,
+        WriteStorage<'a, CPlayer>,
+        ReadStorage<'a, CWorldObject>,
+    );
+
+    fn run(&mut self, (events, mut cplayers, cworld_objs): Self::SystemData) {
+        for event in events.read(self.reader.as_mut().unwrap()) {
+            let target = event.get_target();
+
+            if let (Some(cplayer), Some(cworld_obj)) =
+                (cplayers.get_mut(target), cworld_objs.get(target))
+            {
+                cplayer.spawn(cworld_obj.get_position(), Duration::from_secs(0));
+                altv::sdk::log::info(format!("Revived player {}.", cplayer.get_name()).as_str());
+            }
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<CPlayerDeathEvent>>()
+                .register_reader(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+    use altv::app::ApplicationBuilder;
+    use altv::ecs::WorldExt;
+    use altv::game_data::GameDataBuilder;
+    use altv::sdk::backend::{self, MockCore};
+    use altv::simulator::Simulator;
+    use std::rc::Rc;
+
+    #[test]
+    fn revives_dead_players() {
+        let core = Rc::new(MockCore::new());
+        backend::set_instance(core.clone());
+
+        let application = ApplicationBuilder::with_backend(core.clone(), Box::new(GameState))
+            .build(GameDataBuilder::new().with_thread_local(Respawn::new()));
+        let mut simulator = Simulator::new(core, application);
+        simulator.start();
+
+        let player = simulator.connect_player("rust");
+        let health = |simulator: &Simulator| {
+            simulator
+                .world()
+                .read_storage::<CPlayer>()
+                .get(player)
+                .unwrap()
+                .get_health()
+        };
+
+        simulator.damage_player(player, None, 150, 0);
+        assert_eq!(health(&simulator), 50);
+
+        simulator.tick();
+        assert_eq!(health(&simulator), 200);
+    }
+}
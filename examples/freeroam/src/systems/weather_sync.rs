@@ -1,46 +1,104 @@
-use altv::ecs::{Join, System, WriteStorage};
+use altv::ecs::{Join, Read, System, Write, WriteStorage};
+use altv::schedule::Schedule;
 use altv::sdk::elements::*;
 use rand::Rng;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
-pub struct WeatherSync {
-    last_sync: Instant,
-    last_weather_update: Instant,
-    current_weather: u32,
+pub const SYNC_SCHEDULE: Schedule = Schedule::Hz(1.0);
+pub const CYCLE_SCHEDULE: Schedule = Schedule::Interval(Duration::from_secs(1800));
+
+#[derive(Default)]
+pub struct Weather(u32);
+
+/// Picks a new random weather.
+pub struct WeatherCycle;
+
+impl WeatherCycle {
+    pub fn new() -> Self {
+        WeatherCycle
+    }
 }
 
+impl<'a> System<'a> for WeatherCycle {
+    type SystemData = Write<'a, Weather>;
+
+    fn run(&mut self, mut weather: Self::SystemData) {
+        let mut rng = rand::thread_rng();
+        weather.0 = rng.gen_range(0, 10);
+
+        altv::sdk::log::info(&format!("[WeatherSync] Change weather to {}", weather.0));
+    }
+}
+
+/// Sends the current weather and time to every player.
+pub struct WeatherSync;
+
 impl WeatherSync {
     pub fn new() -> Self {
-        WeatherSync {
-            last_sync: Instant::now(),
-            last_weather_update: Instant::now(),
-            current_weather: 0,
-        }
+        WeatherSync
     }
 }
 
 impl<'a> System<'a> for WeatherSync {
-    type SystemData = WriteStorage<'a, CPlayer>;
-
-    fn run(&mut self, mut cplayers: Self::SystemData) {
-        if self.last_weather_update.elapsed() >= Duration::from_secs(1800) {
-            let mut rng = rand::thread_rng();
-            self.current_weather = rng.gen_range(0, 10);
-            self.last_weather_update = Instant::now();
-
-            altv::sdk::log::info(&format!(
-                "[WeatherSync] Change weather to {}",
-                self.current_weather
-            ));
+    type SystemData = (Read<'a, Weather>, WriteStorage<'a, CPlayer>);
+
+    fn run(&mut self, (weather, mut cplayers): Self::SystemData) {
+        for cplayer in (&mut cplayers).join() {
+            cplayer.set_date_time(1, 1, 1970, 12, 0, 0);
+            cplayer.set_weather(weather.0);
         }
+    }
+}
 
-        if self.last_sync.elapsed() >= Duration::from_secs(1) {
-            for cplayer in (&mut cplayers).join() {
-                cplayer.set_date_time(1, 1, 1970, 12, 0, 0);
-                cplayer.set_weather(self.current_weather);
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+    use altv::app::ApplicationBuilder;
+    use altv::ecs::WorldExt;
+    use altv::game_data::GameDataBuilder;
+    use altv::sdk::backend::mock::LogLevel;
+    use altv::sdk::backend::{self, MockCore};
+    use altv::sdk::mvalue::MValue;
+    use altv::simulator::Simulator;
+    use std::rc::Rc;
 
-            self.last_sync = Instant::now();
-        }
+    #[test]
+    fn syncs_weather_every_second() {
+        let core = Rc::new(MockCore::new());
+        backend::set_instance(core.clone());
+
+        let application = ApplicationBuilder::with_backend(core.clone(), Box::new(GameState))
+            .build(
+                GameDataBuilder::new()
+                    .with_scheduled(WeatherCycle::new(), CYCLE_SCHEDULE)
+                    .with_scheduled(WeatherSync::new(), SYNC_SCHEDULE),
+            );
+        let mut simulator = Simulator::new(core.clone(), application);
+        simulator.start();
+
+        let player = simulator.connect_player("rust");
+        let handle = simulator
+            .world()
+            .read_storage::<CPlayer>()
+            .get(player)
+            .unwrap()
+            .handle()
+            .clone();
+        let player = unsafe { handle.as_ptr() };
+
+        simulator.advance(Duration::from_millis(500));
+        assert_eq!(core.get_property(player, "weather"), None);
+
+        simulator.advance(Duration::from_millis(500));
+        assert_eq!(core.get_property(player, "weather"), Some(MValue::Uint(0)));
+        assert!(core.get_property(player, "date_time").is_some());
+
+        simulator.advance(Duration::from_secs(1800));
+        assert!(core
+            .get_logs()
+            .iter()
+            .any(|(level, msg)| *level == LogLevel::Info
+                && msg.starts_with("[WeatherSync] Change weather to")));
     }
 }
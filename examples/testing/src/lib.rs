@@ -7,20 +7,21 @@ use altv::sdk::events::*;
 use altv::sdk::mvalue::MValue;
 use altv::sdk::rgba::Rgba;
 use altv::sdk::vector::{Rotation3, Vector3};
-use altv::state::State;
+use altv::state::{State, Trans};
+use std::collections::BTreeMap;
 use std::error::Error;
 
 pub struct GameState;
 
 impl State for GameState {
-    fn on_start(&mut self, data: StateData<GameData>) {
+    fn on_start(&mut self, data: StateData<GameData>) -> Trans {
         assert!(altv::sdk::elements::create_vehicle(
             data.world,
             0,
             Vector3::new(0.0, 0.0, 0.0),
             Rotation3::from_euler_angles(0.0, 0.0, 0.0)
         )
-        .is_none());
+        .is_err());
 
         let vehicle = altv::sdk::elements::create_vehicle(
             data.world,
@@ -28,7 +29,7 @@ impl State for GameState {
             Vector3::new(0.0, 0.0, 0.0),
             Rotation3::from_euler_angles(0.0, 0.0, 0.0),
         );
-        assert!(vehicle.is_some());
+        assert!(vehicle.is_ok());
 
         let vehicle = vehicle.unwrap();
         assert!(data.world.is_alive(vehicle));
@@ -61,29 +62,33 @@ impl State for GameState {
                 let cvehicle = cvehicle.unwrap();
 
                 assert_cref(cref);
-                assert_cbase_obj(cbase_obj);
+                assert_cbase_obj(cbase_obj, &alt);
                 assert_cworld_obj(cworld_obj);
-                assert_centity(centity);
+                assert_centity(centity, &alt);
                 assert_cvehicle(cvehicle, &alt);
             },
         );
 
-        dbg!(altv::sdk::elements::delete(data.world, vehicle));
+        if let Err(e) = altv::sdk::elements::delete(data.world, vehicle) {
+            altv::sdk::log::error(format!("Could not delete the vehicle: {}", e).as_str());
+        }
         assert!(!data.world.is_alive(vehicle));
 
         let colshape = altv::sdk::elements::create_collision_shape_sphere(
             data.world,
             Vector3::new(0.0, 0.0, 71.2),
             5.0,
-        );
+        )
+        .unwrap();
         assert!(data.world.is_alive(colshape));
 
         data.world.exec(
-            |(mut crefs, mut cbase_objs, mut cworld_objs, mut ccolshapes): (
+            |(mut crefs, mut cbase_objs, mut cworld_objs, mut ccolshapes, alt): (
                 WriteStorage<CRefCountable>,
                 WriteStorage<CBaseObject>,
                 WriteStorage<CWorldObject>,
                 WriteStorage<CCollisionShape>,
+                Read<AltResource>,
             )| {
                 let cref = crefs.get_mut(colshape);
                 let cbase_obj = cbase_objs.get_mut(colshape);
@@ -101,17 +106,21 @@ impl State for GameState {
                 let ccolshape = ccolshape.unwrap();
 
                 assert_cref(cref);
-                assert_cbase_obj(cbase_obj);
+                assert_cbase_obj(cbase_obj, &alt);
                 assert_cworld_obj(cworld_obj);
                 assert_ccolshape(ccolshape, Vector3::new(0.0, 0.0, 71.2), 5.0);
             },
         );
 
-        dbg!(altv::sdk::elements::delete(data.world, colshape));
+        if let Err(e) = altv::sdk::elements::delete(data.world, colshape) {
+            altv::sdk::log::error(format!("Could not delete the collision shape: {}", e).as_str());
+        }
         assert!(!data.world.is_alive(colshape));
+
+        Trans::None
     }
 
-    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) {
+    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) -> Trans {
         match &event {
             CEvent::PlayerConnect(event) => {
                 let target = event.get_target();
@@ -152,97 +161,153 @@ impl State for GameState {
                         let cplayer = cplayer.unwrap();
 
                         assert_cref(cref);
-                        assert_cbase_obj(cbase_obj);
+                        assert_cbase_obj(cbase_obj, &alt);
                         assert_cworld_obj(cworld_obj);
-                        assert_centity(centity);
+                        assert_centity(centity, &alt);
                         assert_cplayer(cplayer, &alt);
                     },
                 );
 
-                altv::sdk::elements::delete(data.world, target);
+                altv::sdk::elements::delete(data.world, target).unwrap();
                 assert!(data.world.is_alive(target));
             }
             _ => {}
         }
+
+        Trans::None
     }
 }
 
-#[no_mangle]
-pub fn main(core: usize) -> Result<CoreApplication, Box<dyn Error>> {
+fn main() -> Result<CoreApplication, Box<dyn Error>> {
     let game_data_builder = GameDataBuilder::new();
-    let application = ApplicationBuilder::new(core, Box::new(GameState)).build(game_data_builder);
+    let application = ApplicationBuilder::new(Box::new(GameState)).build(game_data_builder);
     Ok(application)
 }
 
+altv::resource!(main);
+
 fn assert_cref(cref: &mut CRefCountable) {
     let ref_cnt = cref.get_ref_count();
     cref.add_ref();
     assert_eq!(ref_cnt + 1, cref.get_ref_count());
-    cref.remove_ref();
+    unsafe { cref.remove_ref() };
     assert_eq!(ref_cnt, cref.get_ref_count());
 }
 
-fn assert_cbase_obj(cbase_obj: &mut CBaseObject) {
+fn assert_cbase_obj(cbase_obj: &mut CBaseObject, alt: &AltResource) {
     assert!(!cbase_obj.has_meta_data("test"));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::None);
-    cbase_obj.set_meta_data("test", MValue::Nil);
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::None));
+    cbase_obj.set_meta_data(alt, "test", MValue::Nil).unwrap();
     assert!(cbase_obj.has_meta_data("test"));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Nil);
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::Nil));
     cbase_obj.delete_meta_data("test");
     assert!(!cbase_obj.has_meta_data("test"));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::None);
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::None));
 
-    cbase_obj.set_meta_data("test", MValue::Bool(false));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Bool(false));
-
-    cbase_obj.set_meta_data("test", MValue::Bool(true));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Bool(true));
-
-    cbase_obj.set_meta_data("test", MValue::Int(0));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Int(0));
-    cbase_obj.set_meta_data("test", MValue::Int(std::i64::MIN));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Int(std::i64::MIN));
-    cbase_obj.set_meta_data("test", MValue::Int(std::i64::MAX));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Int(std::i64::MAX));
-
-    cbase_obj.set_meta_data("test", MValue::Uint(std::u64::MIN));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Uint(std::u64::MIN));
-    cbase_obj.set_meta_data("test", MValue::Uint(std::u64::MAX));
-    assert_eq!(cbase_obj.get_meta_data("test"), MValue::Uint(std::u64::MAX));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Bool(false))
+        .unwrap();
+    assert_eq!(
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Bool(false))
+    );
 
-    cbase_obj.set_meta_data("test", MValue::Double(std::f64::MIN));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Bool(true))
+        .unwrap();
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::Bool(true)));
+
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Int(0))
+        .unwrap();
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::Int(0)));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Int(std::i64::MIN))
+        .unwrap();
     assert_eq!(
-        cbase_obj.get_meta_data("test"),
-        MValue::Double(std::f64::MIN)
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Int(std::i64::MIN))
     );
-    cbase_obj.set_meta_data("test", MValue::Double(std::f64::MAX));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Int(std::i64::MAX))
+        .unwrap();
     assert_eq!(
-        cbase_obj.get_meta_data("test"),
-        MValue::Double(std::f64::MAX)
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Int(std::i64::MAX))
     );
 
-    cbase_obj.set_meta_data("test", MValue::String("test".to_owned()));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Uint(std::u64::MIN))
+        .unwrap();
+    assert_eq!(
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Uint(std::u64::MIN))
+    );
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Uint(std::u64::MAX))
+        .unwrap();
     assert_eq!(
-        cbase_obj.get_meta_data("test"),
-        MValue::String("test".to_owned())
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Uint(std::u64::MAX))
     );
 
-    // cbase_obj.set_meta_data("test", MValue::List(vec![MValue::List(vec![MValue::Bool(false), MValue::Bool(true), MValue::List(vec![MValue::Int(0)])])]));
-    // assert_eq!(cbase_obj.get_meta_data("test"), MValue::List(vec![MValue::List(vec![MValue::Bool(false), MValue::Bool(true), MValue::List(vec![MValue::Int(0)])])]));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Double(std::f64::MIN))
+        .unwrap();
+    assert_eq!(
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Double(std::f64::MIN))
+    );
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Double(std::f64::MAX))
+        .unwrap();
+    assert_eq!(
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Double(std::f64::MAX))
+    );
 
-    // cbase_obj.set_meta_data("test", MValue::Vector3(Vector3::new(0.0, std::f32::MIN, std::f32::MAX)));
-    // assert_eq!(cbase_obj.get_meta_data("test"), MValue::Vector3(Vector3::new(0.0, std::f32::MIN, std::f32::MAX))); // fails
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::String("test".to_owned()))
+        .unwrap();
+    assert_eq!(
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::String("test".to_owned()))
+    );
 
-    cbase_obj.set_meta_data("test", MValue::Rgba(Rgba::new(0, 64, 128, 255)));
+    let mut dict = BTreeMap::new();
+    dict.insert("bool".to_owned(), MValue::Bool(true));
+    dict.insert("string".to_owned(), MValue::String("test".to_owned()));
+    dict.insert("rgba".to_owned(), MValue::Rgba(Rgba::new(0, 64, 128, 255)));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Dict(dict.clone()))
+        .unwrap();
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::Dict(dict)));
+
+    let list = MValue::List(vec![MValue::List(vec![
+        MValue::Bool(false),
+        MValue::Bool(true),
+        MValue::List(vec![MValue::Int(0)]),
+    ])]);
+    cbase_obj.set_meta_data(alt, "test", list.clone()).unwrap();
+    assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(list));
+
+    // cbase_obj.set_meta_data(alt, "test", MValue::Vector3(Vector3::new(0.0, std::f32::MIN, std::f32::MAX))).unwrap();
+    // assert_eq!(cbase_obj.get_meta_data(alt, "test"), Ok(MValue::Vector3(Vector3::new(0.0, std::f32::MIN, std::f32::MAX)))); // fails
+
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::Rgba(Rgba::new(0, 64, 128, 255)))
+        .unwrap();
     assert_eq!(
-        cbase_obj.get_meta_data("test"),
-        MValue::Rgba(Rgba::new(0, 64, 128, 255))
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::Rgba(Rgba::new(0, 64, 128, 255)))
     );
 
-    cbase_obj.set_meta_data("test", MValue::ByteArray(vec![0, 64, 128, 255]));
+    cbase_obj
+        .set_meta_data(alt, "test", MValue::ByteArray(vec![0, 64, 128, 255]))
+        .unwrap();
     assert_eq!(
-        cbase_obj.get_meta_data("test"),
-        MValue::ByteArray(vec![0, 64, 128, 255])
+        cbase_obj.get_meta_data(alt, "test"),
+        Ok(MValue::ByteArray(vec![0, 64, 128, 255]))
     );
 }
 
@@ -256,28 +321,41 @@ fn assert_cworld_obj(cworld_obj: &mut CWorldObject) {
     assert_eq!(cworld_obj.get_position(), Vector3::new(1.0, 1.0, 71.2));
 }
 
-fn assert_centity(centity: &mut CEntity) {
+fn assert_centity(centity: &mut CEntity, alt: &AltResource) {
     // centity.set_rotation(Rotation3::from_euler_angles(0.0, 1.0, 2.0));
     // assert_eq!(centity.get_rotation(), Rotation3::from_euler_angles(0.0, 1.0, 2.0));
 
     altv::sdk::log::error("asserting");
     assert!(!centity.has_synced_meta_data("test"));
-    assert_eq!(centity.get_synced_meta_data("test"), MValue::None);
-    centity.set_synced_meta_data("test", MValue::Nil);
+    assert_eq!(centity.get_synced_meta_data(alt, "test"), Ok(MValue::None));
+    centity
+        .set_synced_meta_data(alt, "test", MValue::Nil)
+        .unwrap();
     assert!(centity.has_synced_meta_data("test"));
-    assert_eq!(centity.get_synced_meta_data("test"), MValue::Nil);
+    assert_eq!(centity.get_synced_meta_data(alt, "test"), Ok(MValue::Nil));
     centity.delete_synced_meta_data("test");
     assert!(!centity.has_synced_meta_data("test"));
-    assert_eq!(centity.get_synced_meta_data("test"), MValue::None);
+    assert_eq!(centity.get_synced_meta_data(alt, "test"), Ok(MValue::None));
 
     assert!(!centity.has_stream_synced_meta_data("test"));
-    assert_eq!(centity.get_stream_synced_meta_data("test"), MValue::None);
-    centity.set_stream_synced_meta_data("test", MValue::Nil);
+    assert_eq!(
+        centity.get_stream_synced_meta_data(alt, "test"),
+        Ok(MValue::None)
+    );
+    centity
+        .set_stream_synced_meta_data(alt, "test", MValue::Nil)
+        .unwrap();
     assert!(centity.has_stream_synced_meta_data("test"));
-    assert_eq!(centity.get_stream_synced_meta_data("test"), MValue::Nil);
+    assert_eq!(
+        centity.get_stream_synced_meta_data(alt, "test"),
+        Ok(MValue::Nil)
+    );
     centity.delete_stream_synced_meta_data("test");
     assert!(!centity.has_stream_synced_meta_data("test"));
-    assert_eq!(centity.get_stream_synced_meta_data("test"), MValue::None);
+    assert_eq!(
+        centity.get_stream_synced_meta_data(alt, "test"),
+        Ok(MValue::None)
+    );
 }
 
 fn assert_cplayer(cplayer: &mut CPlayer, alt: &AltResource) {
@@ -361,18 +439,18 @@ fn assert_cvehicle(cvehicle: &mut CVehicle, alt: &AltResource) {
     cvehicle.set_neon_color(Rgba::new(1, 2, 3, 255));
     assert_eq!(cvehicle.get_neon_color(), Rgba::new(0, 0, 0, 0));
 
-    for n in 0..std::u8::MAX {
-        cvehicle.set_window_tint(n);
-        assert_eq!(cvehicle.get_window_tint(), n);
+    for window_tint in &[WindowTint::None, WindowTint::Limo, WindowTint::Green] {
+        cvehicle.set_window_tint(*window_tint);
+        assert_eq!(cvehicle.get_window_tint(), Some(*window_tint));
     }
 
-    for n in 0..5 {
-        cvehicle.set_license_plate_index(n);
-        assert_eq!(cvehicle.get_license_plate_index(), n);
+    for style in &[NumberPlateStyle::BlueWhite, NumberPlateStyle::Yankton] {
+        cvehicle.set_numberplate_style(*style);
+        assert_eq!(cvehicle.get_numberplate_style(), Some(*style));
     }
 
-    cvehicle.set_license_plate_text("RUST");
-    assert_eq!(cvehicle.get_license_plate_text(), "RUST".to_owned());
+    cvehicle.set_numberplate_text("RUST");
+    assert_eq!(cvehicle.get_numberplate_text(), "RUST".to_owned());
 
     cvehicle.set_engine_on(true);
     assert!(cvehicle.is_engine_on());
@@ -392,8 +470,8 @@ fn assert_cvehicle(cvehicle: &mut CVehicle, alt: &AltResource) {
     }
 
     for n in 0..1000 {
-        cvehicle.set_fuel_tank_health(n);
-        assert_eq!(cvehicle.get_fuel_tank_health(), n);
+        cvehicle.set_petrol_tank_health(n);
+        assert_eq!(cvehicle.get_petrol_tank_health(), n);
     }
 
     for n in 0..std::u8::MAX {
@@ -431,3 +509,32 @@ fn assert_ccolshape(ccolshape: &mut CCollisionShape, position: Vector3, radius:
     assert!(!ccolshape.is_point_in(position.clone() + Vector3::new(0.0, radius + 1.0, 0.0)));
     assert!(!ccolshape.is_point_in(position.clone() + Vector3::new(0.0, 0.0, radius + 1.0)));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use altv::sdk::backend::MockCore;
+    use std::rc::Rc;
+
+    #[test]
+    fn game_state() {
+        let core = Rc::new(MockCore::new());
+        let mut application = ApplicationBuilder::with_backend(core.clone(), Box::new(GameState))
+            .build(GameDataBuilder::new());
+        application.start();
+
+        let player = core.create_player("rust");
+        application.create_game_object(player);
+
+        let target = {
+            let alt = application.world().read_resource::<AltResource>();
+            *alt.players.values().next().unwrap()
+        };
+
+        application.dispatch_event(CEvent::PlayerConnect(CPlayerConnectEvent::new(
+            target,
+            String::new(),
+        )));
+        application.tick();
+    }
+}
@@ -1,38 +1,119 @@
-use crate::core::ecs::{Builder, Component, Entity, World, WorldExt};
+use crate::client_event::ClientEventHandlers;
+use crate::core::ecs::{Component, Entity, World, WorldExt};
 use crate::core::AltResource;
-use crate::game_data::{DataInit, GameData, StateData};
+use crate::event_channel;
+use crate::game_data::{DataInit, GameData};
+use crate::reload::ReloadHook;
+use crate::rpc::{self, Procedures, Rpc};
+use crate::scheduler::{self, Scheduler};
+use crate::sdk::array::convert_array_to_vec_mvalue;
+use crate::sdk::backend::{self, CoreBackend, NativeCore};
+use crate::sdk::core;
 use crate::sdk::elements::{
-    CBaseObject, CBlip, CCheckpoint, CCollisionShape, CEntity, CPlayer, CRefCountable, CVehicle,
-    CVoiceChannel, CWorldObject,
+    get_entity, insert_base_object, remove_base_object, CBaseObject, CBlip, CCheckpoint,
+    CCollisionShape, CEntity, CPlayer, CRefCountable, CVehicle, CVoiceChannel, CWorldObject,
 };
 use crate::sdk::events::{
-    CClientScriptEvent, CCollisionShapeEvent, CConsoleCommandEvent, CDataNodeReceivedEvent, CEvent,
-    CExplosionEvent, CGlobalMetaChangeEvent, CGlobalSyncedMetaChangeEvent,
-    CPlayerChangeVehicleSeatEvent, CPlayerConnectEvent, CPlayerDamageEvent, CPlayerDeathEvent,
-    CPlayerDisconnectEvent, CPlayerEnterVehicleEvent, CPlayerLeaveVehicleEvent, CRemoveEntityEvent,
-    CServerScriptEvent, CStreamSyncedMetaChangeEvent, CSyncedMetaChangeEvent, CWeaponDamageEvent,
+    CCheckpointEvent, CClientScriptEvent, CCollisionShapeEvent, CConsoleCommandEvent,
+    CDataNodeReceivedEvent, CEvent, CExplosionEvent, CFireEvent, CGlobalMetaChangeEvent,
+    CGlobalSyncedMetaChangeEvent, CMetaChangeEvent, CPlayerChangeVehicleSeatEvent,
+    CPlayerConnectEvent, CPlayerDamageEvent, CPlayerDeathEvent, CPlayerDisconnectEvent,
+    CPlayerEnterVehicleEvent, CPlayerLeaveVehicleEvent, CRemoveEntityEvent, CResourceErrorEvent,
+    CResourceStartEvent, CResourceStopEvent, CServerScriptEvent, CStreamSyncedMetaChangeEvent,
+    CSyncedMetaChangeEvent, CVehicleDestroyEvent, CWeaponDamageEvent, FireInfo,
 };
-use crate::sdk::mvalue::MValue;
+use crate::sdk::handle;
+use crate::sdk::mvalue::{FromArgs, MValue};
 use crate::sdk::natives::*;
+use crate::sdk::natives_ext::*;
 use crate::sdk::string_view::StringView;
 use crate::sdk::vector::Vector3;
-use crate::state::State;
+use crate::sdk::{Error as SdkError, Result as SdkResult};
+use crate::state::{State, StateMachine};
+use crate::task::Executor;
+use crate::time::Time;
+use std::collections::HashMap;
 use std::error::Error;
-use std::sync::atomic::AtomicPtr;
+use std::panic;
+use std::rc::Rc;
+
+pub type MainResult = Result<CoreApplication, Box<dyn Error>>;
+
+/// Signature of the `altv_main` symbol exported by `resource!`, the result is boxed to cross the C ABI.
+pub type ResourceMainFn = unsafe extern "C" fn(core: *mut alt_ICore) -> *mut MainResult;
+
+/// Exports the entry points of a resource library, `main` builds its application.
+///
+/// ```ignore
+/// fn main() -> Result<CoreApplication, Box<dyn Error>> {
+///     Ok(ApplicationBuilder::new(Box::new(GameState)).build(GameDataBuilder::new()))
+/// }
+///
+/// altv::resource!(main);
+/// ```
+#[macro_export]
+macro_rules! resource {
+    ($main:path) => {
+        #[no_mangle]
+        pub extern "C" fn altv_rs_build_info() -> $crate::version::BuildInfo {
+            $crate::version::BuildInfo::current()
+        }
+
+        #[no_mangle]
+        pub extern "C" fn altv_rs_entry_points() -> $crate::entry::EntryPoints {
+            $crate::entry::EntryPoints::current()
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn altv_main(
+            core: *mut $crate::sdk::natives::alt_ICore,
+        ) -> *mut $crate::app::MainResult {
+            $crate::app::run_main(core, $main)
+        }
+    };
+}
+
+/// Sets up the library for `core` and runs `main`, called by the `altv_main` of `resource!`.
+///
+/// A null `core` keeps the current backend, so tests can run the entry point on the mock.
+#[doc(hidden)]
+pub unsafe fn run_main(core: *mut alt_ICore, main: fn() -> MainResult) -> *mut MainResult {
+    if !core.is_null() {
+        NativeCore::new(core);
+    }
 
-pub type ResourceMainFn = fn(core: usize) -> Result<CoreApplication, Box<dyn Error>>;
+    crate::panic::install_hook();
+
+    // Unwinding out of an extern "C" function aborts the server, the hook already logged the panic.
+    let result = panic::catch_unwind(main).unwrap_or_else(|_| Err("main panicked".into()));
+    Box::into_raw(Box::new(result))
+}
 
 pub struct ApplicationBuilder {
-    core: usize,
+    backend: Rc<dyn CoreBackend>,
     world: World,
     state: Box<dyn State>,
+    client_events: ClientEventHandlers,
+    procedures: Procedures,
+    executor: Executor,
+    reload_hook: Option<ReloadHook>,
 }
 
 impl ApplicationBuilder {
-    pub fn new(core: usize, state: Box<dyn State>) -> Self {
+    /// Builds on the alt:V core `resource!` hands to the library.
+    pub fn new(state: Box<dyn State>) -> Self {
+        ApplicationBuilder::with_backend(Rc::new(NativeCore), state)
+    }
+
+    pub fn with_backend(backend: Rc<dyn CoreBackend>, state: Box<dyn State>) -> Self {
+        let (executor, tasks) = Executor::new();
+
         let mut world = World::new();
         world.insert(AltResource::default());
-        // world.insert(EventChannel::<CEvent>::with_capacity(40));
+        world.insert(Rpc::default());
+        world.insert(Scheduler::default());
+        world.insert(tasks);
+        event_channel::insert(&mut world);
         world.register::<CRefCountable>();
         world.register::<CBaseObject>();
         world.register::<CWorldObject>();
@@ -44,7 +125,15 @@ impl ApplicationBuilder {
         world.register::<CCollisionShape>();
         world.register::<CCheckpoint>();
 
-        ApplicationBuilder { core, world, state }
+        ApplicationBuilder {
+            backend,
+            world,
+            state,
+            client_events: ClientEventHandlers::new(),
+            procedures: Procedures::new(),
+            executor,
+            reload_hook: None,
+        }
     }
 
     pub fn register<C>(mut self) -> Self
@@ -56,641 +145,614 @@ impl ApplicationBuilder {
         self
     }
 
+    /// Registers a handler for the client script event `name`, it runs before the state sees the event.
+    pub fn with_client_event<A, F>(mut self, name: &str, handler: F) -> Self
+    where
+        A: FromArgs,
+        F: FnMut(&mut World, Entity, A) + 'static,
+    {
+        self.client_events.insert(name, handler);
+        self
+    }
+
+    /// Registers a procedure clients can call through `rpc:call`, errors are sent back to the caller.
+    pub fn with_procedure<A, R, F>(mut self, name: &str, procedure: F) -> Self
+    where
+        A: FromArgs,
+        R: Into<MValue>,
+        F: FnMut(&mut World, Entity, A) -> Result<R, String> + 'static,
+    {
+        self.procedures.insert(name, procedure);
+        self
+    }
+
+    /// Saves data before the module hot reloads the resource and restores it in the new library.
+    pub fn with_reload_hook<S, R>(mut self, save: S, restore: R) -> Self
+    where
+        S: Fn(&World) -> Vec<u8> + 'static,
+        R: FnMut(&mut World, &[u8]) + 'static,
+    {
+        self.reload_hook = Some(ReloadHook::new(save, restore));
+        self
+    }
+
     pub fn build<I>(mut self, init: I) -> CoreApplication
     where
         I: DataInit<GameData<'static, 'static>>,
     {
-        unsafe {
-            alt_ICore_SetInstance(self.core as *mut alt_ICore);
-        }
+        backend::set_instance(self.backend);
+        crate::panic::install_hook();
+        self.world.insert(Time::new(core::now()));
 
         let data = init.build(&mut self.world);
 
         CoreApplication {
             world: self.world,
-            state: self.state,
+            states: StateMachine::new(self.state),
             data,
+            client_events: self.client_events,
+            procedures: self.procedures,
+            executor: self.executor,
+            reload_hook: self.reload_hook,
         }
     }
 }
 
 pub struct CoreApplication {
     world: World,
-    state: Box<dyn State>,
+    states: StateMachine,
     data: GameData<'static, 'static>,
+    client_events: ClientEventHandlers,
+    procedures: Procedures,
+    executor: Executor,
+    reload_hook: Option<ReloadHook>,
 }
 
 impl CoreApplication {
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns whether there is a state left on the stack, `Trans::Quit` stops all of them.
+    pub fn is_running(&self) -> bool {
+        self.states.is_running()
+    }
+
+    /// Starts the initial state, or after a stop the state that was at the bottom of the stack.
     pub fn start(&mut self) {
-        self.state
-            .on_start(StateData::new(&mut self.world, &mut self.data))
+        self.states.start(&mut self.world, &mut self.data);
     }
 
     pub fn stop(&mut self) {
-        self.state
-            .on_stop(StateData::new(&mut self.world, &mut self.data))
+        self.states.stop(&mut self.world, &mut self.data);
+    }
+
+    /// Runs the save half of the reload hook, `None` if there is no hook.
+    pub fn save(&self) -> Option<Vec<u8>> {
+        self.reload_hook
+            .as_ref()
+            .map(|reload_hook| reload_hook.save(&self.world))
+    }
+
+    /// Hands data saved by the previous library to the reload hook.
+    pub fn restore(&mut self, data: &[u8]) {
+        if let Some(reload_hook) = &mut self.reload_hook {
+            reload_hook.restore(&mut self.world, data);
+        }
     }
 
     pub fn tick(&mut self) {
-        self.state
-            .tick(StateData::new(&mut self.world, &mut self.data));
+        self.world.write_resource::<Time>().update(core::now());
+        self.states.tick(&mut self.world, &mut self.data);
         self.world.maintain();
-        self.data.update(&mut self.world);
+
+        // Systems, tasks and procedures only run while there is a state.
+        if self.states.is_running() {
+            self.data.update(&mut self.world);
+            scheduler::run(&mut self.world);
+            self.executor.run(&mut self.world);
+            rpc::maintain(&mut self.world);
+        }
+
+        handle::release_queued();
     }
 
-    pub fn handle_event(&mut self, event: *mut alt_CEvent) {
-        unsafe {
-            let ce = match alt_CEvent_GetType(event) {
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CONNECT => {
-                    let event = event as *mut alt_CPlayerConnectEvent;
+    /// Converts an alt:V event, `None` if the SDK doesn't know the event type.
+    unsafe fn read_event(&self, event: *mut alt_CEvent) -> SdkResult<Option<CEvent>> {
+        let ce = match alt_CEvent_GetType(event) {
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CONNECT => {
+                let event = event as *mut alt_CPlayerConnectEvent;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.players.get(&((*event).target.ptr as usize)).unwrap();
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.players, (*event).target.ptr)?;
 
-                    Some(CEvent::PlayerConnect(CPlayerConnectEvent::new(
-                        *target,
-                        String::new(),
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DISCONNECT => {
-                    let event = event as *mut alt_CPlayerDisconnectEvent;
+                Some(CEvent::PlayerConnect(CPlayerConnectEvent::new(
+                    target,
+                    String::new(),
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DISCONNECT => {
+                let event = event as *mut alt_CPlayerDisconnectEvent;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.players.get(&((*event).target.ptr as usize)).unwrap();
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.players, (*event).target.ptr)?;
+                let reason = alt_CPlayerDisconnectEvent_GetReason_CAPI_Heap(event);
+                let reason = StringView::from(*reason).get_data();
 
-                    Some(CEvent::PlayerDisconnect(CPlayerDisconnectEvent::new(
-                        *target,
-                        String::new(),
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_CLIENT_SCRIPT_EVENT => {
-                    let event = event as *mut alt_CClientScriptEvent;
+                Some(CEvent::PlayerDisconnect(CPlayerDisconnectEvent::new(
+                    target, reason,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_START => {
+                let event = event as *mut alt_CResourceStartEvent;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.players.get(&((*event).target.ptr as usize)).unwrap();
+                let name = alt_IResource_GetName_CAPI_Heap((*event).resource);
+                let name = StringView::from(*name).get_data();
 
-                    let name = alt_CClientScriptEvent_GetName_CAPI_Heap(event);
-                    let name = StringView::from(*name).get_data();
-                    dbg!(&name);
+                Some(CEvent::ResourceStart(CResourceStartEvent::new(name)))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_STOP => {
+                let event = event as *mut alt_CResourceStopEvent;
 
-                    let args = alt_CClientScriptEvent_GetArgs(event);
-                    let args = (*args).into();
+                let name = alt_IResource_GetName_CAPI_Heap((*event).resource);
+                let name = StringView::from(*name).get_data();
 
-                    Some(CEvent::ClientScript(CClientScriptEvent::new(
-                        *target, name, args,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_SERVER_SCRIPT_EVENT => {
-                    let event = event as *mut alt_CServerScriptEvent;
+                Some(CEvent::ResourceStop(CResourceStopEvent::new(name)))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_RESOURCE_ERROR => {
+                let event = event as *mut alt_CResourceErrorEvent;
 
-                    let name = alt_CServerScriptEvent_GetName_CAPI_Heap(event);
-                    let name = StringView::from(*name).get_data();
+                let name = alt_IResource_GetName_CAPI_Heap((*event).resource);
+                let name = StringView::from(*name).get_data();
 
-                    let args = alt_CServerScriptEvent_GetArgs(event);
-                    let args = (*args).into();
+                Some(CEvent::ResourceError(CResourceErrorEvent::new(name)))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_CLIENT_SCRIPT_EVENT => {
+                let event = event as *mut alt_CClientScriptEvent;
 
-                    Some(CEvent::ServerScript(CServerScriptEvent::new(name, args)))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_SYNCED_META_CHANGE => {
-                    let event = event as *mut alt_CSyncedMetaDataChangeEvent;
-
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = match CoreApplication::get_entity(&alt, (*event).target.ptr) {
-                        Some(target) => target,
-                        None => {
-                            crate::sdk::log::error("[Rust] Could not find target for the synced meta change event. Did you remove the entity afterwards?");
-                            return;
-                        }
-                    };
-
-                    let key = alt_CSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
-                    let key = StringView::from(*key).get_data();
-
-                    let val = alt_CSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
-                    let val = MValue::new((*val).ptr);
-
-                    let old_val = alt_CSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
-                    let old_val = MValue::new((*old_val).ptr);
-
-                    Some(CEvent::SyncedMetaChange(CSyncedMetaChangeEvent::new(
-                        target, key, val, old_val,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_STREAM_SYNCED_META_CHANGE => {
-                    let event = event as *mut alt_CStreamSyncedMetaDataChangeEvent;
-
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = match CoreApplication::get_entity(&alt, (*event).target.ptr) {
-                        Some(target) => target,
-                        None => {
-                            crate::sdk::log::error("[Rust] Could not find target for the stream synced meta change event. Did you remove the entity afterwards?");
-                            return;
-                        }
-                    };
-
-                    let key = alt_CStreamSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
-                    let key = StringView::from(*key).get_data();
-
-                    let val = alt_CStreamSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
-                    let val = MValue::new((*val).ptr);
-
-                    let old_val = alt_CStreamSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
-                    let old_val = MValue::new((*old_val).ptr);
-
-                    Some(CEvent::StreamSyncedMetaChange(
-                        CStreamSyncedMetaChangeEvent::new(target, key, val, old_val),
-                    ))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_GLOBAL_META_CHANGE => {
-                    let event = event as *mut alt_CGlobalMetaDataChangeEvent;
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.players, (*event).target.ptr)?;
 
-                    let key = alt_CGlobalMetaDataChangeEvent_GetKey_CAPI_Heap(event);
-                    let key = StringView::from(*key).get_data();
+                let name = alt_CClientScriptEvent_GetName_CAPI_Heap(event);
+                let name = StringView::from(*name).get_data();
 
-                    let val = alt_CGlobalMetaDataChangeEvent_GetVal_CAPI_Heap(event);
-                    let val = MValue::new((*val).ptr);
+                let args = alt_CClientScriptEvent_GetArgs(event);
+                let args = convert_array_to_vec_mvalue(&alt, *args)?;
 
-                    let old_val = alt_CGlobalMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
-                    let old_val = MValue::new((*old_val).ptr);
+                Some(CEvent::ClientScript(CClientScriptEvent::new(
+                    target, name, args,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_SERVER_SCRIPT_EVENT => {
+                let event = event as *mut alt_CServerScriptEvent;
 
-                    Some(CEvent::GlobalMetaChange(CGlobalMetaChangeEvent::new(
-                        key, val, old_val,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_GLOBAL_SYNCED_META_CHANGE => {
-                    let event = event as *mut alt_CGlobalSyncedMetaDataChangeEvent;
+                let alt = self.world.read_resource::<AltResource>();
 
-                    let key = alt_CGlobalSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
-                    let key = StringView::from(*key).get_data();
+                let name = alt_CServerScriptEvent_GetName_CAPI_Heap(event);
+                let name = StringView::from(*name).get_data();
 
-                    let val = alt_CGlobalSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
-                    let val = MValue::new((*val).ptr);
+                let args = alt_CServerScriptEvent_GetArgs(event);
+                let args = convert_array_to_vec_mvalue(&alt, *args)?;
 
-                    let old_val = alt_CGlobalSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
-                    let old_val = MValue::new((*old_val).ptr);
+                Some(CEvent::ServerScript(CServerScriptEvent::new(name, args)))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_META_CHANGE => {
+                let event = event as *mut alt_CMetaChangeEvent;
 
-                    Some(CEvent::GlobalSyncedMetaChange(
-                        CGlobalSyncedMetaChangeEvent::new(key, val, old_val),
-                    ))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DAMAGE => {
-                    let event = event as *mut alt_CPlayerDamageEvent;
+                let alt = self.world.read_resource::<AltResource>();
+                let target = get_entity(&alt, (*event).target.ptr)?;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.players.get(&((*event).target.ptr as usize)).unwrap();
+                let key = crate::sdk::string::String::from((*event).key).get_data();
+                let val = MValue::new(&alt, (*event).val.ptr)?;
+                let old_val = MValue::new(&alt, (*event).oldVal.ptr)?;
 
-                    let attacker = match (*event).attacker.ptr.is_null() {
-                        true => None,
-                        false => {
-                            Some(CoreApplication::get_entity(&alt, (*event).attacker.ptr).unwrap())
-                        }
-                    };
+                Some(CEvent::MetaChange(CMetaChangeEvent::new(
+                    target, key, val, old_val,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_SYNCED_META_CHANGE => {
+                let event = event as *mut alt_CSyncedMetaDataChangeEvent;
 
-                    let damage = alt_CPlayerDamageEvent_GetDamage(event);
-                    let weapon = alt_CPlayerDamageEvent_GetWeapon(event);
+                let alt = self.world.read_resource::<AltResource>();
+                let target = get_entity(&alt, (*event).target.ptr)?;
 
-                    Some(CEvent::PlayerDamage(CPlayerDamageEvent::new(
-                        *target, attacker, damage, weapon,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DEATH => {
-                    let event = event as *mut alt_CPlayerDeathEvent;
+                let key = alt_CSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
+                let key = StringView::from(*key).get_data();
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.players.get(&((*event).target.ptr as usize)).unwrap();
+                let val = alt_CSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
+                let val = MValue::new(&alt, (*val).ptr)?;
 
-                    let killer = match (*event).killer.ptr.is_null() {
-                        true => None,
-                        false => {
-                            Some(CoreApplication::get_entity(&alt, (*event).killer.ptr).unwrap())
-                        }
-                    };
+                let old_val = alt_CSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
+                let old_val = MValue::new(&alt, (*old_val).ptr)?;
 
-                    let weapon = alt_CPlayerDeathEvent_GetWeapon(event);
+                Some(CEvent::SyncedMetaChange(CSyncedMetaChangeEvent::new(
+                    target, key, val, old_val,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_STREAM_SYNCED_META_CHANGE => {
+                let event = event as *mut alt_CStreamSyncedMetaDataChangeEvent;
 
-                    Some(CEvent::PlayerDeath(CPlayerDeathEvent::new(
-                        *target, killer, weapon,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_EXPLOSION_EVENT => {
-                    let event = event as *mut alt_CExplosionEvent;
+                let alt = self.world.read_resource::<AltResource>();
+                let target = get_entity(&alt, (*event).target.ptr)?;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let source = alt.players.get(&((*event).source.ptr as usize)).unwrap();
+                let key = alt_CStreamSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
+                let key = StringView::from(*key).get_data();
 
-                    let explosion_type = alt_CExplosionEvent_GetExplosionType(event) as u8;
+                let val = alt_CStreamSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
+                let val = MValue::new(&alt, (*val).ptr)?;
 
-                    let pos = alt_CExplosionEvent_GetPosition_CAPI_Heap(event);
-                    let pos = Vector3::from(*pos);
+                let old_val = alt_CStreamSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
+                let old_val = MValue::new(&alt, (*old_val).ptr)?;
 
-                    let explosion_fx = alt_CExplosionEvent_GetExplosionFX(event);
+                Some(CEvent::StreamSyncedMetaChange(
+                    CStreamSyncedMetaChangeEvent::new(target, key, val, old_val),
+                ))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_GLOBAL_META_CHANGE => {
+                let event = event as *mut alt_CGlobalMetaDataChangeEvent;
 
-                    Some(CEvent::ExplosionEvent(CExplosionEvent::new(
-                        *source,
-                        explosion_type,
-                        pos,
-                        explosion_fx,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_WEAPON_DAMAGE_EVENT => {
-                    let event = event as *mut alt_CWeaponDamageEvent;
-
-                    let alt = self.world.read_resource::<AltResource>();
-                    let source = alt.players.get(&((*event).source.ptr as usize)).unwrap();
-
-                    let target = match (*event).target.ptr.is_null() {
-                        true => None,
-                        false => {
-                            Some(CoreApplication::get_entity(&alt, (*event).target.ptr).unwrap())
-                        }
-                    };
-
-                    let weapon = alt_CWeaponDamageEvent_GetWeaponHash(event);
-                    let damage = alt_CWeaponDamageEvent_GetDamageValue(event);
-
-                    let shot_offset = alt_CWeaponDamageEvent_GetShotOffset_CAPI_Heap(event);
-                    let shot_offset = Vector3::from(*shot_offset);
-
-                    let body_part = alt_CWeaponDamageEvent_GetBodyPart(event) as u8;
-
-                    Some(CEvent::WeaponDamageEvent(CWeaponDamageEvent::new(
-                        *source,
-                        target,
-                        weapon,
-                        damage,
-                        shot_offset,
-                        body_part,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_COLSHAPE_EVENT => {
-                    let event = event as *mut alt_CColShapeEvent;
-
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = match alt_IColShape_GetType((*event).target.ptr) {
-                        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => alt
-                            .collision_shapes
-                            .get(&((*event).target.ptr as usize))
-                            .unwrap(),
-                        alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => alt
-                            .checkpoints
-                            .get(&((*event).target.ptr as usize))
-                            .unwrap(),
-                        _ => unreachable!(),
-                    };
-                    let entity = CoreApplication::get_entity(&alt, (*event).entity.ptr).unwrap();
-                    let state = alt_CColShapeEvent_GetState(event);
-
-                    Some(CEvent::CollisionShapeEvent(CCollisionShapeEvent::new(
-                        *target, entity, state,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_ENTER_VEHICLE => {
-                    let event = event as *mut alt_CPlayerEnterVehicleEvent;
+                let alt = self.world.read_resource::<AltResource>();
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.vehicles.get(&((*event).target.ptr as usize)).unwrap();
-                    let player = alt.players.get(&((*event).player.ptr as usize)).unwrap();
-                    let seat = alt_CPlayerEnterVehicleEvent_GetSeat(event);
+                let key = alt_CGlobalMetaDataChangeEvent_GetKey_CAPI_Heap(event);
+                let key = StringView::from(*key).get_data();
 
-                    Some(CEvent::PlayerEnterVehicle(CPlayerEnterVehicleEvent::new(
-                        *target, *player, seat,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_LEAVE_VEHICLE => {
-                    let event = event as *mut alt_CPlayerLeaveVehicleEvent;
+                let val = alt_CGlobalMetaDataChangeEvent_GetVal_CAPI_Heap(event);
+                let val = MValue::new(&alt, (*val).ptr)?;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.vehicles.get(&((*event).target.ptr as usize)).unwrap();
-                    let player = alt.players.get(&((*event).player.ptr as usize)).unwrap();
-                    let seat = alt_CPlayerLeaveVehicleEvent_GetSeat(event);
+                let old_val = alt_CGlobalMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
+                let old_val = MValue::new(&alt, (*old_val).ptr)?;
 
-                    Some(CEvent::PlayerLeaveVehicle(CPlayerLeaveVehicleEvent::new(
-                        *target, *player, seat,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CHANGE_VEHICLE_SEAT => {
-                    let event = event as *mut alt_CPlayerChangeVehicleSeatEvent;
-
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = alt.vehicles.get(&((*event).target.ptr as usize)).unwrap();
-                    let player = alt.players.get(&((*event).player.ptr as usize)).unwrap();
-                    let old_seat = alt_CPlayerChangeVehicleSeatEvent_GetOldSeat(event);
-                    let new_seat = alt_CPlayerChangeVehicleSeatEvent_GetNewSeat(event);
-
-                    Some(CEvent::PlayerChangeVehicleSeat(
-                        CPlayerChangeVehicleSeatEvent::new(*target, *player, old_seat, new_seat),
-                    ))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_REMOVE_ENTITY_EVENT => {
-                    let event = event as *mut alt_CRemoveEntityEvent;
+                Some(CEvent::GlobalMetaChange(CGlobalMetaChangeEvent::new(
+                    key, val, old_val,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_GLOBAL_SYNCED_META_CHANGE => {
+                let event = event as *mut alt_CGlobalSyncedMetaDataChangeEvent;
 
-                    let alt = self.world.read_resource::<AltResource>();
-                    let target = CoreApplication::get_entity(&alt, (*event).target.ptr).unwrap();
+                let alt = self.world.read_resource::<AltResource>();
 
-                    Some(CEvent::RemoveEntity(CRemoveEntityEvent::new(target)))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_DATA_NODE_RECEIVED_EVENT => {
-                    let event = event as *mut alt_CDataNodeReceivedEvent;
+                let key = alt_CGlobalSyncedMetaDataChangeEvent_GetKey_CAPI_Heap(event);
+                let key = StringView::from(*key).get_data();
 
-                    let name = alt_CDataNodeReceivedEvent_GetName_CAPI_Heap(event);
-                    let name = StringView::from(*name).get_data();
+                let val = alt_CGlobalSyncedMetaDataChangeEvent_GetVal_CAPI_Heap(event);
+                let val = MValue::new(&alt, (*val).ptr)?;
 
-                    let json = alt_CDataNodeReceivedEvent_GetName_CAPI_Heap(event);
-                    let json = StringView::from(*json).get_data();
+                let old_val = alt_CGlobalSyncedMetaDataChangeEvent_GetOldVal_CAPI_Heap(event);
+                let old_val = MValue::new(&alt, (*old_val).ptr)?;
 
-                    Some(CEvent::DataNodeReceived(CDataNodeReceivedEvent::new(
-                        name, json,
-                    )))
-                }
-                alt_CEvent_Type::ALT_CEVENT_TYPE_CONSOLE_COMMAND_EVENT => {
-                    let event = event as *mut alt_CConsoleCommandEvent;
+                Some(CEvent::GlobalSyncedMetaChange(
+                    CGlobalSyncedMetaChangeEvent::new(key, val, old_val),
+                ))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DAMAGE => {
+                let event = event as *mut alt_CPlayerDamageEvent;
 
-                    let name = alt_CConsoleCommandEvent_GetName_CAPI_Heap(event);
-                    let name = StringView::from(*name).get_data();
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.players, (*event).target.ptr)?;
 
-                    let args = alt_CConsoleCommandEvent_GetArgs(event);
-                    let args = Vec::from(*args).iter().map(|a| a.get_data()).collect();
+                let attacker = match (*event).attacker.ptr.is_null() {
+                    true => None,
+                    false => Some(get_entity(&alt, (*event).attacker.ptr)?),
+                };
 
-                    Some(CEvent::ConsoleCommand(CConsoleCommandEvent::new(
-                        name, args,
-                    )))
-                }
-                _ => None,
-            };
-
-            match ce {
-                Some(ce) => {
-                    dbg!("Pushing event to resource.");
-                    self.state
-                        .handle_event(StateData::new(&mut self.world, &mut self.data), ce);
-                }
-                None => {
-                    altv_sdk::loge!(
-                        "[Rust] Unknown event type {}.",
-                        alt_CEvent_GetType(event) as i32
-                    );
-                }
+                let damage = alt_CPlayerDamageEvent_GetDamage(event);
+                let weapon = alt_CPlayerDamageEvent_GetWeapon(event);
+
+                Some(CEvent::PlayerDamage(CPlayerDamageEvent::new(
+                    target, attacker, damage, weapon,
+                )))
             }
-        }
-    }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_DEATH => {
+                let event = event as *mut alt_CPlayerDeathEvent;
 
-    pub fn create_game_object(&mut self, base_obj: *mut alt_IBaseObject) {
-        unsafe {
-            match alt_IBaseObject_GetType(base_obj) {
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
-                    let player = alt_IBaseObject_to_alt_IPlayer(base_obj);
-                    let entity = create_player(&mut self.world, player);
-
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    alt.players.insert(player as usize, entity);
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
-                    let vehicle = alt_IBaseObject_to_alt_IVehicle(base_obj);
-                    dbg!();
-                    let entity = create_vehicle(&mut self.world, vehicle);
-                    dbg!();
-
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    dbg!();
-                    alt.vehicles.insert(vehicle as usize, entity);
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => {
-                    let blip = alt_IBaseObject_to_alt_IBlip(base_obj);
-                    dbg!(blip);
-                    let entity = create_blip(&mut self.world, blip);
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.players, (*event).target.ptr)?;
 
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    alt.blips.insert(blip as usize, entity);
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => {
-                    let voice_channel = alt_IBaseObject_to_alt_IVoiceChannel(base_obj);
-                    let entity = create_voice_channel(&mut self.world, voice_channel);
+                let killer = match (*event).killer.ptr.is_null() {
+                    true => None,
+                    false => Some(get_entity(&alt, (*event).killer.ptr)?),
+                };
 
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    alt.voice_channels.insert(voice_channel as usize, entity);
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => {
-                    let collision_shape = alt_IBaseObject_to_alt_IColShape(base_obj);
-                    let entity = create_collision_shape(&mut self.world, collision_shape);
+                let weapon = alt_CPlayerDeathEvent_GetWeapon(event);
 
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    alt.collision_shapes
-                        .insert(collision_shape as usize, entity);
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => {
-                    let checkpoint = alt_IBaseObject_to_alt_ICheckpoint(base_obj);
-                    let entity = create_checkpoint(&mut self.world, checkpoint);
+                Some(CEvent::PlayerDeath(CPlayerDeathEvent::new(
+                    target, killer, weapon,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_FIRE_EVENT => {
+                let event = event as *mut alt_CFireEvent;
 
-                    let mut alt = self.world.write_resource::<AltResource>();
-                    alt.checkpoints.insert(checkpoint as usize, entity);
-                }
-                _ => {}
+                let alt = self.world.read_resource::<AltResource>();
+                let source = find(&alt.players, (*event).source.ptr)?;
+
+                let fires = &(*event).fires;
+                let fires = (0..fires.size as usize)
+                    .map(|n| {
+                        let fire = &*fires.data.add(n);
+                        FireInfo::new(Vector3::from(fire.position), fire.weaponHash)
+                    })
+                    .collect();
+
+                Some(CEvent::FireEvent(CFireEvent::new(source, fires)))
             }
-        }
-    }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_EXPLOSION_EVENT => {
+                let event = event as *mut alt_CExplosionEvent;
 
-    pub fn remove_game_object(&mut self, base_obj: *mut alt_IBaseObject) {
-        unsafe {
-            match alt_IBaseObject_GetType(base_obj) {
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
-                    let player = alt_IBaseObject_to_alt_IPlayer(base_obj);
+                let alt = self.world.read_resource::<AltResource>();
+                let source = find(&alt.players, (*event).source.ptr)?;
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.players.remove(&(player as usize)).unwrap()
-                    };
+                let explosion_type = alt_CExplosionEvent_GetExplosionType(event) as u8;
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
-                    let vehicle = alt_IBaseObject_to_alt_IVehicle(base_obj);
+                let pos = alt_CExplosionEvent_GetPosition_CAPI_Heap(event);
+                let pos = Vector3::from(*pos);
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.vehicles.remove(&(vehicle as usize)).unwrap()
-                    };
+                let explosion_fx = alt_CExplosionEvent_GetExplosionFX(event);
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_BLIP => {
-                    let blip = alt_IBaseObject_to_alt_IBlip(base_obj);
+                Some(CEvent::ExplosionEvent(CExplosionEvent::new(
+                    source,
+                    explosion_type,
+                    pos,
+                    explosion_fx,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_WEAPON_DAMAGE_EVENT => {
+                let event = event as *mut alt_CWeaponDamageEvent;
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.blips.remove(&(blip as usize)).unwrap()
-                    };
+                let alt = self.world.read_resource::<AltResource>();
+                let source = find(&alt.players, (*event).source.ptr)?;
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VOICE_CHANNEL => {
-                    let voice_channel = alt_IBaseObject_to_alt_IVoiceChannel(base_obj);
+                let target = match (*event).target.ptr.is_null() {
+                    true => None,
+                    false => Some(get_entity(&alt, (*event).target.ptr)?),
+                };
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.voice_channels
-                            .remove(&(voice_channel as usize))
-                            .unwrap()
-                    };
+                let weapon = alt_CWeaponDamageEvent_GetWeaponHash(event);
+                let damage = alt_CWeaponDamageEvent_GetDamageValue(event);
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => {
-                    let collision_shape = alt_IBaseObject_to_alt_IColShape(base_obj);
+                let shot_offset = alt_CWeaponDamageEvent_GetShotOffset_CAPI_Heap(event);
+                let shot_offset = Vector3::from(*shot_offset);
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.collision_shapes
-                            .remove(&(collision_shape as usize))
-                            .unwrap()
-                    };
+                let body_part = alt_CWeaponDamageEvent_GetBodyPart(event) as u8;
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => {
-                    let checkpoint = alt_IBaseObject_to_alt_ICheckpoint(base_obj);
+                Some(CEvent::WeaponDamageEvent(CWeaponDamageEvent::new(
+                    source,
+                    target,
+                    weapon,
+                    damage,
+                    shot_offset,
+                    body_part,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_VEHICLE_DESTROY => {
+                let event = event as *mut alt_CVehicleDestroyEvent;
 
-                    let entity = {
-                        let mut alt = self.world.write_resource::<AltResource>();
-                        alt.checkpoints.remove(&(checkpoint as usize)).unwrap()
-                    };
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.vehicles, (*event).target.ptr)?;
 
-                    self.world.delete_entity(entity).unwrap();
-                }
-                _ => {}
+                Some(CEvent::VehicleDestroyEvent(CVehicleDestroyEvent::new(
+                    target,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_CHECKPOINT_EVENT => {
+                let event = event as *mut alt_CCheckpointEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.checkpoints, (*event).target)?;
+                let entity = get_entity(&alt, (*event).entity.ptr)?;
+
+                Some(CEvent::CheckpointEvent(CCheckpointEvent::new(
+                    target,
+                    entity,
+                    (*event).state,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_COLSHAPE_EVENT => {
+                let event = event as *mut alt_CColShapeEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = match alt_IColShape_GetType((*event).target.ptr) {
+                    alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_COLSHAPE => {
+                        find(&alt.collision_shapes, (*event).target.ptr)?
+                    }
+                    alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_CHECKPOINT => {
+                        find(&alt.checkpoints, (*event).target.ptr)?
+                    }
+                    object_type => return Err(SdkError::UnsupportedType(object_type)),
+                };
+                let entity = get_entity(&alt, (*event).entity.ptr)?;
+                let state = alt_CColShapeEvent_GetState(event);
+
+                Some(CEvent::CollisionShapeEvent(CCollisionShapeEvent::new(
+                    target, entity, state,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_ENTER_VEHICLE => {
+                let event = event as *mut alt_CPlayerEnterVehicleEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.vehicles, (*event).target.ptr)?;
+                let player = find(&alt.players, (*event).player.ptr)?;
+                let seat = alt_CPlayerEnterVehicleEvent_GetSeat(event);
+
+                Some(CEvent::PlayerEnterVehicle(CPlayerEnterVehicleEvent::new(
+                    target, player, seat,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_LEAVE_VEHICLE => {
+                let event = event as *mut alt_CPlayerLeaveVehicleEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.vehicles, (*event).target.ptr)?;
+                let player = find(&alt.players, (*event).player.ptr)?;
+                let seat = alt_CPlayerLeaveVehicleEvent_GetSeat(event);
+
+                Some(CEvent::PlayerLeaveVehicle(CPlayerLeaveVehicleEvent::new(
+                    target, player, seat,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CHANGE_VEHICLE_SEAT => {
+                let event = event as *mut alt_CPlayerChangeVehicleSeatEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = find(&alt.vehicles, (*event).target.ptr)?;
+                let player = find(&alt.players, (*event).player.ptr)?;
+                let old_seat = alt_CPlayerChangeVehicleSeatEvent_GetOldSeat(event);
+                let new_seat = alt_CPlayerChangeVehicleSeatEvent_GetNewSeat(event);
+
+                Some(CEvent::PlayerChangeVehicleSeat(
+                    CPlayerChangeVehicleSeatEvent::new(target, player, old_seat, new_seat),
+                ))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_REMOVE_ENTITY_EVENT => {
+                let event = event as *mut alt_CRemoveEntityEvent;
+
+                let alt = self.world.read_resource::<AltResource>();
+                let target = get_entity(&alt, (*event).target.ptr)?;
+
+                Some(CEvent::RemoveEntity(CRemoveEntityEvent::new(target)))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_DATA_NODE_RECEIVED_EVENT => {
+                let event = event as *mut alt_CDataNodeReceivedEvent;
+
+                let name = alt_CDataNodeReceivedEvent_GetName_CAPI_Heap(event);
+                let name = StringView::from(*name).get_data();
+
+                let json = alt_CDataNodeReceivedEvent_GetJson_CAPI_Heap(event);
+                let json = StringView::from(*json).get_data();
+
+                Some(CEvent::DataNodeReceived(CDataNodeReceivedEvent::new(
+                    name, json,
+                )))
+            }
+            alt_CEvent_Type::ALT_CEVENT_TYPE_CONSOLE_COMMAND_EVENT => {
+                let event = event as *mut alt_CConsoleCommandEvent;
+
+                let name = alt_CConsoleCommandEvent_GetName_CAPI_Heap(event);
+                let name = StringView::from(*name).get_data();
+
+                let args = alt_CConsoleCommandEvent_GetArgs(event);
+                let args = Vec::from(*args).iter().map(|a| a.get_data()).collect();
+
+                Some(CEvent::ConsoleCommand(CConsoleCommandEvent::new(
+                    name, args,
+                )))
+            }
+            _ => None,
+        };
+
+        Ok(ce)
+    }
+
+    /// Reads an alt:V event and dispatches it, cancelling it in the core if a handler did.
+    ///
+    /// # Safety
+    ///
+    /// `event` must point to a live event of the core for the duration of the call.
+    pub unsafe fn handle_event(&mut self, event: *mut alt_CEvent) {
+        let ce = match self.read_event(event) {
+            Ok(Some(ce)) => ce,
+            Ok(None) => {
+                altv_sdk::loge!(
+                    "[Rust] Unknown event type {}.",
+                    alt_CEvent_GetType(event) as i32
+                );
+                return;
+            }
+            Err(e) => {
+                altv_sdk::loge!(
+                    "[Rust] Could not read the event of type {}: {}",
+                    alt_CEvent_GetType(event) as i32,
+                    e
+                );
+                return;
+            }
+        };
+
+        let cancellation = ce.get_cancellation().cloned();
+
+        if let Some(cancellation) = &cancellation {
+            if alt_CEvent_WasCancelled(event) {
+                cancellation.cancel(None);
+            }
+        }
+
+        if !self.dispatch_event(ce) || alt_CEvent_WasCancelled(event) {
+            return;
+        }
+
+        match cancellation.and_then(|cancellation| cancellation.get_reason()) {
+            Some(reason)
+                if alt_CEvent_GetType(event) == alt_CEvent_Type::ALT_CEVENT_TYPE_PLAYER_CONNECT =>
+            {
+                let mut reason = StringView::new(&reason).into();
+                alt_CPlayerConnectEvent_Cancel_1(
+                    event as *mut alt_CPlayerConnectEvent,
+                    &mut reason,
+                );
             }
+            _ => alt_CEvent_Cancel(event),
         }
     }
 
-    fn get_entity(alt: &AltResource, entity: *mut alt_IEntity) -> Option<Entity> {
-        unsafe {
-            match alt_IEntity_GetType(entity) {
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_PLAYER => {
-                    let player = alt_IEntity_to_alt_IPlayer(entity);
+    /// Runs the event through the handlers and the current state, returns whether it was cancelled.
+    pub fn dispatch_event(&mut self, event: CEvent) -> bool {
+        let cancellation = event.get_cancellation().cloned();
 
-                    match alt.players.get(&(player as usize)) {
-                        Some(player) => Some(*player),
-                        None => None,
-                    }
+        match &event {
+            CEvent::ClientScript(event) => {
+                if rpc::handle_event(&mut self.world, &mut self.procedures, event) {
+                    return false;
                 }
-                alt_IBaseObject_Type::ALT_IBASEOBJECT_TYPE_VEHICLE => {
-                    let vehicle = alt_IEntity_to_alt_IVehicle(entity);
 
-                    match alt.vehicles.get(&(vehicle as usize)) {
-                        Some(vehicle) => Some(*vehicle),
-                        None => None,
-                    }
-                }
-                _ => panic!(),
+                self.client_events.dispatch(&mut self.world, event);
+            }
+            CEvent::PlayerDisconnect(event) => {
+                rpc::cancel_player(&mut self.world, event.get_target())
             }
+            _ => {}
         }
-    }
-}
 
-fn create_blip(world: &mut World, ptr: *mut alt_IBlip) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_IBlip_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(alt_IBlip_to_alt_IBaseObject(
-                ptr,
-            ))))
-            .with(CWorldObject(AtomicPtr::new(alt_IBlip_to_alt_IWorldObject(
-                ptr,
-            ))))
-            .with(CBlip(AtomicPtr::new(ptr)))
-            .build()
-    }
-}
+        // Systems read the copy on the next tick, after the cancellation was returned to the core.
+        event_channel::publish(&self.world, event.clone());
+        self.states
+            .handle_event(&mut self.world, &mut self.data, event);
+        rpc::maintain(&mut self.world);
 
-fn create_collision_shape(world: &mut World, ptr: *mut alt_IColShape) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_IColShape_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(
-                alt_IColShape_to_alt_IBaseObject(ptr),
-            )))
-            .with(CWorldObject(AtomicPtr::new(
-                alt_IColShape_to_alt_IWorldObject(ptr),
-            )))
-            .with(CCollisionShape(AtomicPtr::new(ptr)))
-            .build()
+        cancellation
+            .map(|cancellation| cancellation.is_cancelled())
+            .unwrap_or(false)
     }
-}
 
-fn create_checkpoint(world: &mut World, ptr: *mut alt_ICheckpoint) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_ICheckpoint_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(
-                alt_ICheckpoint_to_alt_IBaseObject(ptr),
-            )))
-            .with(CWorldObject(AtomicPtr::new(
-                alt_ICheckpoint_to_alt_IWorldObject(ptr),
-            )))
-            .with(CCollisionShape(AtomicPtr::new(
-                alt_ICheckpoint_to_alt_IColShape(ptr),
-            )))
-            .with(CCheckpoint(AtomicPtr::new(ptr)))
-            .build()
+    pub fn create_game_object(&mut self, base_obj: *mut alt_IBaseObject) {
+        // Objects the SDK has no components for, such as web views, are left out.
+        let _ = insert_base_object(&self.world, base_obj);
     }
-}
 
-pub fn create_player(world: &mut World, ptr: *mut alt_IPlayer) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_IPlayer_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(alt_IPlayer_to_alt_IBaseObject(
-                ptr,
-            ))))
-            .with(CWorldObject(AtomicPtr::new(
-                alt_IPlayer_to_alt_IWorldObject(ptr),
-            )))
-            .with(CEntity(AtomicPtr::new(alt_IPlayer_to_alt_IEntity(ptr))))
-            .with(CPlayer(AtomicPtr::new(ptr)))
-            .build()
+    /// Returns the base objects the application has entities for, a reloaded library takes
+    /// them over.
+    pub fn base_objects(&self) -> Vec<*mut alt_IBaseObject> {
+        self.world
+            .read_resource::<AltResource>()
+            .base_objects
+            .values()
+            .map(|base_obj| *base_obj as *mut alt_IBaseObject)
+            .collect()
     }
-}
 
-fn create_vehicle(world: &mut World, ptr: *mut alt_IVehicle) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_IVehicle_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(
-                alt_IVehicle_to_alt_IBaseObject(ptr),
-            )))
-            .with(CWorldObject(AtomicPtr::new(
-                alt_IVehicle_to_alt_IWorldObject(ptr),
-            )))
-            .with(CEntity(AtomicPtr::new(alt_IVehicle_to_alt_IEntity(ptr))))
-            .with(CVehicle(AtomicPtr::new(ptr)))
-            .build()
+    pub fn remove_game_object(&mut self, base_obj: *mut alt_IBaseObject) {
+        if let Ok(entity) = remove_base_object(&mut self.world, base_obj) {
+            scheduler::cancel_entity(&self.world, entity);
+        }
     }
 }
 
-fn create_voice_channel(world: &mut World, ptr: *mut alt_IVoiceChannel) -> Entity {
-    unsafe {
-        world
-            .create_entity()
-            .with(CRefCountable(AtomicPtr::new(
-                alt_IVoiceChannel_to_alt_CRefCountable(ptr),
-            )))
-            .with(CBaseObject(AtomicPtr::new(
-                alt_IVoiceChannel_to_alt_IBaseObject(ptr),
-            )))
-            .build()
-    }
+fn find<T>(objects: &HashMap<usize, Entity>, ptr: *mut T) -> SdkResult<Entity> {
+    objects
+        .get(&(ptr as usize))
+        .copied()
+        .ok_or(SdkError::ObjectNotFound)
 }
@@ -0,0 +1,56 @@
+use crate::core::ecs::{Entity, World};
+use crate::sdk::events::CClientScriptEvent;
+use crate::sdk::mvalue::{FromArgs, MValue};
+use std::collections::HashMap;
+
+type Handler = Box<dyn FnMut(&mut World, Entity, &[MValue]) -> Result<(), String>>;
+
+/// Client script event handlers, registered per event name.
+///
+/// Arguments are extracted before the handler runs, invalid ones are logged and the handler is skipped.
+#[derive(Default)]
+pub struct ClientEventHandlers {
+    handlers: HashMap<String, Vec<Handler>>,
+}
+
+impl ClientEventHandlers {
+    pub fn new() -> Self {
+        ClientEventHandlers::default()
+    }
+
+    pub fn insert<A, F>(&mut self, name: &str, mut handler: F)
+    where
+        A: FromArgs,
+        F: FnMut(&mut World, Entity, A) + 'static,
+    {
+        self.handlers
+            .entry(name.to_owned())
+            .or_default()
+            .push(Box::new(move |world, player, args| {
+                let args = A::from_args(args).map_err(|e| e.to_string())?;
+                handler(world, player, args);
+                Ok(())
+            }));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.handlers.contains_key(name)
+    }
+
+    pub fn dispatch(&mut self, world: &mut World, event: &CClientScriptEvent) {
+        let handlers = match self.handlers.get_mut(event.get_name()) {
+            Some(handlers) => handlers,
+            None => return,
+        };
+
+        for handler in handlers {
+            if let Err(e) = handler(world, event.get_target(), event.get_args()) {
+                altv_sdk::loge!(
+                    "[Rust] Invalid arguments for client event {}: {}",
+                    event.get_name(),
+                    e
+                );
+            }
+        }
+    }
+}
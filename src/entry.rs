@@ -0,0 +1,120 @@
+//! Entry points the module calls on a running application, exported by `resource!`.
+//!
+//! A panic can only be caught by the Rust runtime it was raised in, and the module and every
+//! resource library link their own copy of std. So each entry point catches panics inside the
+//! resource library and reports them to the module as a `CallStatus`.
+
+use crate::app::CoreApplication;
+use crate::sdk::natives::{alt_CEvent, alt_IBaseObject};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Signature of the `altv_rs_entry_points` symbol exported by `resource!`.
+pub type EntryPointsFn = extern "C" fn() -> EntryPoints;
+
+pub type AppFn = unsafe extern "C" fn(app: *mut CoreApplication) -> CallStatus;
+pub type EventFn =
+    unsafe extern "C" fn(app: *mut CoreApplication, event: *mut alt_CEvent) -> CallStatus;
+pub type BaseObjectFn =
+    unsafe extern "C" fn(app: *mut CoreApplication, base_obj: *mut alt_IBaseObject) -> CallStatus;
+pub type SaveFn =
+    unsafe extern "C" fn(app: *mut CoreApplication, data: *mut Option<Vec<u8>>) -> CallStatus;
+pub type RestoreFn =
+    unsafe extern "C" fn(app: *mut CoreApplication, data: *const u8, len: usize) -> CallStatus;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallStatus {
+    Ok,
+    /// The application panicked, the hook of the resource library already logged it.
+    Panicked,
+}
+
+/// The entry points of one resource library, only valid while it is loaded.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EntryPoints {
+    pub start: AppFn,
+    pub stop: AppFn,
+    pub tick: AppFn,
+    pub handle_event: EventFn,
+    pub create_game_object: BaseObjectFn,
+    pub remove_game_object: BaseObjectFn,
+    pub save: SaveFn,
+    pub restore: RestoreFn,
+    /// Drops the application in place, it must not be used afterwards.
+    pub drop: AppFn,
+}
+
+impl EntryPoints {
+    pub fn current() -> Self {
+        EntryPoints {
+            start,
+            stop,
+            tick,
+            handle_event,
+            create_game_object,
+            remove_game_object,
+            save,
+            restore,
+            drop,
+        }
+    }
+}
+
+unsafe fn call<F>(app: *mut CoreApplication, f: F) -> CallStatus
+where
+    F: FnOnce(&mut CoreApplication),
+{
+    match panic::catch_unwind(AssertUnwindSafe(|| f(&mut *app))) {
+        Ok(()) => CallStatus::Ok,
+        Err(_) => CallStatus::Panicked,
+    }
+}
+
+unsafe extern "C" fn start(app: *mut CoreApplication) -> CallStatus {
+    call(app, |app| app.start())
+}
+
+unsafe extern "C" fn stop(app: *mut CoreApplication) -> CallStatus {
+    call(app, |app| app.stop())
+}
+
+unsafe extern "C" fn tick(app: *mut CoreApplication) -> CallStatus {
+    call(app, |app| app.tick())
+}
+
+unsafe extern "C" fn handle_event(app: *mut CoreApplication, event: *mut alt_CEvent) -> CallStatus {
+    call(app, |app| app.handle_event(event))
+}
+
+unsafe extern "C" fn create_game_object(
+    app: *mut CoreApplication,
+    base_obj: *mut alt_IBaseObject,
+) -> CallStatus {
+    call(app, |app| app.create_game_object(base_obj))
+}
+
+unsafe extern "C" fn remove_game_object(
+    app: *mut CoreApplication,
+    base_obj: *mut alt_IBaseObject,
+) -> CallStatus {
+    call(app, |app| app.remove_game_object(base_obj))
+}
+
+unsafe extern "C" fn save(app: *mut CoreApplication, data: *mut Option<Vec<u8>>) -> CallStatus {
+    call(app, |app| *data = app.save())
+}
+
+unsafe extern "C" fn restore(app: *mut CoreApplication, data: *const u8, len: usize) -> CallStatus {
+    call(app, |app| {
+        app.restore(std::slice::from_raw_parts(data, len))
+    })
+}
+
+unsafe extern "C" fn drop(app: *mut CoreApplication) -> CallStatus {
+    match panic::catch_unwind(AssertUnwindSafe(|| ptr::drop_in_place(app))) {
+        Ok(()) => CallStatus::Ok,
+        Err(_) => CallStatus::Panicked,
+    }
+}
@@ -0,0 +1,54 @@
+//! Every event is published into an `EventChannel` of its own type, so systems can read them
+//! with a `ReaderId` registered in `System::setup`. Systems read them a tick later, too late to
+//! cancel an event.
+
+use crate::core::ecs::{World, WorldExt};
+use crate::sdk::events::*;
+
+pub use crate::core::ecs::shrev::{EventChannel, ReaderId};
+
+macro_rules! event_channels {
+    ($($variant:ident($event:ident),)*) => {
+        pub(crate) fn insert(world: &mut World) {
+            $(world.insert(EventChannel::<$event>::new());)*
+        }
+
+        pub(crate) fn publish(world: &World, event: CEvent) {
+            match event {
+                CEvent::None => {}
+                $(CEvent::$variant(event) => world
+                    .write_resource::<EventChannel<$event>>()
+                    .single_write(event),)*
+            }
+        }
+    };
+}
+
+event_channels! {
+    PlayerConnect(CPlayerConnectEvent),
+    PlayerDisconnect(CPlayerDisconnectEvent),
+    ResourceStart(CResourceStartEvent),
+    ResourceStop(CResourceStopEvent),
+    ResourceError(CResourceErrorEvent),
+    ServerScript(CServerScriptEvent),
+    ClientScript(CClientScriptEvent),
+    MetaChange(CMetaChangeEvent),
+    SyncedMetaChange(CSyncedMetaChangeEvent),
+    StreamSyncedMetaChange(CStreamSyncedMetaChangeEvent),
+    GlobalMetaChange(CGlobalMetaChangeEvent),
+    GlobalSyncedMetaChange(CGlobalSyncedMetaChangeEvent),
+    PlayerDamage(CPlayerDamageEvent),
+    PlayerDeath(CPlayerDeathEvent),
+    FireEvent(CFireEvent),
+    ExplosionEvent(CExplosionEvent),
+    WeaponDamageEvent(CWeaponDamageEvent),
+    VehicleDestroyEvent(CVehicleDestroyEvent),
+    CheckpointEvent(CCheckpointEvent),
+    CollisionShapeEvent(CCollisionShapeEvent),
+    PlayerEnterVehicle(CPlayerEnterVehicleEvent),
+    PlayerLeaveVehicle(CPlayerLeaveVehicleEvent),
+    PlayerChangeVehicleSeat(CPlayerChangeVehicleSeatEvent),
+    RemoveEntity(CRemoveEntityEvent),
+    DataNodeReceived(CDataNodeReceivedEvent),
+    ConsoleCommand(CConsoleCommandEvent),
+}
@@ -1,4 +1,5 @@
 use crate::core::ecs::{Dispatcher, DispatcherBuilder, RunNow, System, World};
+use crate::schedule::{Schedule, Scheduled};
 
 pub trait DataInit<T> {
     fn build(self, world: &mut World) -> T;
@@ -83,6 +84,19 @@ impl<'a, 'b> GameDataBuilder<'a, 'b> {
         self.dispatcher_builder = self.dispatcher_builder.with_thread_local(system);
         self
     }
+
+    /// Adds a thread local system that only runs when the `schedule` is due.
+    ///
+    /// Panics if a `Schedule::Hz` is not positive and finite.
+    pub fn with_scheduled<T>(mut self, system: T, schedule: Schedule) -> Self
+    where
+        T: for<'c> RunNow<'c> + 'b,
+    {
+        self.dispatcher_builder = self
+            .dispatcher_builder
+            .with_thread_local(Scheduled::new(system, schedule));
+        self
+    }
 }
 
 impl<'a, 'b> DataInit<GameData<'a, 'b>> for GameDataBuilder<'a, 'b> {
@@ -4,5 +4,17 @@ pub use altv_sdk as sdk;
 pub use crate::core::ecs;
 
 pub mod app;
+pub mod client_event;
+pub mod entry;
+pub mod event_channel;
 pub mod game_data;
+pub mod panic;
+pub mod reload;
+pub mod rpc;
+pub mod schedule;
+pub mod scheduler;
+pub mod simulator;
 pub mod state;
+pub mod task;
+pub mod time;
+pub mod version;
@@ -0,0 +1,42 @@
+//! Reports panics through the alt:V log, so they show up in the server console with a backtrace.
+
+use crate::sdk::log;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::panic::{self, Location};
+use std::sync::Once;
+
+static INSTALL: Once = Once::new();
+
+/// Installs the panic hook once per library, the previous hook still runs afterwards.
+///
+/// The module and every resource library link their own copy of std, so both install it.
+pub fn install_hook() {
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            log::error(&format!(
+                "[Rust] {}\n{}",
+                describe(info.payload(), info.location()),
+                Backtrace::force_capture()
+            ));
+            previous(info);
+        }));
+    });
+}
+
+fn describe(payload: &(dyn Any + Send), location: Option<&Location>) -> String {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => (*message).to_owned(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "Box<dyn Any>".to_owned(),
+        },
+    };
+
+    match location {
+        Some(location) => format!("Panicked at '{}', {}", message, location),
+        None => format!("Panicked at '{}'", message),
+    }
+}
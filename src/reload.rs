@@ -0,0 +1,35 @@
+//! Keeps world data across a hot reload of the resource library.
+//!
+//! The reloaded library starts with a new world, so entities get new ids. Saved data should be
+//! keyed by `get_entity_base_object` instead, the base objects stay the same.
+
+use crate::core::ecs::World;
+
+type Save = Box<dyn Fn(&World) -> Vec<u8>>;
+type Restore = Box<dyn FnMut(&mut World, &[u8])>;
+
+pub(crate) struct ReloadHook {
+    save: Save,
+    restore: Restore,
+}
+
+impl ReloadHook {
+    pub fn new<S, R>(save: S, restore: R) -> Self
+    where
+        S: Fn(&World) -> Vec<u8> + 'static,
+        R: FnMut(&mut World, &[u8]) + 'static,
+    {
+        ReloadHook {
+            save: Box::new(save),
+            restore: Box::new(restore),
+        }
+    }
+
+    pub fn save(&self, world: &World) -> Vec<u8> {
+        (self.save)(world)
+    }
+
+    pub fn restore(&mut self, world: &mut World, data: &[u8]) {
+        (self.restore)(world, data)
+    }
+}
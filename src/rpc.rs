@@ -0,0 +1,355 @@
+//! Request/response calls between the server and clients on top of client script events.
+//!
+//! A call is sent as `rpc:call` with `[id, procedure, [args...]]` and answered with
+//! `rpc:result` carrying `[id, ok, value]`, where `value` is the error message if `ok` is false.
+//! Both sides use the same two events, so clients can call server procedures the same way.
+
+use crate::core::ecs::{Entity, World, WorldExt};
+use crate::core::AltResource;
+use crate::sdk::core;
+use crate::sdk::elements::CPlayer;
+use crate::sdk::events::CClientScriptEvent;
+use crate::sdk::mvalue::{FromArgs, MValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+pub const CALL_EVENT: &str = "rpc:call";
+pub const RESULT_EVENT: &str = "rpc:result";
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RpcError {
+    /// The client didn't answer before the timeout ran out.
+    Timeout,
+    /// The player disconnected before answering.
+    Disconnected,
+    /// The procedure failed on the other side, or the call could not be sent.
+    Failed(String),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Timeout => write!(f, "the call timed out"),
+            RpcError::Disconnected => write!(f, "the player disconnected"),
+            RpcError::Failed(e) => write!(f, "the call failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+pub type RpcResult = Result<MValue, RpcError>;
+
+type Callback = Box<dyn FnOnce(&mut World, RpcResult) + Send + Sync>;
+type Resolved = Box<dyn FnOnce(&mut World)>;
+
+#[derive(Default)]
+struct Slot {
+    result: Option<RpcResult>,
+    waker: Option<Waker>,
+}
+
+/// Resolves once the client answers, the call times out or the player disconnects.
+///
+/// Without an executor the result can be picked up in a later tick with `try_take`.
+pub struct RpcFuture {
+    slot: Arc<Mutex<Slot>>,
+}
+
+impl RpcFuture {
+    pub fn try_take(&self) -> Option<RpcResult> {
+        self.slot.lock().unwrap().result.take()
+    }
+}
+
+impl Future for RpcFuture {
+    type Output = RpcResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<RpcResult> {
+        let mut slot = self.slot.lock().unwrap();
+
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+enum Responder {
+    Future(Arc<Mutex<Slot>>),
+    Callback(Callback),
+}
+
+struct PendingCall {
+    player: Entity,
+    deadline: Instant,
+    responder: Responder,
+}
+
+struct OutgoingCall {
+    id: u64,
+    player: Entity,
+    procedure: String,
+    args: Vec<MValue>,
+}
+
+/// Outstanding server to client calls, inserted into the world by `ApplicationBuilder`.
+///
+/// Calls are sent after the current state or systems ran, results are delivered by the application.
+pub struct Rpc {
+    timeout: Duration,
+    next_id: u64,
+    pending: HashMap<u64, PendingCall>,
+    outgoing: Vec<OutgoingCall>,
+}
+
+impl Default for Rpc {
+    fn default() -> Self {
+        Rpc {
+            timeout: DEFAULT_TIMEOUT,
+            next_id: 1,
+            pending: HashMap::new(),
+            outgoing: Vec::new(),
+        }
+    }
+}
+
+impl Rpc {
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    pub fn call(&mut self, player: Entity, procedure: &str, args: Vec<MValue>) -> RpcFuture {
+        let slot = Arc::new(Mutex::new(Slot::default()));
+        self.insert(player, procedure, args, Responder::Future(slot.clone()));
+        RpcFuture { slot }
+    }
+
+    /// Like `call`, but runs `callback` with the world once the call is resolved.
+    pub fn call_then<F>(&mut self, player: Entity, procedure: &str, args: Vec<MValue>, callback: F)
+    where
+        F: FnOnce(&mut World, RpcResult) + Send + Sync + 'static,
+    {
+        self.insert(
+            player,
+            procedure,
+            args,
+            Responder::Callback(Box::new(callback)),
+        );
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn insert(&mut self, player: Entity, procedure: &str, args: Vec<MValue>, responder: Responder) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.pending.insert(
+            id,
+            PendingCall {
+                player,
+                deadline: core::now() + self.timeout,
+                responder,
+            },
+        );
+        self.outgoing.push(OutgoingCall {
+            id,
+            player,
+            procedure: procedure.to_owned(),
+            args,
+        });
+    }
+
+    fn resolve(&mut self, id: u64, result: RpcResult) -> Option<Resolved> {
+        match self.pending.remove(&id)?.responder {
+            Responder::Future(slot) => {
+                let mut slot = slot.lock().unwrap();
+                slot.result = Some(result);
+
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+
+                None
+            }
+            Responder::Callback(callback) => Some(Box::new(move |world| callback(world, result))),
+        }
+    }
+
+    fn resolve_where<P>(&mut self, result: RpcError, predicate: P) -> Vec<Resolved>
+    where
+        P: Fn(&PendingCall) -> bool,
+    {
+        let mut ids: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|(_, call)| predicate(call))
+            .map(|(id, _)| *id)
+            .collect();
+        ids.sort_unstable();
+
+        ids.into_iter()
+            .filter_map(|id| self.resolve(id, Err(result.clone())))
+            .collect()
+    }
+}
+
+fn run_callbacks(world: &mut World, callbacks: Vec<Resolved>) {
+    for callback in callbacks {
+        callback(world);
+    }
+}
+
+type Procedure = Box<dyn FnMut(&mut World, Entity, &[MValue]) -> Result<MValue, String>>;
+
+/// Server procedures callable by clients, registered per name.
+#[derive(Default)]
+pub struct Procedures {
+    procedures: HashMap<String, Procedure>,
+}
+
+impl Procedures {
+    pub fn new() -> Self {
+        Procedures::default()
+    }
+
+    pub fn insert<A, R, F>(&mut self, name: &str, mut procedure: F)
+    where
+        A: FromArgs,
+        R: Into<MValue>,
+        F: FnMut(&mut World, Entity, A) -> Result<R, String> + 'static,
+    {
+        self.procedures.insert(
+            name.to_owned(),
+            Box::new(move |world, player, args| {
+                let args = A::from_args(args).map_err(|e| format!("invalid arguments: {}", e))?;
+                procedure(world, player, args).map(Into::into)
+            }),
+        );
+    }
+
+    fn call(&mut self, world: &mut World, player: Entity, args: &[MValue]) {
+        let (id, name, args) = match <(u64, String, Vec<MValue>)>::from_args(args) {
+            Ok(call) => call,
+            Err(e) => {
+                altv_sdk::loge!("[Rust] Malformed rpc call: {}", e);
+                return;
+            }
+        };
+
+        let result = match self.procedures.get_mut(&name) {
+            Some(procedure) => procedure(world, player, &args),
+            None => Err(format!("unknown procedure {}", name)),
+        };
+
+        let response = match result {
+            Ok(value) => vec![MValue::Uint(id), MValue::Bool(true), value],
+            Err(e) => {
+                altv_sdk::loge!("[Rust] Rpc {} failed: {}", name, e);
+                vec![MValue::Uint(id), MValue::Bool(false), MValue::String(e)]
+            }
+        };
+
+        let alt = world.read_resource::<AltResource>();
+
+        if let Some(cplayer) = world.write_storage::<CPlayer>().get_mut(player) {
+            if let Err(e) = cplayer.emit(&alt, RESULT_EVENT, &response) {
+                altv_sdk::loge!("[Rust] Could not answer rpc {}: {}", name, e);
+            }
+        }
+    }
+}
+
+/// Handles `rpc:call` and `rpc:result`, returns false for every other client event.
+pub(crate) fn handle_event(
+    world: &mut World,
+    procedures: &mut Procedures,
+    event: &CClientScriptEvent,
+) -> bool {
+    match event.get_name() {
+        CALL_EVENT => procedures.call(world, event.get_target(), event.get_args()),
+        RESULT_EVENT => {
+            let (id, result) = match <(u64, bool, MValue)>::from_args(event.get_args()) {
+                Ok((id, true, value)) => (id, Ok(value)),
+                Ok((id, false, MValue::String(e))) => (id, Err(RpcError::Failed(e))),
+                Ok((id, false, value)) => (id, Err(RpcError::Failed(format!("{:?}", value)))),
+                Err(e) => {
+                    altv_sdk::loge!("[Rust] Malformed rpc result: {}", e);
+                    return true;
+                }
+            };
+
+            let callback = {
+                let mut rpc = world.write_resource::<Rpc>();
+
+                // Only the called player may answer.
+                match rpc.pending.get(&id) {
+                    Some(call) if call.player == event.get_target() => rpc.resolve(id, result),
+                    _ => return true,
+                }
+            };
+
+            run_callbacks(world, callback.into_iter().collect());
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// Cancels every call waiting on `player`.
+pub(crate) fn cancel_player(world: &mut World, player: Entity) {
+    let callbacks = world
+        .write_resource::<Rpc>()
+        .resolve_where(RpcError::Disconnected, |call| call.player == player);
+    run_callbacks(world, callbacks);
+}
+
+/// Sends queued calls and times out the ones past their deadline.
+pub(crate) fn maintain(world: &mut World) {
+    let outgoing = std::mem::take(&mut world.write_resource::<Rpc>().outgoing);
+    let mut callbacks = Vec::new();
+
+    for call in outgoing {
+        let sent = match world.write_storage::<CPlayer>().get_mut(call.player) {
+            // The args can hold entities the core doesn't know, e.g. ones removed this tick.
+            Some(cplayer) => cplayer
+                .emit(
+                    &world.read_resource::<AltResource>(),
+                    CALL_EVENT,
+                    &[
+                        MValue::Uint(call.id),
+                        MValue::String(call.procedure),
+                        MValue::List(call.args),
+                    ],
+                )
+                .map_err(|e| RpcError::Failed(e.to_string())),
+            None => Err(RpcError::Disconnected),
+        };
+
+        if let Err(e) = sent {
+            let mut rpc = world.write_resource::<Rpc>();
+            callbacks.extend(rpc.resolve(call.id, Err(e)));
+        }
+    }
+
+    let now = core::now();
+    callbacks.extend(
+        world
+            .write_resource::<Rpc>()
+            .resolve_where(RpcError::Timeout, |call| call.deadline <= now),
+    );
+
+    run_callbacks(world, callbacks);
+}
@@ -0,0 +1,107 @@
+use crate::core::ecs::{RunNow, World};
+use crate::time::Time;
+use std::time::Duration;
+
+/// How often a system added with `GameDataBuilder::with_scheduled` runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Schedule {
+    /// Runs the given number of times per second, which has to be positive and finite.
+    Hz(f64),
+    /// Runs once the given time has passed.
+    Interval(Duration),
+    /// Runs on every n-th tick.
+    Ticks(u64),
+}
+
+/// Wraps a system so it only runs when its schedule is due.
+///
+/// Time schedules keep the remainder of the elapsed time, so they don't drift with the tick rate,
+/// but the system still runs at most once per tick.
+pub(crate) struct Scheduled<T> {
+    system: T,
+    every: Every,
+    elapsed: Duration,
+    ticks: u64,
+}
+
+enum Every {
+    Interval(Duration),
+    Ticks(u64),
+}
+
+impl<T> Scheduled<T> {
+    /// Panics if a `Schedule::Hz` is not positive and finite.
+    pub fn new(system: T, schedule: Schedule) -> Self {
+        let every = match schedule {
+            Schedule::Hz(hz) => {
+                assert!(
+                    hz.is_finite() && hz > 0.0,
+                    "schedule rate has to be positive and finite, got {} Hz",
+                    hz
+                );
+                Every::Interval(Duration::from_secs_f64(1.0 / hz))
+            }
+            Schedule::Interval(interval) => Every::Interval(interval),
+            Schedule::Ticks(ticks) => Every::Ticks(ticks),
+        };
+
+        Scheduled {
+            system,
+            every,
+            elapsed: Duration::from_secs(0),
+            ticks: 0,
+        }
+    }
+
+    fn is_due(&mut self, delta: Duration) -> bool {
+        let interval = match self.every {
+            Every::Interval(interval) => interval,
+            Every::Ticks(ticks) => {
+                self.ticks += 1;
+
+                if self.ticks < ticks {
+                    return false;
+                }
+
+                self.ticks = 0;
+                return true;
+            }
+        };
+
+        self.elapsed += delta;
+
+        if self.elapsed < interval {
+            return false;
+        }
+
+        self.elapsed -= interval;
+
+        if self.elapsed >= interval {
+            self.elapsed =
+                Duration::from_nanos((self.elapsed.as_nanos() % interval.as_nanos().max(1)) as u64);
+        }
+
+        true
+    }
+}
+
+impl<'a, T> RunNow<'a> for Scheduled<T>
+where
+    T: RunNow<'a>,
+{
+    fn run_now(&mut self, world: &'a World) {
+        let delta = world.fetch::<Time>().delta();
+
+        if self.is_due(delta) {
+            self.system.run_now(world);
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        self.system.setup(world);
+    }
+
+    fn dispose(self: Box<Self>, world: &mut World) {
+        Box::new(self.system).dispose(world);
+    }
+}
@@ -0,0 +1,177 @@
+//! Delayed and repeating callbacks over the world, run by the application after the systems.
+
+use crate::core::ecs::{Entity, World, WorldExt};
+use crate::sdk::core;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+type Callback = Box<dyn FnMut(&mut World) + Send + Sync>;
+
+/// Identifies a timer, used to cancel it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerHandle(u64);
+
+struct Timer {
+    due: Instant,
+    interval: Option<Duration>,
+    owner: Option<Entity>,
+    // Taken out while the callback runs, so it can cancel its own timer.
+    callback: Option<Callback>,
+}
+
+/// Timers inserted into the world by `ApplicationBuilder`.
+///
+/// A timer runs at most once per tick, timers tied to an entity are dropped once it is removed.
+pub struct Scheduler {
+    next_id: u64,
+    timers: HashMap<u64, Timer>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Scheduler {
+            next_id: 1,
+            timers: HashMap::new(),
+        }
+    }
+}
+
+impl Scheduler {
+    /// Runs `callback` once after `delay`.
+    pub fn after<F>(&mut self, delay: Duration, callback: F) -> TimerHandle
+    where
+        F: FnOnce(&mut World) + Send + Sync + 'static,
+    {
+        self.insert(delay, None, None, once(callback))
+    }
+
+    /// Runs `callback` every `interval` until the timer is cancelled.
+    pub fn every<F>(&mut self, interval: Duration, callback: F) -> TimerHandle
+    where
+        F: FnMut(&mut World) + Send + Sync + 'static,
+    {
+        self.insert(interval, Some(interval), None, Box::new(callback))
+    }
+
+    /// Like `after`, but the timer is dropped if `owner` is removed first.
+    pub fn after_for<F>(&mut self, owner: Entity, delay: Duration, callback: F) -> TimerHandle
+    where
+        F: FnOnce(&mut World) + Send + Sync + 'static,
+    {
+        self.insert(delay, None, Some(owner), once(callback))
+    }
+
+    /// Like `every`, but the timer is dropped once `owner` is removed.
+    pub fn every_for<F>(&mut self, owner: Entity, interval: Duration, callback: F) -> TimerHandle
+    where
+        F: FnMut(&mut World) + Send + Sync + 'static,
+    {
+        self.insert(interval, Some(interval), Some(owner), Box::new(callback))
+    }
+
+    /// Cancels the timer, returns false if it already ran or was cancelled.
+    pub fn cancel(&mut self, handle: TimerHandle) -> bool {
+        self.timers.remove(&handle.0).is_some()
+    }
+
+    pub fn is_scheduled(&self, handle: TimerHandle) -> bool {
+        self.timers.contains_key(&handle.0)
+    }
+
+    pub fn pending(&self) -> usize {
+        self.timers.len()
+    }
+
+    fn insert(
+        &mut self,
+        delay: Duration,
+        interval: Option<Duration>,
+        owner: Option<Entity>,
+        callback: Callback,
+    ) -> TimerHandle {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.timers.insert(
+            id,
+            Timer {
+                due: core::now() + delay,
+                interval,
+                owner,
+                callback: Some(callback),
+            },
+        );
+
+        TimerHandle(id)
+    }
+}
+
+fn once<F>(callback: F) -> Callback
+where
+    F: FnOnce(&mut World) + Send + Sync + 'static,
+{
+    let mut callback = Some(callback);
+    Box::new(move |world| {
+        if let Some(callback) = callback.take() {
+            callback(world);
+        }
+    })
+}
+
+pub(crate) fn cancel_entity(world: &World, entity: Entity) {
+    world
+        .write_resource::<Scheduler>()
+        .timers
+        .retain(|_, timer| timer.owner != Some(entity));
+}
+
+pub(crate) fn run(world: &mut World) {
+    let now = core::now();
+
+    let mut due: Vec<_> = world
+        .read_resource::<Scheduler>()
+        .timers
+        .iter()
+        .filter(|(_, timer)| timer.due <= now)
+        .map(|(id, timer)| (timer.due, *id))
+        .collect();
+    due.sort_unstable();
+
+    for (_, id) in due {
+        let (owner, callback) = match world.write_resource::<Scheduler>().timers.get_mut(&id) {
+            Some(timer) => (timer.owner, timer.callback.take()),
+            None => continue,
+        };
+
+        let mut callback = match callback {
+            Some(callback) => callback,
+            None => continue,
+        };
+
+        // The owner may have been deleted without going through `remove_game_object`.
+        if owner.map(|owner| !world.is_alive(owner)).unwrap_or(false) {
+            world.write_resource::<Scheduler>().timers.remove(&id);
+            continue;
+        }
+
+        callback(world);
+
+        let mut scheduler = world.write_resource::<Scheduler>();
+        let interval = match scheduler.timers.get(&id).and_then(|timer| timer.interval) {
+            Some(interval) => interval,
+            None => {
+                scheduler.timers.remove(&id);
+                continue;
+            }
+        };
+
+        let timer = scheduler.timers.get_mut(&id).unwrap();
+        timer.due += interval;
+
+        if timer.due <= now {
+            timer.due = now + interval;
+        }
+
+        timer.callback = Some(callback);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::app::CoreApplication;
+use crate::core::ecs::{Entity, Join, World, WorldExt};
+use crate::core::AltResource;
+use crate::sdk::backend::{CoreBackend, MockCore};
+use crate::sdk::elements::{CBaseObject, CCollisionShape, CEntity, CPlayer, CWorldObject};
+use crate::sdk::events::{
+    CClientScriptEvent, CCollisionShapeEvent, CEvent, CPlayerConnectEvent, CPlayerDamageEvent,
+    CPlayerDeathEvent, CPlayerDisconnectEvent,
+};
+use crate::sdk::mvalue::MValue;
+use crate::sdk::natives::*;
+use crate::sdk::vector::Vector3;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SentClientEvent {
+    pub target: Option<Entity>,
+    pub name: String,
+    pub args: Vec<MValue>,
+}
+
+/// Drives a `CoreApplication` running on a `MockCore` through a scripted timeline.
+///
+/// Every step behaves like the server would: objects are announced through
+/// `create_game_object`, events go through the current state and time only moves on `advance`.
+pub struct Simulator {
+    core: Rc<MockCore>,
+    application: CoreApplication,
+}
+
+impl Simulator {
+    pub fn new(core: Rc<MockCore>, application: CoreApplication) -> Self {
+        Simulator { core, application }
+    }
+
+    pub fn core(&self) -> &MockCore {
+        &self.core
+    }
+
+    pub fn application(&mut self) -> &mut CoreApplication {
+        &mut self.application
+    }
+
+    pub fn world(&self) -> &World {
+        self.application.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.application.world_mut()
+    }
+
+    pub fn start(&mut self) {
+        self.application.start();
+    }
+
+    pub fn stop(&mut self) {
+        self.application.stop();
+    }
+
+    pub fn tick(&mut self) {
+        self.application.tick();
+    }
+
+    /// Moves the mock clock forward and runs a single tick.
+    pub fn advance(&mut self, duration: Duration) {
+        self.core.advance_time(duration);
+        self.tick();
+    }
+
+    /// Ticks every `step` until `duration` has passed.
+    pub fn run_for(&mut self, duration: Duration, step: Duration) {
+        let mut elapsed = Duration::from_secs(0);
+
+        while elapsed < duration {
+            self.advance(step);
+            elapsed += step;
+        }
+    }
+
+    /// Dispatches the event, returns whether it was cancelled.
+    pub fn dispatch(&mut self, event: CEvent) -> bool {
+        self.application.dispatch_event(event)
+    }
+
+    /// Connects a player, if the connect event gets cancelled the player is dropped again.
+    pub fn connect_player(&mut self, name: &str) -> Entity {
+        let base_obj = self.core.create_player(name);
+        self.application.create_game_object(base_obj);
+
+        let player = self.core.base_object_to_player(base_obj);
+        let entity = *self
+            .world()
+            .read_resource::<AltResource>()
+            .players
+            .get(&(player as usize))
+            .unwrap();
+
+        let cancelled = self.dispatch(CEvent::PlayerConnect(CPlayerConnectEvent::new(
+            entity,
+            String::new(),
+        )));
+
+        if cancelled {
+            self.application.remove_game_object(base_obj);
+            self.core.remove_player(base_obj);
+        }
+
+        entity
+    }
+
+    pub fn disconnect_player(&mut self, player: Entity, reason: &str) {
+        let base_obj = self.get_base_object(player);
+
+        self.dispatch(CEvent::PlayerDisconnect(CPlayerDisconnectEvent::new(
+            player,
+            reason.to_owned(),
+        )));
+
+        self.application.remove_game_object(base_obj);
+        self.core.remove_player(base_obj);
+    }
+
+    /// Teleports an entity and fires collision shape events for every shape it entered or left.
+    pub fn move_entity(&mut self, entity: Entity, position: Vector3) {
+        let centity = self.get_entity(entity);
+        let inside = self.get_collision_shapes_containing(centity);
+
+        let world_obj = unsafe {
+            self.world()
+                .read_storage::<CWorldObject>()
+                .get(entity)
+                .unwrap()
+                .handle()
+                .as_ptr()
+        };
+        self.core.world_object_set_position(world_obj, position);
+
+        let now_inside = self.get_collision_shapes_containing(centity);
+
+        for target in inside.iter().filter(|cs| !now_inside.contains(cs)) {
+            self.fire_collision_shape_event(*target, entity, false);
+        }
+
+        for target in now_inside.iter().filter(|cs| !inside.contains(cs)) {
+            self.fire_collision_shape_event(*target, entity, true);
+        }
+    }
+
+    /// Applies damage the way the game would and fires `PlayerDeath` once health runs out.
+    pub fn damage_player(
+        &mut self,
+        target: Entity,
+        attacker: Option<Entity>,
+        damage: u16,
+        weapon: u32,
+    ) {
+        let player = self.get_player(target);
+        let was_dead = self.core.player_is_dead(player);
+        let health = self.core.player_get_health(player).saturating_sub(damage);
+        self.core.player_set_health(player, health);
+
+        self.dispatch(CEvent::PlayerDamage(CPlayerDamageEvent::new(
+            target, attacker, damage, weapon,
+        )));
+
+        if !was_dead && self.core.player_is_dead(player) {
+            self.dispatch(CEvent::PlayerDeath(CPlayerDeathEvent::new(
+                target, attacker, weapon,
+            )));
+        }
+    }
+
+    pub fn client_script_event(&mut self, player: Entity, name: &str, args: Vec<MValue>) {
+        self.dispatch(CEvent::ClientScript(CClientScriptEvent::new(
+            player,
+            name.to_owned(),
+            args,
+        )));
+    }
+
+    pub fn fire_collision_shape_event(&mut self, target: Entity, entity: Entity, state: bool) {
+        self.dispatch(CEvent::CollisionShapeEvent(CCollisionShapeEvent::new(
+            target, entity, state,
+        )));
+    }
+
+    /// Returns the client events sent since the last call, with targets resolved to entities.
+    pub fn take_client_events(&self) -> Vec<SentClientEvent> {
+        let alt = self.world().read_resource::<AltResource>();
+
+        self.core
+            .take_client_events()
+            .into_iter()
+            .map(|event| SentClientEvent {
+                target: event
+                    .target
+                    .and_then(|player| alt.players.get(&(player as usize)).copied()),
+                name: event.name,
+                args: event.args,
+            })
+            .collect()
+    }
+
+    // The pointers are only handed to the mock core while the world still holds their handles.
+    fn get_base_object(&self, entity: Entity) -> *mut alt_IBaseObject {
+        unsafe {
+            self.world()
+                .read_storage::<CBaseObject>()
+                .get(entity)
+                .unwrap()
+                .handle()
+                .as_ptr()
+        }
+    }
+
+    fn get_entity(&self, entity: Entity) -> *mut alt_IEntity {
+        unsafe {
+            self.world()
+                .read_storage::<CEntity>()
+                .get(entity)
+                .unwrap()
+                .handle()
+                .as_ptr()
+        }
+    }
+
+    fn get_player(&self, entity: Entity) -> *mut alt_IPlayer {
+        unsafe {
+            self.world()
+                .read_storage::<CPlayer>()
+                .get(entity)
+                .unwrap()
+                .handle()
+                .as_ptr()
+        }
+    }
+
+    fn get_collision_shapes_containing(&self, entity: *mut alt_IEntity) -> Vec<Entity> {
+        let entities = self.world().entities();
+        let ccollision_shapes = self.world().read_storage::<CCollisionShape>();
+
+        (&entities, &ccollision_shapes)
+            .join()
+            .filter(|(_, ccollision_shape)| {
+                self.core
+                    .col_shape_is_entity_in(unsafe { ccollision_shape.handle().as_ptr() }, entity)
+            })
+            .map(|(cs, _)| cs)
+            .collect()
+    }
+}
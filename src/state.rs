@@ -1,21 +1,144 @@
+use crate::core::ecs::World;
 use crate::game_data::{GameData, StateData};
 use crate::sdk::events::CEvent;
 
+/// A transition between states, returned from the state callbacks.
+pub enum Trans {
+    /// Stays in the current state.
+    None,
+    /// Pauses the current state and starts the given one on top of it.
+    Push(Box<dyn State>),
+    /// Stops the current state and resumes the one below it.
+    Pop,
+    /// Stops the current state and starts the given one in its place.
+    Switch(Box<dyn State>),
+    /// Stops every state on the stack.
+    Quit,
+}
+
 pub trait State {
-    fn on_start(&mut self, data: StateData<GameData>) {
+    fn on_start(&mut self, data: StateData<GameData>) -> Trans {
         let _ = data;
+        Trans::None
     }
 
     fn on_stop(&mut self, data: StateData<GameData>) {
         let _ = data;
     }
 
-    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) {
+    /// Called when another state is pushed on top of this one.
+    fn on_pause(&mut self, data: StateData<GameData>) {
+        let _ = data;
+    }
+
+    /// Called when the state above this one was popped.
+    fn on_resume(&mut self, data: StateData<GameData>) {
+        let _ = data;
+    }
+
+    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) -> Trans {
         let _ = data;
         let _ = event;
+        Trans::None
     }
 
-    fn tick(&mut self, data: StateData<GameData>) {
+    fn tick(&mut self, data: StateData<GameData>) -> Trans {
         let _ = data;
+        Trans::None
+    }
+}
+
+/// A stack of states, only the state on top receives events and ticks.
+pub(crate) struct StateMachine {
+    initial: Option<Box<dyn State>>,
+    stack: Vec<Box<dyn State>>,
+}
+
+impl StateMachine {
+    pub fn new(initial: Box<dyn State>) -> Self {
+        StateMachine {
+            initial: Some(initial),
+            stack: Vec::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.stack.is_empty()
+    }
+
+    /// Starts the bottom state again if the machine was stopped before.
+    pub fn start(&mut self, world: &mut World, data: &mut GameData) {
+        if let Some(state) = self.initial.take() {
+            self.push(world, data, state);
+        }
+    }
+
+    pub fn stop(&mut self, world: &mut World, data: &mut GameData) {
+        while !self.stack.is_empty() {
+            self.pop(world, data);
+        }
+    }
+
+    pub fn handle_event(&mut self, world: &mut World, data: &mut GameData, event: CEvent) {
+        let trans = match self.stack.last_mut() {
+            Some(state) => state.handle_event(StateData::new(world, data), event),
+            None => return,
+        };
+
+        self.transition(world, data, trans);
+    }
+
+    pub fn tick(&mut self, world: &mut World, data: &mut GameData) {
+        let trans = match self.stack.last_mut() {
+            Some(state) => state.tick(StateData::new(world, data)),
+            None => return,
+        };
+
+        self.transition(world, data, trans);
+    }
+
+    fn transition(&mut self, world: &mut World, data: &mut GameData, trans: Trans) {
+        match trans {
+            Trans::None => {}
+            Trans::Push(state) => {
+                if let Some(current) = self.stack.last_mut() {
+                    current.on_pause(StateData::new(world, data));
+                }
+
+                self.push(world, data, state);
+            }
+            Trans::Pop => {
+                self.pop(world, data);
+
+                if let Some(state) = self.stack.last_mut() {
+                    state.on_resume(StateData::new(world, data));
+                }
+            }
+            Trans::Switch(state) => {
+                if let Some(mut current) = self.stack.pop() {
+                    current.on_stop(StateData::new(world, data));
+                }
+
+                self.push(world, data, state);
+            }
+            Trans::Quit => self.stop(world, data),
+        }
+    }
+
+    /// Stops the state on top, the bottom one is kept so `start` can run it again.
+    fn pop(&mut self, world: &mut World, data: &mut GameData) {
+        if let Some(mut state) = self.stack.pop() {
+            state.on_stop(StateData::new(world, data));
+
+            if self.stack.is_empty() {
+                self.initial = Some(state);
+            }
+        }
+    }
+
+    fn push(&mut self, world: &mut World, data: &mut GameData, mut state: Box<dyn State>) {
+        let trans = state.on_start(StateData::new(world, data));
+        self.stack.push(state);
+        self.transition(world, data, trans);
     }
 }
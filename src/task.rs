@@ -0,0 +1,195 @@
+//! Futures polled on the main thread from `CoreApplication::tick`.
+//!
+//! Futures never hold the world, they go through a `WorldSender` instead: `send` posts a closure
+//! that runs with the world on the next tick and `run` returns its result as a future.
+//! The sender can be cloned into background threads, so blocking work can post results back.
+
+use crate::core::ecs::{World, WorldExt};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+type Job = Box<dyn FnOnce(&mut World) + Send>;
+type BoxedTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Posts closures into the world from any thread.
+#[derive(Clone)]
+pub struct WorldSender {
+    sender: Arc<Mutex<Sender<Job>>>,
+}
+
+impl WorldSender {
+    /// Runs `job` with the world on the next tick, returns false if the application is gone.
+    pub fn send<F>(&self, job: F) -> bool
+    where
+        F: FnOnce(&mut World) + Send + 'static,
+    {
+        self.sender.lock().unwrap().send(Box::new(job)).is_ok()
+    }
+
+    /// Like `send`, but the returned future resolves with the result of `job`.
+    pub fn run<F, R>(&self, job: F) -> WorldFuture<R>
+    where
+        F: FnOnce(&mut World) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(Slot::default()));
+        let result = slot.clone();
+
+        self.send(move |world| {
+            let mut slot = result.lock().unwrap();
+            slot.result = Some(job(world));
+
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        });
+
+        WorldFuture { slot }
+    }
+}
+
+struct Slot<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+}
+
+impl<R> Default for Slot<R> {
+    fn default() -> Self {
+        Slot {
+            result: None,
+            waker: None,
+        }
+    }
+}
+
+/// Resolves once the closure passed to `WorldSender::run` ran, never if the application is gone.
+pub struct WorldFuture<R> {
+    slot: Arc<Mutex<Slot<R>>>,
+}
+
+impl<R> Future for WorldFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut slot = self.slot.lock().unwrap();
+
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Spawns futures onto the executor, inserted into the world by `ApplicationBuilder`.
+pub struct Tasks {
+    spawned: Mutex<Vec<BoxedTask>>,
+    sender: WorldSender,
+}
+
+impl Tasks {
+    /// Polls `future` on the main thread, starting with the next tick.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawned.lock().unwrap().push(Box::pin(future));
+    }
+
+    pub fn sender(&self) -> WorldSender {
+        self.sender.clone()
+    }
+}
+
+struct TaskWaker {
+    id: u64,
+    ready: Arc<Mutex<Vec<u64>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.ready.lock().unwrap().push(self.id);
+    }
+}
+
+/// Owns the spawned futures and the receiving end of the world channel.
+pub(crate) struct Executor {
+    next_id: u64,
+    tasks: HashMap<u64, BoxedTask>,
+    ready: Arc<Mutex<Vec<u64>>>,
+    receiver: Receiver<Job>,
+}
+
+impl Executor {
+    pub fn new() -> (Self, Tasks) {
+        let (sender, receiver) = mpsc::channel();
+
+        let executor = Executor {
+            next_id: 1,
+            tasks: HashMap::new(),
+            ready: Arc::new(Mutex::new(Vec::new())),
+            receiver,
+        };
+        let tasks = Tasks {
+            spawned: Mutex::new(Vec::new()),
+            sender: WorldSender {
+                sender: Arc::new(Mutex::new(sender)),
+            },
+        };
+
+        (executor, tasks)
+    }
+
+    /// Runs the posted closures and polls every task that was woken since the last run.
+    pub fn run(&mut self, world: &mut World) {
+        self.run_jobs(world);
+
+        let spawned = std::mem::take(&mut *world.read_resource::<Tasks>().spawned.lock().unwrap());
+        for task in spawned {
+            let id = self.next_id;
+            self.next_id += 1;
+
+            self.tasks.insert(id, task);
+            self.ready.lock().unwrap().push(id);
+        }
+
+        let mut ready = std::mem::take(&mut *self.ready.lock().unwrap());
+        ready.sort_unstable();
+        ready.dedup();
+
+        for id in ready {
+            let task = match self.tasks.get_mut(&id) {
+                Some(task) => task,
+                None => continue,
+            };
+
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id,
+                ready: self.ready.clone(),
+            }));
+
+            if task
+                .as_mut()
+                .poll(&mut Context::from_waker(&waker))
+                .is_ready()
+            {
+                self.tasks.remove(&id);
+            }
+        }
+
+        // Closures posted while polling shouldn't wait for the next tick.
+        self.run_jobs(world);
+    }
+
+    fn run_jobs(&self, world: &mut World) {
+        while let Ok(job) = self.receiver.try_recv() {
+            job(world);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::sdk::core;
+use std::time::{Duration, Instant};
+
+/// Timing of the server ticks, updated by the application before each tick.
+pub struct Time {
+    started_at: Instant,
+    last_tick: Instant,
+    delta: Duration,
+    tick_count: u64,
+}
+
+impl Time {
+    pub fn new(now: Instant) -> Self {
+        Time {
+            started_at: now,
+            last_tick: now,
+            delta: Duration::from_secs(0),
+            tick_count: 0,
+        }
+    }
+
+    /// Time passed between the previous and the current tick.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Number of ticks since the resource started, the current tick included.
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Time passed since the resource started.
+    pub fn uptime(&self) -> Duration {
+        self.last_tick - self.started_at
+    }
+
+    pub(crate) fn update(&mut self, now: Instant) {
+        self.delta = now - self.last_tick;
+        self.last_tick = now;
+        self.tick_count += 1;
+    }
+}
+
+impl Default for Time {
+    fn default() -> Self {
+        Time::new(core::now())
+    }
+}
@@ -0,0 +1,63 @@
+//! Build information the module checks before calling into a resource library.
+//!
+//! The application crosses the library boundary as a Rust type, so the module only accepts
+//! resources built against the same `altv` version and alt:V SDK.
+
+use crate::sdk::core::SDK_VERSION;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Symbol of the `BuildInfoFn` exported by `resource!`.
+pub const BUILD_INFO_SYMBOL: &[u8] = b"altv_rs_build_info\0";
+/// Symbol of the `ResourceMainFn` exported by `resource!`.
+pub const MAIN_SYMBOL: &[u8] = b"altv_main\0";
+/// Symbol of the `EntryPointsFn` exported by `resource!`.
+pub const ENTRY_POINTS_SYMBOL: &[u8] = b"altv_rs_entry_points\0";
+
+pub type BuildInfoFn = extern "C" fn() -> BuildInfo;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BuildInfo {
+    sdk_version: u32,
+    version: *const u8,
+    version_len: usize,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        BuildInfo {
+            sdk_version: SDK_VERSION,
+            version: VERSION.as_ptr(),
+            version_len: VERSION.len(),
+        }
+    }
+
+    pub fn sdk_version(&self) -> u32 {
+        self.sdk_version
+    }
+
+    /// Returns the `altv` version.
+    ///
+    /// # Safety
+    ///
+    /// The version points into the library that built the info, it must still be loaded.
+    pub unsafe fn version(&self) -> String {
+        let bytes = std::slice::from_raw_parts(self.version, self.version_len);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+
+    /// Returns whether the info was built against this `altv` version and alt:V SDK.
+    ///
+    /// # Safety
+    ///
+    /// See `version`.
+    pub unsafe fn is_compatible(&self) -> bool {
+        is_compatible(self.sdk_version, &self.version())
+    }
+}
+
+/// Returns whether a resource built against `version` and `sdk_version` can be loaded.
+pub fn is_compatible(sdk_version: u32, version: &str) -> bool {
+    sdk_version == SDK_VERSION && version == VERSION
+}
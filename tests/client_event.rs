@@ -0,0 +1,106 @@
+mod common;
+
+use altv::ecs::{Entity, WorldExt};
+use altv::game_data::GameDataBuilder;
+use altv::sdk::backend::mock::LogLevel;
+use altv::sdk::mvalue::MValue;
+use altv::sdk::vector::Vector3;
+use common::EmptyState;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct Chat(Vec<(Entity, String)>);
+
+#[test]
+fn typed_handlers() {
+    let teleports = Rc::new(RefCell::new(Vec::new()));
+    let mut simulator = common::simulator_with(Box::new(EmptyState), |builder| {
+        builder
+            .with_client_event("chatmessage", |world, player, (msg,): (String,)| {
+                world
+                    .entry::<Chat>()
+                    .or_insert_with(Chat::default)
+                    .0
+                    .push((player, msg));
+            })
+            .with_client_event("teleport", {
+                let teleports = teleports.clone();
+                move |_, player, (position, dimension): (Vector3, Option<i32>)| {
+                    teleports.borrow_mut().push((player, position, dimension));
+                }
+            })
+            .build(GameDataBuilder::new())
+    });
+    let player = simulator.connect_player("rust");
+
+    simulator.client_script_event(
+        player,
+        "chatmessage",
+        vec![MValue::String("hello".to_owned())],
+    );
+    simulator.client_script_event(
+        player,
+        "teleport",
+        vec![MValue::Vector3(Vector3::new(1.0, 2.0, 3.0)), MValue::Int(4)],
+    );
+    simulator.client_script_event(
+        player,
+        "teleport",
+        vec![MValue::Vector3(Vector3::new(1.0, 2.0, 3.0)), MValue::Nil],
+    );
+
+    assert_eq!(
+        simulator.world().read_resource::<Chat>().0,
+        vec![(player, "hello".to_owned())]
+    );
+    assert_eq!(
+        *teleports.borrow(),
+        vec![
+            (player, Vector3::new(1.0, 2.0, 3.0), Some(4)),
+            (player, Vector3::new(1.0, 2.0, 3.0), None)
+        ]
+    );
+}
+
+#[test]
+fn invalid_arguments_are_logged() {
+    let calls = Rc::new(RefCell::new(0));
+    let mut simulator = common::simulator_with(Box::new(EmptyState), |builder| {
+        builder
+            .with_client_event("give", {
+                let calls = calls.clone();
+                move |_, _, (_weapon, _ammo): (u32, u16)| {
+                    *calls.borrow_mut() += 1;
+                }
+            })
+            .build(GameDataBuilder::new())
+    });
+    let player = simulator.connect_player("rust");
+
+    simulator.client_script_event(player, "give", vec![MValue::Uint(1)]);
+    simulator.client_script_event(
+        player,
+        "give",
+        vec![MValue::Uint(1), MValue::String("all".to_owned())],
+    );
+    simulator.client_script_event(player, "give", vec![MValue::Uint(1), MValue::Int(-1)]);
+
+    assert_eq!(*calls.borrow(), 0);
+
+    let errors: Vec<_> = simulator
+        .core()
+        .get_logs()
+        .into_iter()
+        .filter(|(level, _)| *level == LogLevel::Error)
+        .map(|(_, msg)| msg)
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            "[Rust] Invalid arguments for client event give: expected 2 arguments, got 1",
+            "[Rust] Invalid arguments for client event give: argument 1: expected u16, got \"all\"",
+            "[Rust] Invalid arguments for client event give: argument 1: -1L is out of range for u16",
+        ]
+    );
+}
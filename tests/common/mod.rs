@@ -0,0 +1,41 @@
+//! Fixtures shared by the integration tests, each test crate only uses some of them.
+#![allow(dead_code)]
+
+use altv::app::{ApplicationBuilder, CoreApplication};
+use altv::game_data::GameDataBuilder;
+use altv::sdk::backend::MockCore;
+use altv::simulator::Simulator;
+use altv::state::State;
+use std::rc::Rc;
+
+pub struct EmptyState;
+
+impl State for EmptyState {}
+
+/// Builds and starts an application without systems on `core`.
+pub fn application(core: &Rc<MockCore>) -> CoreApplication {
+    let mut application = ApplicationBuilder::with_backend(core.clone(), Box::new(EmptyState))
+        .build(GameDataBuilder::new());
+    application.start();
+    application
+}
+
+/// Starts an application without systems in a simulator on a new mock core.
+pub fn simulator() -> Simulator {
+    simulator_with(Box::new(EmptyState), |builder| {
+        builder.build(GameDataBuilder::new())
+    })
+}
+
+/// Starts the application `build` makes from a builder for `state` in a simulator on a new mock
+/// core.
+pub fn simulator_with<F>(state: Box<dyn State>, build: F) -> Simulator
+where
+    F: FnOnce(ApplicationBuilder) -> CoreApplication,
+{
+    let core = Rc::new(MockCore::new());
+    let application = build(ApplicationBuilder::with_backend(core.clone(), state));
+    let mut simulator = Simulator::new(core, application);
+    simulator.start();
+    simulator
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use altv::app::CoreApplication;
+use altv::core::AltResource;
+use altv::ecs::{Entity, WorldExt};
+use altv::sdk::backend::MockCore;
+use altv::sdk::elements::*;
+use altv::sdk::rgba::Rgba;
+use altv::sdk::vector::Vector3;
+use altv::sdk::Error;
+use std::rc::Rc;
+
+fn create_player(core: &MockCore, application: &mut CoreApplication, name: &str) -> Entity {
+    let base_obj = core.create_player(name);
+    application.create_game_object(base_obj);
+
+    get_base_object_entity(
+        &application.world().read_resource::<AltResource>(),
+        base_obj,
+    )
+    .unwrap()
+}
+
+#[test]
+fn blips_checkpoints_and_voice_channels() {
+    let core = Rc::new(MockCore::new());
+    let mut application = common::application(&core);
+    let player = create_player(&core, &mut application, "rust");
+
+    let world = application.world();
+    let checkpoint = create_checkpoint(
+        world,
+        CheckpointType::CylinderCheckerboard,
+        Vector3::new(10.0, 0.0, 0.0),
+        2.0,
+        5.0,
+        Rgba::new(255, 0, 0, 255),
+    )
+    .unwrap();
+    let global = create_blip(world, None, BlipType::Destination, Vector3::zeros()).unwrap();
+    let private = create_blip(world, Some(player), BlipType::Area, Vector3::zeros()).unwrap();
+    let attached = create_blip_attached(world, None, BlipType::Ped, player).unwrap();
+    let voice_channel = create_voice_channel(world, true, 20.0).unwrap();
+
+    let alt = world.read_resource::<AltResource>();
+    let cblips = world.read_storage::<CBlip>();
+    assert_eq!(alt.blips.len(), 3);
+    assert!(cblips.get(global).unwrap().is_global());
+    assert_eq!(cblips.get(private).unwrap().get_target(&alt), Some(player));
+    assert_eq!(cblips.get(private).unwrap().get_blip_type(), BlipType::Area);
+    assert!(cblips.get(attached).unwrap().is_attached());
+    assert_eq!(
+        cblips.get(attached).unwrap().attached_to(&alt),
+        Some(player)
+    );
+
+    let ccheckpoints = world.read_storage::<CCheckpoint>();
+    assert_eq!(
+        ccheckpoints.get(checkpoint).unwrap().get_type(),
+        Some(CheckpointType::CylinderCheckerboard)
+    );
+
+    let cvoice_channels = world.read_storage::<CVoiceChannel>();
+    assert!(cvoice_channels.get(voice_channel).unwrap().is_spatial());
+}
+
+#[test]
+fn element_errors() {
+    let core = Rc::new(MockCore::new());
+    let mut application = common::application(&core);
+    let player = create_player(&core, &mut application, "rust");
+    let world = application.world_mut();
+    let cs = create_collision_shape_circle(world, Vector3::zeros(), 1.0).unwrap();
+
+    assert_eq!(
+        create_blip(world, Some(cs), BlipType::Area, Vector3::zeros()),
+        Err(Error::WrongType {
+            entity: cs,
+            expected: "player"
+        })
+    );
+
+    assert_eq!(delete(world, cs), Ok(()));
+    assert_eq!(delete(world, cs), Err(Error::EntityNotFound(cs)));
+    assert_eq!(
+        create_blip_attached(world, Some(player), BlipType::Ped, cs),
+        Err(Error::EntityNotFound(cs))
+    );
+}
+
+#[test]
+fn handles_outlive_removed_objects() {
+    let core = Rc::new(MockCore::new());
+    let mut application = common::application(&core);
+    let world = application.world_mut();
+    let cs = create_collision_shape_sphere(world, Vector3::zeros(), 1.0).unwrap();
+    let handle = world
+        .read_storage::<CBaseObject>()
+        .get(cs)
+        .unwrap()
+        .handle()
+        .clone();
+    let base_obj = unsafe { handle.as_ptr() };
+
+    delete(world, cs).unwrap();
+    assert!(!handle.is_valid());
+    assert!(!core.exists(base_obj));
+    assert!(core.is_allocated(base_obj));
+
+    drop(handle);
+    assert!(!core.is_allocated(base_obj));
+}
+
+#[test]
+fn move_collision_shapes_checkpoints_and_blips() {
+    let core = Rc::new(MockCore::new());
+    let application = common::application(&core);
+    let world = application.world();
+    let cs = create_collision_shape_cylinder(world, Vector3::zeros(), 1.0, 2.0).unwrap();
+    let checkpoint = create_checkpoint(
+        world,
+        CheckpointType::RingCar,
+        Vector3::zeros(),
+        2.0,
+        5.0,
+        Rgba::new(0, 0, 255, 255),
+    )
+    .unwrap();
+    let blip = create_blip(world, None, BlipType::Destination, Vector3::zeros()).unwrap();
+
+    let mut ccollision_shapes = world.write_storage::<CCollisionShape>();
+    let ccs = ccollision_shapes.get_mut(cs).unwrap();
+    assert_eq!(ccs.get_type(), CollisionShapeType::Cylinder);
+    ccs.set_position(Vector3::new(50.0, 0.0, 0.0));
+    ccs.set_dimension(2);
+    assert!(ccs.is_point_in(Vector3::new(50.0, 0.0, 1.0)));
+    assert!(!ccs.is_point_in(Vector3::zeros()));
+    assert_eq!(ccs.get_dimension(), 2);
+
+    let mut ccheckpoints = world.write_storage::<CCheckpoint>();
+    let ccheckpoint = ccheckpoints.get_mut(checkpoint).unwrap();
+    assert_eq!(
+        ccheckpoint.get_colshape_type(),
+        CollisionShapeType::CheckpointCylinder
+    );
+    ccheckpoint.set_position(Vector3::new(0.0, 100.0, 0.0));
+    assert_eq!(ccheckpoint.get_position(), Vector3::new(0.0, 100.0, 0.0));
+    assert!(ccheckpoint.is_point_in(Vector3::new(0.0, 101.0, 1.0)));
+
+    let mut cblips = world.write_storage::<CBlip>();
+    let cblip = cblips.get_mut(blip).unwrap();
+    cblip.set_position(Vector3::new(1.0, 2.0, 3.0));
+    cblip.set_dimension(-1);
+    assert_eq!(cblip.get_position(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(cblip.get_dimension(), -1);
+    assert!(!cblip.is_attached());
+}
+
+#[test]
+fn handles_dropped_on_other_threads_are_released_on_tick() {
+    let core = Rc::new(MockCore::new());
+    let mut application = common::application(&core);
+    let cs = create_collision_shape_sphere(application.world(), Vector3::zeros(), 1.0).unwrap();
+    let handle = application
+        .world()
+        .read_storage::<CBaseObject>()
+        .get(cs)
+        .unwrap()
+        .handle()
+        .clone();
+    let base_obj = unsafe { handle.as_ptr() };
+
+    delete(application.world_mut(), cs).unwrap();
+    std::thread::spawn(move || drop(handle)).join().unwrap();
+    assert!(core.is_allocated(base_obj));
+
+    application.tick();
+    assert!(!core.is_allocated(base_obj));
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use altv::core::AltResource;
+use altv::ecs::{Read, System, SystemData, World, WorldExt};
+use altv::event_channel::{EventChannel, ReaderId};
+use altv::game_data::{GameData, GameDataBuilder, StateData};
+use altv::sdk::elements::CPlayer;
+use altv::sdk::events::{CEvent, CExplosionEvent, CPlayerConnectEvent, CPlayerDisconnectEvent};
+use altv::sdk::vector::Vector3;
+use altv::simulator::Simulator;
+use altv::state::{State, Trans};
+
+struct SafeZoneState;
+
+impl State for SafeZoneState {
+    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) -> Trans {
+        match &event {
+            CEvent::PlayerConnect(connect) => {
+                let cplayers = data.world.read_storage::<CPlayer>();
+
+                if cplayers.get(connect.get_target()).unwrap().get_name() == "banned" {
+                    connect.cancel_with_reason("You are banned.");
+                }
+            }
+            CEvent::ExplosionEvent(explosion) if explosion.get_position().norm() < 100.0 => {
+                explosion.cancel();
+            }
+            _ => {}
+        }
+
+        Trans::None
+    }
+}
+
+#[derive(Default)]
+struct CancelExplosions {
+    reader: Option<ReaderId<CExplosionEvent>>,
+}
+
+impl<'a> System<'a> for CancelExplosions {
+    type SystemData = Read<'a, EventChannel<CExplosionEvent>>;
+
+    fn run(&mut self, events: Self::SystemData) {
+        for event in events.read(self.reader.as_mut().unwrap()) {
+            event.cancel();
+        }
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<CExplosionEvent>>()
+                .register_reader(),
+        );
+    }
+}
+
+fn simulator() -> Simulator {
+    common::simulator_with(Box::new(SafeZoneState), |builder| {
+        builder.build(GameDataBuilder::new())
+    })
+}
+
+#[test]
+fn cancel_explosions() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+
+    let explosion = |position| CEvent::ExplosionEvent(CExplosionEvent::new(player, 2, position, 0));
+
+    assert!(simulator.dispatch(explosion(Vector3::new(10.0, 0.0, 0.0))));
+    assert!(!simulator.dispatch(explosion(Vector3::new(500.0, 0.0, 0.0))));
+}
+
+#[test]
+fn reject_connect() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+    simulator.connect_player("banned");
+
+    let players = simulator
+        .world()
+        .read_resource::<AltResource>()
+        .players
+        .values()
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(players, vec![player]);
+
+    let event = CEvent::PlayerConnect(CPlayerConnectEvent::new(player, String::new()));
+    if let CEvent::PlayerConnect(connect) = &event {
+        connect.cancel_with_reason("full");
+        connect.cancel();
+    }
+    assert!(event.was_cancelled());
+    assert_eq!(
+        event.get_cancellation().unwrap().get_reason(),
+        Some("full".to_owned())
+    );
+}
+
+#[test]
+fn only_cancellable_events_have_a_cancellation() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+
+    let event = CEvent::PlayerDisconnect(CPlayerDisconnectEvent::new(player, String::new()));
+    assert!(event.get_cancellation().is_none());
+    assert!(!event.was_cancelled());
+}
+
+#[test]
+fn systems_cannot_cancel() {
+    let mut simulator = common::simulator_with(Box::new(SafeZoneState), |builder| {
+        builder.build(GameDataBuilder::new().with_thread_local(CancelExplosions::default()))
+    });
+    let player = simulator.connect_player("rust");
+
+    let event = CEvent::ExplosionEvent(CExplosionEvent::new(
+        player,
+        2,
+        Vector3::new(500.0, 0.0, 0.0),
+        0,
+    ));
+    assert!(!simulator.dispatch(event.clone()));
+
+    // The system cancels the copy it reads on the tick, after the dispatch answered the core.
+    simulator.tick();
+    assert!(event.was_cancelled());
+}
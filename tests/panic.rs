@@ -0,0 +1,31 @@
+use altv::app::ApplicationBuilder;
+use altv::game_data::{GameData, GameDataBuilder, StateData};
+use altv::sdk::backend::mock::LogLevel;
+use altv::sdk::backend::MockCore;
+use altv::state::{State, Trans};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+struct PanickingState;
+
+impl State for PanickingState {
+    fn tick(&mut self, _data: StateData<GameData>) -> Trans {
+        panic!("boom");
+    }
+}
+
+#[test]
+fn logs_panics() {
+    let core = Rc::new(MockCore::new());
+    let mut application = ApplicationBuilder::with_backend(core.clone(), Box::new(PanickingState))
+        .build(GameDataBuilder::new());
+    application.start();
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| application.tick())).is_err());
+
+    let logs = core.get_logs();
+    let (level, msg) = logs.last().unwrap();
+    assert_eq!(*level, LogLevel::Error);
+    assert!(msg.starts_with("[Rust] Panicked at 'boom', tests/panic.rs:"));
+    assert!(msg.contains("panic::PanickingState"));
+}
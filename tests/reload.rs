@@ -0,0 +1,90 @@
+mod common;
+
+use altv::app::{ApplicationBuilder, CoreApplication};
+use altv::core::AltResource;
+use altv::ecs::{World, WorldExt};
+use altv::game_data::GameDataBuilder;
+use altv::sdk::backend::MockCore;
+use altv::sdk::elements::*;
+use altv::sdk::rgba::Rgba;
+use altv::sdk::vector::{Rotation3, Vector3};
+use common::EmptyState;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct Round(u8);
+
+fn application() -> CoreApplication {
+    let core = Rc::new(MockCore::new());
+    let mut application = ApplicationBuilder::with_backend(core, Box::new(EmptyState))
+        .with_reload_hook(
+            |world: &World| vec![world.read_resource::<Round>().0],
+            |world: &mut World, data: &[u8]| world.write_resource::<Round>().0 = data[0],
+        )
+        .build(GameDataBuilder::new());
+    application.world_mut().insert(Round::default());
+    application
+}
+
+#[test]
+fn save_and_restore() {
+    let mut old = application();
+    old.world_mut().write_resource::<Round>().0 = 3;
+    let data = old.save().unwrap();
+
+    let mut new = application();
+    new.restore(&data);
+    assert_eq!(new.world().read_resource::<Round>().0, 3);
+
+    let core = Rc::new(MockCore::new());
+    let without_hook =
+        ApplicationBuilder::with_backend(core, Box::new(EmptyState)).build(GameDataBuilder::new());
+    assert_eq!(without_hook.save(), None);
+}
+
+#[test]
+fn base_objects_are_taken_over() {
+    let core = Rc::new(MockCore::new());
+    let old = ApplicationBuilder::with_backend(core.clone(), Box::new(EmptyState))
+        .build(GameDataBuilder::new());
+
+    let world = old.world();
+    let vehicle = create_vehicle(
+        world,
+        altv::sdk::hash("tyrant"),
+        Vector3::zeros(),
+        Rotation3::identity(),
+    )
+    .unwrap();
+    create_blip(world, None, BlipType::Destination, Vector3::zeros()).unwrap();
+    create_collision_shape_sphere(world, Vector3::zeros(), 1.0).unwrap();
+    create_checkpoint(
+        world,
+        CheckpointType::RingCar,
+        Vector3::zeros(),
+        2.0,
+        5.0,
+        Rgba::new(0, 0, 255, 255),
+    )
+    .unwrap();
+    create_voice_channel(world, true, 20.0).unwrap();
+    let vehicle = get_entity_base_object(&world.read_resource::<AltResource>(), vehicle).unwrap();
+
+    let base_objects = old.base_objects();
+    assert_eq!(base_objects.len(), 5);
+
+    let mut new = ApplicationBuilder::with_backend(core.clone(), Box::new(EmptyState))
+        .build(GameDataBuilder::new());
+    for base_obj in base_objects {
+        new.create_game_object(base_obj);
+    }
+    drop(old);
+
+    let alt = new.world().read_resource::<AltResource>();
+    assert_eq!(alt.vehicles.len(), 1);
+    assert_eq!(alt.blips.len(), 1);
+    assert_eq!(alt.collision_shapes.len(), 1);
+    assert_eq!(alt.checkpoints.len(), 1);
+    assert_eq!(alt.voice_channels.len(), 1);
+    assert!(core.exists(vehicle));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use altv::app::{self, ApplicationBuilder, CoreApplication, MainResult};
+use altv::entry::CallStatus;
+use altv::game_data::{GameData, GameDataBuilder, StateData};
+use altv::sdk::backend::mock::LogLevel;
+use altv::sdk::backend::{self, MockCore};
+use altv::state::{State, Trans};
+use common::EmptyState;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::rc::Rc;
+
+struct PanickingState;
+
+impl State for PanickingState {
+    fn tick(&mut self, _data: StateData<GameData>) -> Trans {
+        panic!("boom");
+    }
+}
+
+fn start() -> MainResult {
+    Ok(
+        ApplicationBuilder::with_backend(backend::instance(), Box::new(EmptyState))
+            .build(GameDataBuilder::new()),
+    )
+}
+
+fn panicking() -> MainResult {
+    panic!("no config");
+}
+
+altv::resource!(start);
+
+#[test]
+fn exports_entry_points() {
+    let core = Rc::new(MockCore::new());
+    backend::set_instance(core.clone());
+
+    // The info comes from this binary, which stays loaded.
+    assert!(unsafe { altv_rs_build_info().is_compatible() });
+
+    let result = unsafe { Box::from_raw(altv_main(ptr::null_mut())) };
+    let mut application = result.unwrap();
+    application.start();
+
+    // A null core keeps the backend the test set instead of installing the native one.
+    altv::sdk::log::info("started");
+    assert_eq!(
+        core.get_logs().last(),
+        Some(&(LogLevel::Info, "started".to_owned()))
+    );
+}
+
+#[test]
+fn main_panics_are_returned() {
+    let core = Rc::new(MockCore::new());
+    backend::set_instance(core.clone());
+
+    let result = unsafe { Box::from_raw(app::run_main(ptr::null_mut(), panicking)) };
+    assert_eq!(result.err().unwrap().to_string(), "main panicked");
+
+    let logs = core.get_logs();
+    let (level, msg) = logs.last().unwrap();
+    assert_eq!(*level, LogLevel::Error);
+    assert!(msg.starts_with("[Rust] Panicked at 'no config', tests/resource.rs:"));
+}
+
+#[test]
+fn entry_points_return_panics() {
+    let core = Rc::new(MockCore::new());
+    let mut application = ManuallyDrop::new(
+        ApplicationBuilder::with_backend(core, Box::new(PanickingState))
+            .build(GameDataBuilder::new()),
+    );
+    let app: *mut CoreApplication = &mut *application;
+    let entry_points = altv_rs_entry_points();
+
+    unsafe {
+        assert_eq!((entry_points.start)(app), CallStatus::Ok);
+        assert_eq!((entry_points.tick)(app), CallStatus::Panicked);
+        assert_eq!((entry_points.stop)(app), CallStatus::Ok);
+
+        let mut data = Some(Vec::new());
+        assert_eq!((entry_points.save)(app, &mut data), CallStatus::Ok);
+        assert_eq!(data, None);
+
+        assert_eq!((entry_points.drop)(app), CallStatus::Ok);
+    }
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use altv::app::CoreApplication;
+use altv::core::AltResource;
+use altv::ecs::{Entity, WorldExt};
+use altv::sdk::backend::{self, MockCore};
+use altv::sdk::elements::*;
+use altv::sdk::mvalue::MValue;
+use altv::sdk::natives::alt_IBaseObject;
+use altv::sdk::vector::{Rotation3, Vector3};
+use altv::sdk::Error;
+use std::rc::Rc;
+
+fn base_object(application: &CoreApplication, entity: Entity) -> *mut alt_IBaseObject {
+    // The world keeps the handle for the whole test.
+    unsafe {
+        application
+            .world()
+            .read_storage::<CBaseObject>()
+            .get(entity)
+            .unwrap()
+            .handle()
+            .as_ptr()
+    }
+}
+
+fn entity(application: &CoreApplication, base_obj: *mut alt_IBaseObject) -> Result<Entity, Error> {
+    get_base_object_entity(
+        &application.world().read_resource::<AltResource>(),
+        base_obj,
+    )
+}
+
+#[test]
+fn resources_map_objects_to_their_own_entities() {
+    let core = Rc::new(MockCore::new());
+    let mut first = common::application(&core);
+    let mut second = common::application(&core);
+
+    // Shifts the entity ids of the second resource, so the two can't match by accident.
+    second.world().entities().create();
+
+    let vehicle = create_vehicle(
+        first.world(),
+        altv::sdk::hash("tyrant"),
+        Vector3::zeros(),
+        Rotation3::identity(),
+    )
+    .unwrap();
+    let base_obj = base_object(&first, vehicle);
+
+    // The core announces the object to every resource.
+    first.create_game_object(base_obj);
+    second.create_game_object(base_obj);
+
+    let other = entity(&second, base_obj).unwrap();
+    assert_eq!(entity(&first, base_obj), Ok(vehicle));
+    assert_ne!(other, vehicle);
+
+    // A value sent by one resource reads as the other resource's entity.
+    let value = {
+        let alt = first.world().read_resource::<AltResource>();
+        MValue::BaseObject(vehicle).into_ref(&alt).unwrap()
+    };
+    let value = backend::instance().mvalue_ref_get(value);
+    assert_eq!(
+        MValue::new(&second.world().read_resource::<AltResource>(), value),
+        Ok(MValue::BaseObject(other))
+    );
+
+    second.remove_game_object(base_obj);
+
+    assert_eq!(entity(&second, base_obj), Err(Error::ObjectNotFound));
+    assert_eq!(entity(&first, base_obj), Ok(vehicle));
+    assert!(first.world().is_alive(vehicle));
+}
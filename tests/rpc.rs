@@ -0,0 +1,166 @@
+mod common;
+
+use altv::ecs::WorldExt;
+use altv::game_data::GameDataBuilder;
+use altv::rpc::{Rpc, RpcError, CALL_EVENT, RESULT_EVENT};
+use altv::sdk::mvalue::MValue;
+use altv::simulator::{SentClientEvent, Simulator};
+use common::EmptyState;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn simulator() -> Simulator {
+    common::simulator_with(Box::new(EmptyState), |builder| {
+        builder
+            .with_procedure("add", |_, _, (a, b): (i64, i64)| Ok(a + b))
+            .with_procedure("fail", |_, _, ()| Err::<bool, _>("nope".to_owned()))
+            .build(GameDataBuilder::new())
+    })
+}
+
+#[test]
+fn server_calls_client() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+
+    let future = simulator.world().write_resource::<Rpc>().call(
+        player,
+        "getWaypoint",
+        vec![MValue::Bool(true)],
+    );
+    simulator.tick();
+
+    assert_eq!(
+        simulator.take_client_events(),
+        vec![SentClientEvent {
+            target: Some(player),
+            name: CALL_EVENT.to_owned(),
+            args: vec![
+                MValue::Uint(1),
+                MValue::String("getWaypoint".to_owned()),
+                MValue::List(vec![MValue::Bool(true)]),
+            ],
+        }]
+    );
+    assert_eq!(future.try_take(), None);
+
+    simulator.client_script_event(
+        player,
+        RESULT_EVENT,
+        vec![MValue::Int(1), MValue::Bool(true), MValue::Double(2.5)],
+    );
+
+    assert_eq!(future.try_take(), Some(Ok(MValue::Double(2.5))));
+    assert_eq!(simulator.world().read_resource::<Rpc>().pending(), 0);
+}
+
+#[test]
+fn callbacks_timeouts_and_disconnects() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+    let other = simulator.connect_player("other");
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    {
+        let mut rpc = simulator.world().write_resource::<Rpc>();
+        rpc.set_timeout(Duration::from_secs(5));
+
+        let callback_results = results.clone();
+        rpc.call_then(player, "fail", vec![], move |_, result| {
+            callback_results.lock().unwrap().push(result)
+        });
+    }
+    let slow = simulator
+        .world()
+        .write_resource::<Rpc>()
+        .call(player, "slow", vec![]);
+    let left = simulator
+        .world()
+        .write_resource::<Rpc>()
+        .call(other, "left", vec![]);
+    simulator.tick();
+
+    // Answers from other players are ignored.
+    simulator.client_script_event(
+        other,
+        RESULT_EVENT,
+        vec![MValue::Uint(1), MValue::Bool(true), MValue::Nil],
+    );
+    simulator.client_script_event(
+        player,
+        RESULT_EVENT,
+        vec![
+            MValue::Uint(1),
+            MValue::Bool(false),
+            MValue::String("nope".to_owned()),
+        ],
+    );
+    assert_eq!(
+        *results.lock().unwrap(),
+        vec![Err(RpcError::Failed("nope".to_owned()))]
+    );
+
+    simulator.disconnect_player(other, "quit");
+    assert_eq!(left.try_take(), Some(Err(RpcError::Disconnected)));
+
+    simulator.advance(Duration::from_secs(4));
+    assert_eq!(slow.try_take(), None);
+    simulator.advance(Duration::from_secs(1));
+    assert_eq!(slow.try_take(), Some(Err(RpcError::Timeout)));
+}
+
+#[test]
+fn client_calls_server() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+
+    simulator.client_script_event(
+        player,
+        CALL_EVENT,
+        vec![
+            MValue::Uint(7),
+            MValue::String("add".to_owned()),
+            MValue::List(vec![MValue::Int(1), MValue::Int(2)]),
+        ],
+    );
+    simulator.client_script_event(
+        player,
+        CALL_EVENT,
+        vec![
+            MValue::Uint(8),
+            MValue::String("fail".to_owned()),
+            MValue::List(vec![]),
+        ],
+    );
+    simulator.client_script_event(
+        player,
+        CALL_EVENT,
+        vec![
+            MValue::Uint(9),
+            MValue::String("add".to_owned()),
+            MValue::List(vec![MValue::Int(1)]),
+        ],
+    );
+
+    let result = |args| SentClientEvent {
+        target: Some(player),
+        name: RESULT_EVENT.to_owned(),
+        args,
+    };
+    assert_eq!(
+        simulator.take_client_events(),
+        vec![
+            result(vec![MValue::Uint(7), MValue::Bool(true), MValue::Int(3)]),
+            result(vec![
+                MValue::Uint(8),
+                MValue::Bool(false),
+                MValue::String("nope".to_owned())
+            ]),
+            result(vec![
+                MValue::Uint(9),
+                MValue::Bool(false),
+                MValue::String("invalid arguments: expected 2 arguments, got 1".to_owned())
+            ]),
+        ]
+    );
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use altv::ecs::{Read, System, World, WorldExt, Write};
+use altv::game_data::GameDataBuilder;
+use altv::schedule::Schedule;
+use altv::time::Time;
+use common::EmptyState;
+use std::time::Duration;
+
+#[derive(Default)]
+struct Runs(Vec<&'static str>);
+
+struct Count(&'static str);
+
+impl<'a> System<'a> for Count {
+    type SystemData = Write<'a, Runs>;
+
+    fn run(&mut self, mut runs: Self::SystemData) {
+        runs.0.push(self.0);
+    }
+}
+
+struct RecordTime;
+
+impl<'a> System<'a> for RecordTime {
+    type SystemData = (Read<'a, Time>, Write<'a, Vec<(Duration, u64, Duration)>>);
+
+    fn run(&mut self, (time, mut records): Self::SystemData) {
+        records.push((time.delta(), time.tick_count(), time.uptime()));
+    }
+}
+
+fn runs(world: &World) -> Vec<&'static str> {
+    std::mem::take(&mut world.write_resource::<Runs>().0)
+}
+
+#[test]
+fn schedules() {
+    let mut simulator = common::simulator_with(Box::new(EmptyState), |builder| {
+        builder.build(
+            GameDataBuilder::new()
+                .with_scheduled(Count("hz"), Schedule::Hz(2.0))
+                .with_scheduled(
+                    Count("interval"),
+                    Schedule::Interval(Duration::from_secs(1)),
+                )
+                .with_scheduled(Count("ticks"), Schedule::Ticks(3)),
+        )
+    });
+
+    simulator.advance(Duration::from_millis(250));
+    assert!(runs(simulator.world()).is_empty());
+
+    simulator.advance(Duration::from_millis(250));
+    assert_eq!(runs(simulator.world()), vec!["hz"]);
+
+    simulator.advance(Duration::from_millis(500));
+    assert_eq!(runs(simulator.world()), vec!["hz", "interval", "ticks"]);
+
+    // A long tick runs every system once, the interval keeps the remainder.
+    simulator.advance(Duration::from_millis(1700));
+    assert_eq!(runs(simulator.world()), vec!["hz", "interval"]);
+
+    simulator.advance(Duration::from_millis(300));
+    assert_eq!(runs(simulator.world()), vec!["hz", "interval"]);
+}
+
+#[test]
+fn time() {
+    let mut simulator = common::simulator_with(Box::new(EmptyState), |builder| {
+        builder.build(GameDataBuilder::new().with_thread_local(RecordTime))
+    });
+
+    simulator.advance(Duration::from_millis(50));
+    simulator.advance(Duration::from_millis(100));
+
+    assert_eq!(
+        *simulator
+            .world()
+            .read_resource::<Vec<(Duration, u64, Duration)>>(),
+        vec![
+            (Duration::from_millis(50), 1, Duration::from_millis(50)),
+            (Duration::from_millis(100), 2, Duration::from_millis(150)),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "schedule rate has to be positive and finite, got 0 Hz")]
+fn zero_hz_is_rejected() {
+    GameDataBuilder::new().with_scheduled(Count("hz"), Schedule::Hz(0.0));
+}
+
+#[test]
+fn invalid_hz_is_rejected() {
+    for hz in &[-1.0, f64::NAN, f64::INFINITY] {
+        let result = std::panic::catch_unwind(|| {
+            GameDataBuilder::new().with_scheduled(Count("hz"), Schedule::Hz(*hz));
+        });
+        assert!(result.is_err(), "{} Hz was accepted", hz);
+    }
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use altv::ecs::{World, WorldExt};
+use altv::scheduler::{Scheduler, TimerHandle};
+use altv::simulator::Simulator;
+use std::time::Duration;
+
+#[derive(Default)]
+struct Fired(Vec<&'static str>);
+
+fn simulator() -> Simulator {
+    let mut simulator = common::simulator();
+    simulator.world_mut().insert(Fired::default());
+    simulator
+}
+
+fn fire(name: &'static str) -> impl FnMut(&mut World) + Send + Sync {
+    move |world| world.write_resource::<Fired>().0.push(name)
+}
+
+fn fired(simulator: &Simulator) -> Vec<&'static str> {
+    std::mem::take(&mut simulator.world().write_resource::<Fired>().0)
+}
+
+#[test]
+fn one_shot_and_repeating() {
+    let mut simulator = simulator();
+    let repeating = {
+        let mut scheduler = simulator.world().write_resource::<Scheduler>();
+        scheduler.after(Duration::from_secs(5), fire("once"));
+        scheduler.every(Duration::from_secs(2), fire("every"))
+    };
+
+    simulator.advance(Duration::from_secs(1));
+    assert!(fired(&simulator).is_empty());
+
+    simulator.advance(Duration::from_secs(1));
+    assert_eq!(fired(&simulator), vec!["every"]);
+
+    simulator.advance(Duration::from_secs(3));
+    assert_eq!(fired(&simulator), vec!["every", "once"]);
+
+    simulator.advance(Duration::from_secs(10));
+    assert_eq!(fired(&simulator), vec!["every"]);
+
+    assert!(simulator
+        .world()
+        .write_resource::<Scheduler>()
+        .cancel(repeating));
+    simulator.advance(Duration::from_secs(10));
+    assert!(fired(&simulator).is_empty());
+    assert_eq!(simulator.world().read_resource::<Scheduler>().pending(), 0);
+}
+
+#[test]
+fn cancel_from_callback() {
+    let mut simulator = simulator();
+    let handle =
+        simulator
+            .world()
+            .write_resource::<Scheduler>()
+            .every(Duration::from_secs(1), |world| {
+                let mut fired = world.write_resource::<Fired>();
+                fired.0.push("every");
+
+                if fired.0.len() == 2 {
+                    let handle = *world.read_resource::<TimerHandle>();
+                    assert!(world.write_resource::<Scheduler>().cancel(handle));
+                }
+            });
+    simulator.world_mut().insert(handle);
+
+    simulator.run_for(Duration::from_secs(5), Duration::from_secs(1));
+    assert_eq!(fired(&simulator), vec!["every", "every"]);
+}
+
+#[test]
+fn drop_with_entity() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+    let handle = simulator.world().write_resource::<Scheduler>().after_for(
+        player,
+        Duration::from_secs(5),
+        fire("respawn"),
+    );
+
+    simulator.disconnect_player(player, "");
+    assert!(!simulator
+        .world()
+        .read_resource::<Scheduler>()
+        .is_scheduled(handle));
+
+    simulator.advance(Duration::from_secs(5));
+    assert!(fired(&simulator).is_empty());
+}
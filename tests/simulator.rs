@@ -0,0 +1,144 @@
+mod common;
+
+use altv::core::AltResource;
+use altv::ecs::WorldExt;
+use altv::game_data::{GameData, GameDataBuilder, StateData};
+use altv::sdk::elements::*;
+use altv::sdk::events::CEvent;
+use altv::sdk::mvalue::MValue;
+use altv::sdk::rgba::Rgba;
+use altv::sdk::vector::Vector3;
+use altv::simulator::{SentClientEvent, Simulator};
+use altv::state::{State, Trans};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct RecordingState(Rc<RefCell<Vec<String>>>);
+
+impl State for RecordingState {
+    fn handle_event(&mut self, data: StateData<GameData>, event: CEvent) -> Trans {
+        let name = match &event {
+            CEvent::PlayerConnect(_) => "connect".to_owned(),
+            CEvent::PlayerDisconnect(event) => format!("disconnect:{}", event.get_reason()),
+            CEvent::PlayerDamage(event) => format!("damage:{}", event.get_damage()),
+            CEvent::PlayerDeath(_) => "death".to_owned(),
+            CEvent::CollisionShapeEvent(event) => format!("colshape:{}", event.get_state()),
+            CEvent::ClientScript(event) => {
+                let alt = data.world.read_resource::<AltResource>();
+                let mut cplayers = data.world.write_storage::<CPlayer>();
+                cplayers
+                    .get_mut(event.get_target())
+                    .unwrap()
+                    .emit(&alt, "pong", event.get_args())
+                    .unwrap();
+                format!("client:{}", event.get_name())
+            }
+            _ => return Trans::None,
+        };
+
+        self.0.borrow_mut().push(name);
+        Trans::None
+    }
+}
+
+fn simulator() -> (Simulator, Rc<RefCell<Vec<String>>>) {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let simulator = common::simulator_with(Box::new(RecordingState(events.clone())), |builder| {
+        builder.build(GameDataBuilder::new())
+    });
+
+    (simulator, events)
+}
+
+#[test]
+fn collision_shapes() {
+    let (mut simulator, events) = simulator();
+    let player = simulator.connect_player("rust");
+    let cs = create_collision_shape_sphere(simulator.world(), Vector3::new(10.0, 0.0, 0.0), 2.0)
+        .unwrap();
+
+    simulator.move_entity(player, Vector3::new(9.0, 0.0, 0.0));
+    simulator.move_entity(player, Vector3::new(10.0, 1.0, 0.0));
+    simulator.move_entity(player, Vector3::new(20.0, 0.0, 0.0));
+    simulator.fire_collision_shape_event(cs, player, true);
+
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            "connect",
+            "colshape:true",
+            "colshape:false",
+            "colshape:true"
+        ]
+    );
+}
+
+#[test]
+fn checkpoints() {
+    let (mut simulator, events) = simulator();
+    let player = simulator.connect_player("rust");
+    create_checkpoint(
+        simulator.world(),
+        CheckpointType::CylinderCheckerboard,
+        Vector3::new(10.0, 0.0, 0.0),
+        2.0,
+        5.0,
+        Rgba::new(255, 0, 0, 255),
+    )
+    .unwrap();
+
+    simulator.move_entity(player, Vector3::new(10.0, 1.0, 1.0));
+    assert_eq!(*events.borrow(), vec!["connect", "colshape:true"]);
+}
+
+#[test]
+fn damage_until_death() {
+    let (mut simulator, events) = simulator();
+    let target = simulator.connect_player("target");
+    let attacker = simulator.connect_player("attacker");
+
+    simulator.damage_player(target, Some(attacker), 50, 0);
+    simulator.damage_player(target, Some(attacker), 60, 0);
+    simulator.damage_player(target, Some(attacker), 10, 0);
+
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            "connect",
+            "connect",
+            "damage:50",
+            "damage:60",
+            "death",
+            "damage:10"
+        ]
+    );
+}
+
+#[test]
+fn client_events() {
+    let (mut simulator, events) = simulator();
+    let player = simulator.connect_player("rust");
+
+    simulator.client_script_event(player, "ping", vec![MValue::Int(1)]);
+    assert_eq!(
+        simulator.take_client_events(),
+        vec![SentClientEvent {
+            target: Some(player),
+            name: "pong".to_owned(),
+            args: vec![MValue::Int(1)],
+        }]
+    );
+
+    simulator.disconnect_player(player, "quit");
+    simulator.tick();
+
+    assert!(simulator
+        .world()
+        .read_resource::<AltResource>()
+        .players
+        .is_empty());
+    assert_eq!(
+        *events.borrow(),
+        vec!["connect", "client:ping", "disconnect:quit"]
+    );
+}
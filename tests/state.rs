@@ -0,0 +1,149 @@
+mod common;
+
+use altv::ecs::{System, WorldExt, Write};
+use altv::game_data::{GameData, GameDataBuilder, StateData};
+use altv::sdk::events::{CConsoleCommandEvent, CEvent};
+use altv::state::{State, Trans};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+struct Lobby(Log);
+
+impl State for Lobby {
+    fn on_start(&mut self, _data: StateData<GameData>) -> Trans {
+        self.0.borrow_mut().push("lobby:start".to_owned());
+        Trans::None
+    }
+
+    fn on_pause(&mut self, _data: StateData<GameData>) {
+        self.0.borrow_mut().push("lobby:pause".to_owned());
+    }
+
+    fn on_resume(&mut self, _data: StateData<GameData>) {
+        self.0.borrow_mut().push("lobby:resume".to_owned());
+    }
+
+    fn on_stop(&mut self, _data: StateData<GameData>) {
+        self.0.borrow_mut().push("lobby:stop".to_owned());
+    }
+
+    fn handle_event(&mut self, _data: StateData<GameData>, event: CEvent) -> Trans {
+        match event {
+            CEvent::ConsoleCommand(event) if event.get_name() == "start" => {
+                Trans::Push(Box::new(Round(self.0.clone())))
+            }
+            CEvent::ConsoleCommand(event) if event.get_name() == "quit" => Trans::Quit,
+            _ => Trans::None,
+        }
+    }
+}
+
+struct Round(Log);
+
+impl State for Round {
+    fn on_start(&mut self, _data: StateData<GameData>) -> Trans {
+        self.0.borrow_mut().push("round:start".to_owned());
+        Trans::None
+    }
+
+    fn on_stop(&mut self, _data: StateData<GameData>) {
+        self.0.borrow_mut().push("round:stop".to_owned());
+    }
+
+    fn tick(&mut self, _data: StateData<GameData>) -> Trans {
+        Trans::Switch(Box::new(Scoreboard(self.0.clone())))
+    }
+}
+
+struct Scoreboard(Log);
+
+impl State for Scoreboard {
+    fn on_start(&mut self, _data: StateData<GameData>) -> Trans {
+        self.0.borrow_mut().push("scoreboard:start".to_owned());
+        Trans::None
+    }
+
+    fn on_stop(&mut self, _data: StateData<GameData>) {
+        self.0.borrow_mut().push("scoreboard:stop".to_owned());
+    }
+
+    fn tick(&mut self, _data: StateData<GameData>) -> Trans {
+        Trans::Pop
+    }
+}
+
+#[derive(Default)]
+struct Ticks(u32);
+
+struct CountTicks;
+
+impl<'a> System<'a> for CountTicks {
+    type SystemData = Write<'a, Ticks>;
+
+    fn run(&mut self, mut ticks: Self::SystemData) {
+        ticks.0 += 1;
+    }
+}
+
+fn command(name: &str) -> CEvent {
+    CEvent::ConsoleCommand(CConsoleCommandEvent::new(name.to_owned(), Vec::new()))
+}
+
+#[test]
+fn lobby_round_scoreboard() {
+    let log = Log::default();
+    let mut simulator = common::simulator_with(Box::new(Lobby(log.clone())), |builder| {
+        builder.build(GameDataBuilder::new())
+    });
+
+    simulator.dispatch(command("start"));
+    simulator.tick();
+    simulator.tick();
+
+    assert_eq!(
+        *log.borrow(),
+        vec![
+            "lobby:start",
+            "lobby:pause",
+            "round:start",
+            "round:stop",
+            "scoreboard:start",
+            "scoreboard:stop",
+            "lobby:resume",
+        ]
+    );
+    assert!(simulator.application().is_running());
+
+    log.borrow_mut().clear();
+    simulator.dispatch(command("quit"));
+    simulator.tick();
+
+    assert_eq!(*log.borrow(), vec!["lobby:stop"]);
+    assert!(!simulator.application().is_running());
+}
+
+#[test]
+fn restart_after_quit() {
+    let log = Log::default();
+    let mut simulator = common::simulator_with(Box::new(Lobby(log.clone())), |builder| {
+        builder.build(GameDataBuilder::new().with_thread_local(CountTicks))
+    });
+
+    simulator.tick();
+    assert_eq!(simulator.world().read_resource::<Ticks>().0, 1);
+
+    simulator.dispatch(command("quit"));
+    simulator.tick();
+    assert_eq!(simulator.world().read_resource::<Ticks>().0, 1);
+
+    simulator.start();
+    simulator.tick();
+    assert!(simulator.application().is_running());
+    assert_eq!(simulator.world().read_resource::<Ticks>().0, 2);
+    assert_eq!(
+        *log.borrow(),
+        vec!["lobby:start", "lobby:stop", "lobby:start"]
+    );
+}
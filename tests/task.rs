@@ -0,0 +1,88 @@
+mod common;
+
+use altv::ecs::WorldExt;
+use altv::rpc::{Rpc, RESULT_EVENT};
+use altv::sdk::elements::CPlayer;
+use altv::sdk::mvalue::MValue;
+use altv::simulator::Simulator;
+use altv::task::Tasks;
+use std::thread;
+
+#[derive(Default)]
+struct Messages(Vec<String>);
+
+fn simulator() -> Simulator {
+    let mut simulator = common::simulator();
+    simulator.world_mut().insert(Messages::default());
+    simulator
+}
+
+fn messages(simulator: &Simulator) -> Vec<String> {
+    std::mem::take(&mut simulator.world().write_resource::<Messages>().0)
+}
+
+#[test]
+fn post_from_background_thread() {
+    let mut simulator = simulator();
+    let sender = simulator.world().read_resource::<Tasks>().sender();
+
+    thread::spawn(move || {
+        let result = "query result".to_owned();
+        sender.send(move |world| world.write_resource::<Messages>().0.push(result));
+    })
+    .join()
+    .unwrap();
+    assert!(messages(&simulator).is_empty());
+
+    simulator.tick();
+    assert_eq!(messages(&simulator), vec!["query result"]);
+}
+
+#[test]
+fn await_world_and_rpc() {
+    let mut simulator = simulator();
+    let player = simulator.connect_player("rust");
+
+    {
+        let tasks = simulator.world().read_resource::<Tasks>();
+        let sender = tasks.sender();
+
+        tasks.spawn(async move {
+            let name = sender
+                .run(move |world| {
+                    let cplayers = world.read_storage::<CPlayer>();
+                    cplayers.get(player).unwrap().get_name()
+                })
+                .await;
+
+            let call = sender
+                .run(move |world| {
+                    world
+                        .write_resource::<Rpc>()
+                        .call(player, "getWaypoint", vec![])
+                })
+                .await;
+            let waypoint = call.await;
+
+            sender.send(move |world| {
+                world
+                    .write_resource::<Messages>()
+                    .0
+                    .push(format!("{}: {:?}", name, waypoint))
+            });
+        });
+    }
+
+    simulator.tick();
+    simulator.tick();
+    assert!(messages(&simulator).is_empty());
+    assert_eq!(simulator.world().read_resource::<Rpc>().pending(), 1);
+
+    simulator.client_script_event(
+        player,
+        RESULT_EVENT,
+        vec![MValue::Int(1), MValue::Bool(true), MValue::Int(7)],
+    );
+    simulator.tick();
+    assert_eq!(messages(&simulator), vec!["rust: Ok(7L)"]);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use altv::ecs::WorldExt;
+use altv::sdk::backend::MockCore;
+use altv::sdk::elements::*;
+use altv::sdk::vector::{Rotation3, Vector3};
+use std::rc::Rc;
+
+#[test]
+fn vehicle_doors_and_siren() {
+    let core = Rc::new(MockCore::new());
+    let application = common::application(&core);
+    let world = application.world();
+    let vehicle = create_vehicle(
+        world,
+        altv::sdk::hash("police"),
+        Vector3::zeros(),
+        Rotation3::identity(),
+    )
+    .unwrap();
+
+    let mut cvehicles = world.write_storage::<CVehicle>();
+    let cvehicle = cvehicles.get_mut(vehicle).unwrap();
+    assert_eq!(cvehicle.get_door_state(5), DoorState::Closed);
+    assert_eq!(cvehicle.get_lock_state(), Some(LockState::Unlocked));
+
+    cvehicle.set_door_state(5, DoorState::OpenedLevel7);
+    cvehicle.set_lock_state(LockState::Locked);
+    cvehicle.set_siren_active(true);
+    cvehicle.toggle_extra(1, true);
+    cvehicle.set_numberplate_style(NumberPlateStyle::Yankton);
+
+    assert_eq!(cvehicle.get_door_state(5), DoorState::OpenedLevel7);
+    assert_eq!(cvehicle.get_door_state(0), DoorState::Closed);
+    assert_eq!(cvehicle.get_lock_state(), Some(LockState::Locked));
+    assert!(cvehicle.is_siren_active());
+    assert!(cvehicle.is_extra_on(1));
+    assert_eq!(
+        cvehicle.get_numberplate_style(),
+        Some(NumberPlateStyle::Yankton)
+    );
+}
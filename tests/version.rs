@@ -0,0 +1,22 @@
+use altv::sdk::core::SDK_VERSION;
+use altv::version::{self, BuildInfo, VERSION};
+
+#[test]
+fn compatible_versions() {
+    assert!(version::is_compatible(SDK_VERSION, VERSION));
+    assert!(!version::is_compatible(SDK_VERSION + 1, VERSION));
+    assert!(!version::is_compatible(SDK_VERSION, "0.0.0"));
+    assert!(!version::is_compatible(SDK_VERSION, ""));
+}
+
+#[test]
+fn current_build_info() {
+    let build_info = BuildInfo::current();
+    assert_eq!(build_info.sdk_version(), SDK_VERSION);
+
+    // The info comes from this binary, which stays loaded.
+    unsafe {
+        assert_eq!(build_info.version(), VERSION);
+        assert!(build_info.is_compatible());
+    }
+}